@@ -0,0 +1,686 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use registry::{Registry, Selection, TypeKind, Declaration, Constant, Value};
+
+// Naming follows the hand-written sys modules: C names for types, enum
+// variants and functions, snake_case for members and parameters, and
+// `PFN_vkFoo` becomes `PFNvkFoo`.
+pub fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            // Acronyms are one word (`vendorID`), unless the last capital
+            // starts the next one (`residencyStandard2DBlockShape`)
+            let acronym = i > 0 && chars[i - 1].is_uppercase() &&
+                !chars.get(i + 1).map_or(false, |n| n.is_lowercase());
+            if i > 0 && !acronym && !snake.ends_with('_') {snake.push('_')}
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    match snake.as_str() {
+        "type" => "ty".to_string(),
+        _ => snake,
+    }
+}
+
+fn primitive(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "void" => "c_void",
+        "char" => "c_char",
+        "float" => "c_float",
+        "double" => "c_double",
+        "int" => "c_int",
+        "size_t" => "size_t",
+        "int8_t" => "int8_t",
+        "uint8_t" => "uint8_t",
+        "int16_t" => "int16_t",
+        "uint16_t" => "uint16_t",
+        "int32_t" => "int32_t",
+        "uint32_t" => "uint32_t",
+        "int64_t" => "int64_t",
+        "uint64_t" => "uint64_t",
+        _ => return None,
+    })
+}
+
+// Window system types passed by value, the others only appear behind
+// pointers and stay opaque
+fn platform_type(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "Window" | "VisualID" => "c_ulong",
+        "xcb_window_t" | "xcb_visualid_t" => "uint32_t",
+        "HINSTANCE" | "HWND" => "*mut c_void",
+        _ => return None,
+    })
+}
+
+// VK_EXT_debug_report -> DebugReportFns
+fn table_name(extension: &str) -> String {
+    let words = extension.splitn(3, '_').nth(2).unwrap_or(extension).split('_');
+    let mut name = words.map(|w| {
+        let mut chars = w.chars();
+        chars.next().map_or(String::new(), |c| c.to_uppercase().chain(chars).collect())
+    }).collect::<String>();
+    name.push_str("Fns");
+    name
+}
+
+const LIBC_TYPES: &'static str = "c_void, c_char, c_float, c_double, c_int, c_ulong, size_t, int8_t, uint8_t, int16_t, uint16_t, int32_t, uint32_t, int64_t, uint64_t";
+
+pub struct Generator<'a> {
+    registry: &'a Registry,
+    kinds: HashMap<&'a str, &'a TypeKind>,
+    // FlagBits enums are folded into their Flags type
+    flags: HashMap<&'a str, &'a str>,
+    types: Vec<&'a str>,
+    constants: Vec<&'a str>,
+    commands: Vec<&'a str>,
+    // Dispatch tables, with the extension they belong to
+    tables: Vec<(String, Option<&'a str>, Vec<&'a str>)>,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(registry: &'a Registry, selection: &'a Selection) -> Self {
+        let kinds = registry.types.iter()
+            .map(|&(ref n, ref k)| (n.as_str(), k))
+            .collect::<HashMap<_, _>>();
+        let flags = registry.types.iter().filter_map(|&(ref n, ref k)| match *k {
+            TypeKind::Bitmask{bits: Some(ref bits), ..} => Some((bits.as_str(), n.as_str())),
+            _ => None,
+        }).collect();
+
+        let mut generator = Generator{
+            registry: registry, kinds: kinds, flags: flags,
+            types: Vec::new(), constants: Vec::new(), commands: Vec::new(), tables: Vec::new(),
+        };
+
+        let mut seen = HashSet::new();
+        for command in &selection.commands {
+            let command = registry.command_aliases.get(command).unwrap_or(command);
+            if seen.insert(command.as_str()) {
+                generator.commands.push(command.as_str());
+                for param in &registry.commands[command].params {
+                    generator.require(param);
+                }
+                let ret = registry.commands[command].ret.as_str();
+                generator.require_type(ret);
+            }
+        }
+        for ty in &selection.types {
+            generator.require_type(ty);
+        }
+        for constant in &selection.constants {
+            if registry.constants.contains_key(constant) && !generator.constants.contains(&constant.as_str()) {
+                generator.constants.push(constant.as_str());
+            }
+        }
+        // Keep registry order, so output is stable
+        let required = generator.types.iter().cloned().collect::<HashSet<_>>();
+        generator.types = registry.types.iter()
+            .map(|&(ref n, _)| n.as_str())
+            .filter(|n| required.contains(n))
+            .collect();
+        generator.constants.sort();
+        generator.commands.sort();
+
+        // Core commands are split by what they're loaded with, extension
+        // commands by extension. vkGetInstanceProcAddr loads everything else.
+        let mut extension_commands = HashSet::new();
+        let mut tables = Vec::new();
+        for &(ref extension, ref commands) in &selection.extension_commands {
+            let mut commands = commands.iter()
+                .map(|c| registry.command_aliases.get(c).unwrap_or(c).as_str())
+                .collect::<Vec<_>>();
+            commands.sort();
+            commands.dedup();
+            extension_commands.extend(commands.iter().cloned());
+            tables.push((table_name(extension), Some(extension.as_str()), commands));
+        }
+        let mut core = vec!(Vec::new(), Vec::new(), Vec::new());
+        for &name in generator.commands.iter().filter(|c| !extension_commands.contains(*c)) {
+            let first = registry.commands[name].params.first().map(|p| p.base.as_str());
+            match (name, first) {
+                ("vkGetInstanceProcAddr", _) => {},
+                ("vkGetDeviceProcAddr", _) => core[1].push(name),
+                (_, Some("VkInstance")) | (_, Some("VkPhysicalDevice")) => core[1].push(name),
+                (_, Some("VkDevice")) | (_, Some("VkQueue")) | (_, Some("VkCommandBuffer")) => core[2].push(name),
+                _ => core[0].push(name),
+            }
+        }
+        generator.tables = ["EntryFns", "InstanceFns", "DeviceFns"].iter()
+            .zip(core)
+            .map(|(table, commands)| (table.to_string(), None, commands))
+            .chain(tables)
+            .filter(|&(_, _, ref commands)| !commands.is_empty())
+            .collect();
+        generator
+    }
+
+    fn require(&mut self, decl: &'a Declaration) {
+        self.require_type(&decl.base);
+        if let Some(ref array) = decl.array {
+            if self.registry.constants.contains_key(array) && !self.constants.contains(&array.as_str()) {
+                self.constants.push(array.as_str());
+            }
+        }
+    }
+
+    fn require_type(&mut self, name: &'a str) {
+        if primitive(name).is_some() {
+            return;
+        }
+        let kind = match self.kinds.get(name) {
+            Some(kind) => *kind,
+            None => return,
+        };
+        if self.types.contains(&name) {
+            return;
+        }
+        self.types.push(name);
+        if let Some(flags) = self.flags.get(name).cloned() {
+            self.require_type(flags);
+        }
+        match *kind {
+            TypeKind::Alias(ref alias) => self.require_type(alias),
+            TypeKind::Basetype(ref underlying) => self.require_type(underlying),
+            TypeKind::Bitmask{ref bits, ref underlying} => {
+                self.require_type(underlying);
+                if let Some(ref bits) = *bits {self.require_type(bits)}
+            },
+            TypeKind::FuncPointer{ref ret, ref params} => {
+                self.require(ret);
+                for p in params {self.require(p)}
+            },
+            TypeKind::Struct(ref members) | TypeKind::Union(ref members) => {
+                for m in members {self.require(m)}
+            },
+            TypeKind::Handle | TypeKind::Enum | TypeKind::External => {},
+        }
+    }
+
+    fn type_name(&self, name: &str) -> String {
+        if let Some(p) = primitive(name) {
+            return p.to_string();
+        }
+        if let Some(flags) = self.flags.get(name) {
+            return flags.to_string();
+        }
+        if name.starts_with("PFN_") {
+            return name.replacen("_", "", 1);
+        }
+        name.to_string()
+    }
+
+    pub fn rust_type(&self, decl: &Declaration) -> String {
+        let mut ty = self.type_name(&decl.base);
+        for is_const in &decl.pointers {
+            ty = format!("*{} {}", if *is_const {"const"} else {"mut"}, ty);
+        }
+        match decl.array {
+            Some(ref len) if self.registry.constants.contains_key(len) =>
+                format!("[{}; {} as usize]", ty, len),
+            Some(ref len) => format!("[{}; {}]", ty, len),
+            None => ty,
+        }
+    }
+
+    fn array_len(&self, len: &str) -> Option<i64> {
+        match self.registry.constants.get(len) {
+            Some(&Constant{value: Value::Int(v), ..}) => Some(v),
+            Some(_) => None,
+            None => len.parse().ok(),
+        }
+    }
+
+    fn is_default(&self, decl: &Declaration) -> bool {
+        if !decl.pointers.is_empty() {
+            return false;
+        }
+        if decl.array.as_ref().map_or(false, |len| self.array_len(len).map_or(true, |l| l > 32)) {
+            return false;
+        }
+        // bitflags don't implement Default
+        if primitive(&decl.base).is_some() || decl.base == "VkBool32" {
+            return true;
+        }
+        match self.kinds.get(decl.base.as_str()) {
+            Some(&&TypeKind::Basetype(_)) | Some(&&TypeKind::Handle) => true,
+            Some(&&TypeKind::Struct(ref members)) => members.iter().all(|m| self.is_default(m)),
+            _ => false,
+        }
+    }
+
+    // Unions can't derive Debug or PartialEq, and comparing function
+    // pointers isn't meaningful
+    fn is_comparable(&self, decl: &Declaration, allow_fn: bool) -> bool {
+        if !decl.pointers.is_empty() {
+            return true;
+        }
+        match self.kinds.get(decl.base.as_str()) {
+            Some(&&TypeKind::Union(_)) => false,
+            Some(&&TypeKind::FuncPointer{..}) => allow_fn,
+            Some(&&TypeKind::Struct(ref members)) => members.iter().all(|m| self.is_comparable(m, allow_fn)),
+            _ => true,
+        }
+    }
+
+    fn is_float(&self, decl: &Declaration) -> bool {
+        if !decl.pointers.is_empty() {
+            return false;
+        }
+        match self.kinds.get(decl.base.as_str()) {
+            Some(&&TypeKind::Struct(ref members)) => members.iter().any(|m| self.is_float(m)),
+            _ => decl.base == "float" || decl.base == "double",
+        }
+    }
+
+    fn derives(&self, members: &[Declaration]) -> String {
+        let mut derives = Vec::new();
+        if members.iter().all(|m| self.is_comparable(m, true)) {
+            derives.push("Debug");
+        }
+        derives.extend(&["Clone", "Copy"]);
+        if members.iter().all(|m| self.is_comparable(m, false)) {
+            derives.push("PartialEq");
+            if !members.iter().any(|m| self.is_float(m)) {
+                derives.push("Eq");
+            }
+        }
+        if members.iter().all(|m| self.is_default(m)) {
+            derives.push("Default");
+        }
+        derives.join(", ")
+    }
+
+    fn constant(&self, constant: &Constant) -> String {
+        let (ty, value) = match constant.value {
+            Value::Int(v) => ("uint32_t".to_string(), v.to_string()),
+            Value::Bits(v) => ("uint32_t".to_string(), format!("0x{:08x}", v)),
+            Value::Literal(ref l) if l.starts_with('"') => ("&'static str".to_string(), l.clone()),
+            Value::Literal(ref l) => {
+                let l = l.trim_matches(|c| c == '(' || c == ')');
+                let ty = if l.ends_with('F') || l.ends_with('f') {
+                    "c_float"
+                } else if l.ends_with("ULL") {
+                    "uint64_t"
+                } else {
+                    "uint32_t"
+                };
+                let value = l.trim_right_matches(|c| c == 'F' || c == 'f' || c == 'U' || c == 'L')
+                    .replace("~", "!");
+                (ty.to_string(), value)
+            },
+        };
+        let ty = constant.ty.as_ref().map_or(ty, |t| self.type_name(t));
+        format!("pub const {}: {} = {};\n", constant.name, ty, value)
+    }
+
+    // C array parameters decay to pointers
+    fn param_type(&self, decl: &Declaration) -> String {
+        match decl.array {
+            Some(_) => format!("*const {}", self.rust_type(&Declaration{array: None, ..decl.clone()})),
+            None => self.rust_type(decl),
+        }
+    }
+
+    fn function_signature(&self, params: &[Declaration], ret: &str) -> String {
+        let params = params.iter()
+            .map(|p| format!("{}: {}", snake_case(&p.name), self.param_type(p)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match ret {
+            "void" => format!("({})", params),
+            ret => format!("({}) -> {}", params, self.type_name(ret)),
+        }
+    }
+
+    fn enum_values(&self, name: &str) -> Vec<&'a Constant> {
+        let mut seen = HashSet::new();
+        self.registry.enums.get(name).map_or(Vec::new(), |values| {
+            values.iter().filter(|v| match v.value {
+                Value::Int(i) => seen.insert(i),
+                Value::Bits(b) => seen.insert(b as i64),
+                Value::Literal(_) => false,
+            }).collect()
+        })
+    }
+
+    pub fn generate(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "// Generated by vkrs-gen from vk.xml, do not edit.").unwrap();
+        writeln!(out, "#![allow(dead_code, non_camel_case_types, non_snake_case)]").unwrap();
+        writeln!(out, "").unwrap();
+        writeln!(out, "extern crate libc;").unwrap();
+        writeln!(out, "#[allow(unused_imports)]").unwrap();
+        writeln!(out, "use self::libc::{{{}}};", LIBC_TYPES).unwrap();
+        writeln!(out, "").unwrap();
+        writeln!(out, "pub const VK_NULL_HANDLE : usize = 0;").unwrap();
+        for name in &self.constants {
+            out.push_str(&self.constant(&self.registry.constants[*name]));
+        }
+
+        for name in self.types.iter().filter(|n| !self.flags.contains_key(*n)) {
+            out.push('\n');
+            match *self.kinds[name] {
+                TypeKind::Basetype(_) if *name == "VkBool32" => {
+                    out.push_str(concat!(
+                        "#[repr(u32)]\n",
+                        "#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n",
+                        "pub enum VkBool32 {\n",
+                        "    False = 0,\n",
+                        "    True = 1,\n",
+                        "}\n\n",
+                        "impl Default for VkBool32 {\n",
+                        "    fn default() -> Self {\n",
+                        "        VkBool32::False\n",
+                        "    }\n",
+                        "}\n"));
+                },
+                TypeKind::Basetype(ref underlying) => {
+                    writeln!(out, "pub type {} = {};", name, self.type_name(underlying)).unwrap();
+                },
+                TypeKind::Alias(ref alias) => {
+                    writeln!(out, "pub type {} = {};", name, self.type_name(alias)).unwrap();
+                },
+                TypeKind::Handle => {
+                    writeln!(out, "pub type {} = usize;", name).unwrap();
+                },
+                TypeKind::External => match platform_type(name) {
+                    Some(ty) => writeln!(out, "pub type {} = {};", name, ty).unwrap(),
+                    None => writeln!(out, "pub enum {} {{}}", name).unwrap(),
+                },
+                TypeKind::Enum => {
+                    let values = self.enum_values(name);
+                    if values.is_empty() {
+                        writeln!(out, "pub type {} = int32_t;", name).unwrap();
+                        continue;
+                    }
+                    // Newtypes rather than enums, see `vk_enum!` in vkrs::sys
+                    writeln!(out, "vk_enum!({}: int32_t {{", name).unwrap();
+                    for value in values {
+                        if let Value::Int(v) = value.value {
+                            writeln!(out, "    {} = {},", value.name, v).unwrap();
+                        }
+                    }
+                    writeln!(out, "}});").unwrap();
+                },
+                TypeKind::Bitmask{ref bits, ref underlying} => {
+                    let values = bits.as_ref().map_or(Vec::new(), |b| self.enum_values(b));
+                    if values.is_empty() {
+                        writeln!(out, "#[repr(u32)]\n#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub enum {} {{\n    Reserved = 0,\n}}", name).unwrap();
+                        continue;
+                    }
+                    writeln!(out, "bitflags! {{\n    #[repr(C)]\n    pub flags {}: {} {{", name, underlying).unwrap();
+                    for value in values {
+                        match value.value {
+                            Value::Bits(v) => writeln!(out, "        const {} = 0x{:08x},", value.name, v).unwrap(),
+                            Value::Int(v) => writeln!(out, "        const {} = 0x{:08x},", value.name, v).unwrap(),
+                            Value::Literal(_) => {},
+                        }
+                    }
+                    writeln!(out, "    }}\n}}").unwrap();
+                },
+                TypeKind::FuncPointer{ref ret, ref params} => {
+                    let ret = if ret.pointers.is_empty() {
+                        ret.base.clone()
+                    } else {
+                        self.rust_type(ret)
+                    };
+                    writeln!(out, "pub type {} = extern fn{};", self.type_name(name),
+                             self.function_signature(params, &ret)).unwrap();
+                },
+                TypeKind::Struct(ref members) | TypeKind::Union(ref members) => {
+                    let is_union = if let TypeKind::Union(_) = *self.kinds[name] {true} else {false};
+                    let derives = if is_union {"Clone, Copy".to_string()} else {self.derives(members)};
+                    writeln!(out, "#[repr(C)]\n#[derive({})]\npub {} {} {{", derives,
+                             if is_union {"union"} else {"struct"}, name).unwrap();
+                    for member in members {
+                        writeln!(out, "    pub {}: {},", snake_case(&member.name), self.rust_type(member)).unwrap();
+                    }
+                    writeln!(out, "}}").unwrap();
+                },
+            }
+        }
+
+        // Commands are resolved at runtime into tables, see `vk_functions!` in
+        // vkrs::sys
+        out.push('\n');
+        for name in &self.commands {
+            let command = &self.registry.commands[*name];
+            let ret = match self.kinds.get(command.ret.as_str()) {
+                // vkGet*ProcAddr return null for unknown functions
+                Some(&&TypeKind::FuncPointer{..}) => format!("Option<{}>", self.type_name(&command.ret)),
+                _ => command.ret.clone(),
+            };
+            writeln!(out, "pub type PFN{} = unsafe extern fn{};", name,
+                     self.function_signature(&command.params, &ret)).unwrap();
+        }
+        for &(ref table, extension, ref commands) in &self.tables {
+            out.push('\n');
+            if let Some(extension) = extension {
+                writeln!(out, "// {}", extension).unwrap();
+            }
+            writeln!(out, "vk_functions!({} {{", table).unwrap();
+            for command in commands {
+                writeln!(out, "    {}: PFN{},", command, command).unwrap();
+            }
+            writeln!(out, "}});").unwrap();
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use registry::{parse_xml, Registry};
+    use emit::*;
+
+    const REGISTRY: &'static str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<registry>
+    <types>
+        <type category="basetype">typedef <type>uint32_t</type> <name>VkFlags</name>;</type>
+        <type category="basetype">typedef <type>uint32_t</type> <name>VkBool32</name>;</type>
+        <type category="handle"><type>VK_DEFINE_HANDLE</type>(<name>VkInstance</name>)</type>
+        <type category="handle"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkFence</name>)</type>
+        <type category="handle"><type>VK_DEFINE_HANDLE</type>(<name>VkCommandBuffer</name>)</type>
+        <type category="handle"><type>VK_DEFINE_NON_DISPATCHABLE_HANDLE</type>(<name>VkDebugReportCallbackEXT</name>)</type>
+        <type requires="X11/Xlib.h" name="Display"/>
+        <type requires="X11/Xlib.h" name="Window"/>
+        <type requires="VkQueueFlagBits" category="bitmask">typedef <type>VkFlags</type> <name>VkQueueFlags</name>;</type>
+        <type category="bitmask">typedef <type>VkFlags</type> <name>VkInstanceCreateFlags</name>;</type>
+        <type name="VkQueueFlagBits" category="enum"/>
+        <type name="VkResult" category="enum"/>
+        <type name="VkStructureType" category="enum"/>
+        <type category="funcpointer">typedef void (VKAPI_PTR *<name>PFN_vkVoidFunction</name>)(void);</type>
+        <type category="funcpointer">typedef void (VKAPI_PTR *<name>PFN_vkFreeFunction</name>)(
+    <type>void</type>*                                       pUserData,
+    <type>void</type>*                                       pMemory);</type>
+        <type category="struct" name="VkAllocationCallbacks">
+            <member><type>void</type>*           <name>pUserData</name></member>
+            <member><type>PFN_vkFreeFunction</type>     <name>pfnFree</name></member>
+        </type>
+        <type category="struct" name="VkInstanceCreateInfo">
+            <member values="VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+            <member optional="true">const <type>void</type>*     <name>pNext</name></member>
+            <member optional="true"><type>VkInstanceCreateFlags</type>  <name>flags</name></member>
+            <member optional="true"><type>uint32_t</type>               <name>enabledLayerCount</name></member>
+            <member len="enabledLayerCount,null-terminated">const <type>char</type>* const*      <name>ppEnabledLayerNames</name></member>
+        </type>
+        <type category="struct" name="VkExtent3D">
+            <member><type>uint32_t</type>        <name>width</name></member>
+            <member><type>uint32_t</type>        <name>height</name></member>
+        </type>
+        <type category="struct" name="VkQueueFamilyProperties">
+            <member optional="true"><type>VkQueueFlagBits</type>       <name>queueFlags</name></member>
+            <member><type>VkBool32</type>               <name>sparseResidencyImage2D</name></member>
+            <member><type>VkExtent3D</type>             <name>minImageTransferGranularity</name></member>
+            <member><type>char</type>                   <name>deviceName</name>[<enum>VK_MAX_PHYSICAL_DEVICE_NAME_SIZE</enum>]</member>
+        </type>
+    </types>
+    <enums name="API Constants">
+        <enum type="uint32_t" value="256" name="VK_MAX_PHYSICAL_DEVICE_NAME_SIZE"/>
+        <enum type="float" value="1000.0f" name="VK_LOD_CLAMP_NONE"/>
+        <enum type="uint64_t" value="(~0ULL)" name="VK_WHOLE_SIZE"/>
+    </enums>
+    <enums name="VkResult" type="enum">
+        <enum value="0" name="VK_SUCCESS"/>
+        <enum value="-1" name="VK_ERROR_OUT_OF_HOST_MEMORY"/>
+    </enums>
+    <enums name="VkStructureType" type="enum">
+        <enum value="1" name="VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO"/>
+    </enums>
+    <enums name="VkQueueFlagBits" type="bitmask">
+        <enum bitpos="0" name="VK_QUEUE_GRAPHICS_BIT"/>
+        <enum bitpos="1" name="VK_QUEUE_COMPUTE_BIT"/>
+    </enums>
+    <commands>
+        <command successcodes="VK_SUCCESS" errorcodes="VK_ERROR_OUT_OF_HOST_MEMORY">
+            <proto><type>VkResult</type> <name>vkCreateInstance</name></proto>
+            <param>const <type>VkInstanceCreateInfo</type>* <name>pCreateInfo</name></param>
+            <param optional="true">const <type>VkAllocationCallbacks</type>* <name>pAllocator</name></param>
+            <param><type>VkInstance</type>* <name>pInstance</name></param>
+        </command>
+        <command>
+            <proto><type>PFN_vkVoidFunction</type> <name>vkGetInstanceProcAddr</name></proto>
+            <param optional="true"><type>VkInstance</type> <name>instance</name></param>
+            <param len="null-terminated">const <type>char</type>* <name>pName</name></param>
+        </command>
+        <command>
+            <proto><type>void</type> <name>vkDestroyFence</name></proto>
+            <param><type>VkInstance</type> <name>device</name></param>
+            <param optional="true"><type>VkFence</type> <name>fence</name></param>
+        </command>
+        <command>
+            <proto><type>void</type> <name>vkCmdSetBlendConstants</name></proto>
+            <param><type>VkCommandBuffer</type> <name>commandBuffer</name></param>
+            <param>const <type>float</type> <name>blendConstants</name>[4]</param>
+        </command>
+        <command>
+            <proto><type>void</type> <name>vkDestroyDebugReportCallbackEXT</name></proto>
+            <param><type>VkInstance</type> <name>instance</name></param>
+            <param><type>VkDebugReportCallbackEXT</type> <name>callback</name></param>
+        </command>
+        <command>
+            <proto><type>void</type> <name>vkCreateXlibWindow</name></proto>
+            <param><type>Display</type>* <name>dpy</name></param>
+            <param><type>Window</type> <name>window</name></param>
+        </command>
+    </commands>
+    <feature api="vulkan" name="VK_VERSION_1_0" number="1.0">
+        <require>
+            <type name="VkQueueFamilyProperties"/>
+            <enum name="VK_LOD_CLAMP_NONE"/>
+            <enum name="VK_WHOLE_SIZE"/>
+            <command name="vkCreateInstance"/>
+            <command name="vkDestroyFence"/>
+            <command name="vkGetInstanceProcAddr"/>
+            <command name="vkCmdSetBlendConstants"/>
+            <command name="vkCreateXlibWindow"/>
+        </require>
+    </feature>
+    <extensions>
+        <extension name="VK_EXT_debug_report" number="12" supported="vulkan">
+            <require>
+                <enum offset="1" extends="VkResult" dir="-" name="VK_ERROR_VALIDATION_FAILED_EXT"/>
+                <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT"/>
+                <command name="vkDestroyDebugReportCallbackEXT"/>
+            </require>
+        </extension>
+    </extensions>
+</registry>"#;
+
+    fn generate(extensions: &[String]) -> String {
+        let root = parse_xml(REGISTRY.as_bytes()).unwrap();
+        let mut registry = Registry::new(&root).unwrap();
+        let features = registry.feature_names().iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let selection = registry.select(&features, extensions).unwrap();
+        Generator::new(&registry, &selection).generate()
+    }
+
+    #[test]
+    fn member_names() {
+        assert_eq!(snake_case("pNext"), "p_next");
+        assert_eq!(snake_case("ppEnabledLayerNames"), "pp_enabled_layer_names");
+        assert_eq!(snake_case("sparseResidencyImage2D"), "sparse_residency_image2_d");
+        assert_eq!(snake_case("type"), "ty");
+        assert_eq!(snake_case("vendorID"), "vendor_id");
+        assert_eq!(snake_case("textureCompressionASTC_LDR"), "texture_compression_astc_ldr");
+        assert_eq!(snake_case("residencyStandard2DBlockShape"), "residency_standard2_d_block_shape");
+    }
+
+    #[test]
+    fn structs() {
+        let out = generate(&[]);
+        assert!(out.contains("pub struct VkInstanceCreateInfo {\n    pub s_type: VkStructureType,\n    pub p_next: *const c_void,\n    pub flags: VkInstanceCreateFlags,\n"));
+        assert!(out.contains("    pub pp_enabled_layer_names: *const *const c_char,\n"));
+        assert!(out.contains("    pub queue_flags: VkQueueFlags,\n"));
+        assert!(out.contains("    pub device_name: [c_char; VK_MAX_PHYSICAL_DEVICE_NAME_SIZE as usize],\n"));
+        assert!(out.contains("    pub pfn_free: PFNvkFreeFunction,\n"));
+        assert!(out.contains("#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]\npub struct VkExtent3D"));
+        assert!(out.contains("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub struct VkQueueFamilyProperties"));
+        assert!(out.contains("#[derive(Debug, Clone, Copy)]\npub struct VkAllocationCallbacks"));
+    }
+
+    #[test]
+    fn flags() {
+        let out = generate(&[]);
+        assert!(out.contains("pub flags VkQueueFlags: VkFlags {\n        const VK_QUEUE_GRAPHICS_BIT = 0x00000001,\n        const VK_QUEUE_COMPUTE_BIT = 0x00000002,\n"));
+        assert!(out.contains("pub enum VkInstanceCreateFlags {\n    Reserved = 0,\n}"));
+        assert!(!out.contains("VkQueueFlagBits"));
+    }
+
+    #[test]
+    fn constants() {
+        let out = generate(&[]);
+        assert!(out.contains("pub const VK_MAX_PHYSICAL_DEVICE_NAME_SIZE: uint32_t = 256;\n"));
+        assert!(out.contains("pub const VK_LOD_CLAMP_NONE: c_float = 1000.0;\n"));
+        assert!(out.contains("pub const VK_WHOLE_SIZE: uint64_t = !0;\n"));
+    }
+
+    #[test]
+    fn commands() {
+        let out = generate(&[]);
+        assert!(out.contains("pub type VkInstance = usize;\n"));
+        assert!(out.contains("pub type PFNvkCreateInstance = unsafe extern fn(p_create_info: *const VkInstanceCreateInfo, p_allocator: *const VkAllocationCallbacks, p_instance: *mut VkInstance) -> VkResult;\n"));
+        assert!(out.contains("pub type PFNvkDestroyFence = unsafe extern fn(device: VkInstance, fence: VkFence);\n"));
+        assert!(out.contains("pub type PFNvkFreeFunction = extern fn(p_user_data: *mut c_void, p_memory: *mut c_void);\n"));
+        assert!(out.contains("pub type PFNvkGetInstanceProcAddr = unsafe extern fn(instance: VkInstance, p_name: *const c_char) -> Option<PFNvkVoidFunction>;\n"));
+        assert!(out.contains("pub type PFNvkCmdSetBlendConstants = unsafe extern fn(command_buffer: VkCommandBuffer, blend_constants: *const c_float);\n"));
+        assert!(out.contains("pub type PFNvkCreateXlibWindow = unsafe extern fn(dpy: *mut Display, window: Window);\n"));
+        assert!(out.contains("pub enum Display {}\n"));
+        assert!(out.contains("pub type Window = c_ulong;\n"));
+    }
+
+    #[test]
+    fn tables() {
+        assert_eq!(table_name("VK_EXT_debug_report"), "DebugReportFns");
+        assert_eq!(table_name("VK_KHR_maintenance1"), "Maintenance1Fns");
+
+        let out = generate(&["VK_EXT_debug_report".to_string()]);
+        assert!(out.contains("vk_functions!(EntryFns {\n    vkCreateInstance: PFNvkCreateInstance,\n    vkCreateXlibWindow: PFNvkCreateXlibWindow,\n});\n"));
+        assert!(out.contains("vk_functions!(InstanceFns {\n    vkDestroyFence: PFNvkDestroyFence,\n});\n"));
+        assert!(out.contains("vk_functions!(DeviceFns {\n    vkCmdSetBlendConstants: PFNvkCmdSetBlendConstants,\n});\n"));
+        assert!(out.contains("// VK_EXT_debug_report\nvk_functions!(DebugReportFns {\n    vkDestroyDebugReportCallbackEXT: PFNvkDestroyDebugReportCallbackEXT,\n});\n"));
+        assert!(!out.contains("    vkGetInstanceProcAddr:"));
+        assert!(!generate(&[]).contains("DebugReportFns"));
+    }
+
+    #[test]
+    fn enums() {
+        let out = generate(&[]);
+        assert!(out.contains("vk_enum!(VkResult: int32_t {\n    VK_SUCCESS = 0,\n    VK_ERROR_OUT_OF_HOST_MEMORY = -1,\n});\n"));
+        assert!(!out.contains("pub enum VkResult"));
+    }
+
+    #[test]
+    fn extension_enums() {
+        let out = generate(&["VK_EXT_debug_report".to_string()]);
+        assert!(out.contains("    VK_ERROR_VALIDATION_FAILED_EXT = -1000011001,\n"));
+        assert!(out.contains("    VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT = 1000011000,\n"));
+        assert!(!generate(&[]).contains("VK_ERROR_VALIDATION_FAILED_EXT"));
+    }
+}
@@ -0,0 +1,91 @@
+// Generates the vkrs sys layer from the Khronos registry (vk.xml).
+//
+// Usage: vkrs-gen <vk.xml> <output.rs> [--feature VK_VERSION_X_Y]... [--extension VK_EXT_foo]...
+//
+// All vulkan features (core versions) are emitted unless some are listed
+// explicitly. Extensions must be listed, VK_EXT_debug_report is always
+// included because vkrs wraps it. Window system types are emitted as the
+// integer or pointer types they're declared as, or as opaque enums when they
+// only appear behind pointers.
+
+extern crate xml;
+
+mod registry;
+mod emit;
+
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::process;
+
+fn usage() -> ! {
+    let _ = writeln!(std::io::stderr(), "Usage: vkrs-gen <vk.xml> <output.rs> [--feature NAME]... [--extension NAME]...");
+    process::exit(1)
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let (input, output) = match (args.next(), args.next()) {
+        (Some(input), Some(output)) => (input, output),
+        _ => usage(),
+    };
+
+    let mut features = Vec::new();
+    let mut extensions = vec!("VK_EXT_debug_report".to_string());
+    while let Some(flag) = args.next() {
+        match (flag.as_str(), args.next()) {
+            ("--feature", Some(name)) => features.push(name),
+            ("--extension", Some(name)) => if !extensions.contains(&name) {extensions.push(name)},
+            _ => usage(),
+        }
+    }
+
+    match generate(&input, &features, &extensions) {
+        Ok(generated) => File::create(&output)
+            .and_then(|mut f| f.write_all(generated.as_bytes()))
+            .expect("Could not write output"),
+        Err(e) => {
+            let _ = writeln!(std::io::stderr(), "{}: {}", input, e);
+            process::exit(1)
+        },
+    }
+}
+
+fn generate(input: &str, features: &[String], extensions: &[String]) -> Result<String, String> {
+    let file = File::open(input).map_err(|e| e.to_string())?;
+    let root = registry::parse_xml(file)?;
+    let mut registry = registry::Registry::new(&root)?;
+    let features = if features.is_empty() {
+        registry.feature_names().iter().map(|s| s.to_string()).collect()
+    } else {
+        features.to_vec()
+    };
+    let selection = registry.select(&features, extensions)?;
+    Ok(emit::Generator::new(&registry, &selection).generate())
+}
+
+#[cfg(test)]
+mod tests {
+    // The extensions vkrs generates bindings for, VK_EXT_debug_report first
+    // as `main` adds it
+    const EXTENSIONS: &'static [&'static str] = &[
+        "VK_EXT_debug_report", "VK_KHR_surface", "VK_KHR_swapchain", "VK_KHR_display",
+        "VK_KHR_display_swapchain", "VK_KHR_xlib_surface", "VK_KHR_xcb_surface", "VK_KHR_wayland_surface",
+        "VK_KHR_mir_surface", "VK_KHR_android_surface", "VK_KHR_win32_surface", "VK_NV_glsl_shader",
+        "VK_KHR_maintenance1",
+    ];
+
+    // vkrs checks in the generated sys layer, it must match the vendored registry
+    #[test]
+    fn checked_in_output() {
+        use std::fs::File;
+        use std::io::Read;
+
+        let dir = env!("CARGO_MANIFEST_DIR");
+        let extensions = EXTENSIONS.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        let generated = super::generate(&format!("{}/vk.xml", dir), &[], &extensions).unwrap();
+        let mut checked_in = String::new();
+        File::open(format!("{}/../src/sys/vk.rs", dir)).unwrap().read_to_string(&mut checked_in).unwrap();
+        assert!(generated == checked_in, "src/sys/vk.rs is out of date, regenerate it from gen/vk.xml");
+    }
+}
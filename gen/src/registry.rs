@@ -0,0 +1,523 @@
+use std::collections::HashMap;
+use std::io::Read;
+
+use xml::reader::{EventReader, XmlEvent};
+
+// Minimal DOM, vk.xml mixes text and tags inside declarations so we need both
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+pub struct Element {
+    pub name: String,
+    pub attrs: HashMap<String, String>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.get(name).map(|s| s.as_str())
+    }
+
+    pub fn elements<'a>(&'a self, name: &'a str) -> Box<Iterator<Item=&'a Element> + 'a> {
+        Box::new(self.children.iter().filter_map(move |c| match *c {
+            Node::Element(ref e) if e.name == name => Some(e),
+            _ => None,
+        }))
+    }
+
+    pub fn element<'a>(&'a self, name: &'a str) -> Option<&'a Element> {
+        self.elements(name).next()
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for child in &self.children {
+            match *child {
+                Node::Text(ref t) => text.push_str(t),
+                Node::Element(ref e) if e.name != "comment" => text.push_str(&e.text()),
+                Node::Element(_) => {},
+            }
+        }
+        text
+    }
+
+    fn is_vulkan(&self) -> bool {
+        self.attr("api").map_or(true, |api| api.split(',').any(|a| a == "vulkan"))
+    }
+}
+
+pub fn parse_xml<R: Read>(source: R) -> Result<Element, String> {
+    let mut stack = vec!(Element{name: String::new(), attrs: HashMap::new(), children: Vec::new()});
+    for event in EventReader::new(source) {
+        match event.map_err(|e| format!("Malformed registry: {}", e))? {
+            XmlEvent::StartElement{name, attributes, ..} => {
+                stack.push(Element{
+                    name: name.local_name,
+                    attrs: attributes.into_iter().map(|a| (a.name.local_name, a.value)).collect(),
+                    children: Vec::new(),
+                });
+            },
+            XmlEvent::EndElement{..} => {
+                let element = stack.pop().unwrap();
+                stack.last_mut().unwrap().children.push(Node::Element(element));
+            },
+            XmlEvent::Characters(text) | XmlEvent::Whitespace(text) | XmlEvent::CData(text) => {
+                stack.last_mut().unwrap().children.push(Node::Text(text));
+            },
+            _ => {},
+        }
+    }
+    match stack.pop().unwrap().children.into_iter().filter_map(|c| match c {
+        Node::Element(e) => Some(e),
+        Node::Text(_) => None,
+    }).next() {
+        Some(root) => Ok(root),
+        None => Err("Empty registry".to_string()),
+    }
+}
+
+// A C declaration, e.g. `const char* const* ppEnabledLayerNames[2]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Declaration {
+    pub name: String,
+    pub base: String,
+    // Constness of each pointee, innermost first
+    pub pointers: Vec<bool>,
+    pub array: Option<String>,
+}
+
+impl Declaration {
+    fn parse(element: &Element) -> Option<Self> {
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+        let mut base = None;
+        let mut name = None;
+        let mut array = None;
+        for child in &element.children {
+            match *child {
+                Node::Text(ref t) if base.is_none() => prefix.push_str(t),
+                Node::Text(ref t) if name.is_none() => suffix.push_str(t),
+                Node::Text(ref t) => {
+                    let t = t.trim();
+                    if t.starts_with(':') {
+                        array = Some(t.to_string());
+                    } else if t.starts_with('[') {
+                        let len = t.trim_matches(|c| c == '[' || c == ']');
+                        if !len.is_empty() {array = Some(len.to_string())}
+                    }
+                },
+                Node::Element(ref e) if e.name == "type" => base = Some(e.text()),
+                Node::Element(ref e) if e.name == "name" => name = Some(e.text()),
+                Node::Element(ref e) if e.name == "enum" => array = Some(e.text()),
+                Node::Element(_) => {},
+            }
+        }
+        // Bitfields are not representable, skip them
+        if array.as_ref().map_or(false, |a| a.starts_with(':')) {
+            return None;
+        }
+
+        let mut pointers = Vec::new();
+        let mut is_const = prefix.contains("const");
+        for token in suffix.replace("*", " * ").split_whitespace() {
+            match token {
+                "const" => is_const = true,
+                "*" => {pointers.push(is_const); is_const = false},
+                _ => {},
+            }
+        }
+        match (name, base) {
+            (Some(name), Some(base)) => Some(Declaration{
+                name: name, base: base, pointers: pointers, array: array
+            }),
+            _ => None,
+        }
+    }
+
+    // Function pointer parameters are plain text, e.g. `const char* pMessage`
+    fn parse_text(text: &str) -> Option<Self> {
+        let text = text.replace("*", " * ");
+        let mut tokens = text.split_whitespace().collect::<Vec<_>>();
+        let name = match tokens.pop() {
+            Some(name) => name.to_string(),
+            None => return None,
+        };
+        let mut pointers = Vec::new();
+        let mut is_const = false;
+        let mut base = None;
+        for token in tokens {
+            match token {
+                "const" => is_const = true,
+                "*" => {pointers.push(is_const); is_const = false},
+                "struct" => {},
+                t => base = Some(t.to_string()),
+            }
+        }
+        base.map(|base| Declaration{name: name, base: base, pointers: pointers, array: None})
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeKind {
+    Basetype(String),
+    Bitmask{bits: Option<String>, underlying: String},
+    Handle,
+    Enum,
+    FuncPointer{ret: Declaration, params: Vec<Declaration>},
+    Struct(Vec<Declaration>),
+    Union(Vec<Declaration>),
+    Alias(String),
+    External,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Bits(u64),
+    Literal(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constant {
+    pub name: String,
+    pub value: Value,
+    pub ty: Option<String>,
+}
+
+pub struct Command {
+    pub ret: String,
+    pub params: Vec<Declaration>,
+}
+
+pub struct Registry {
+    // Types in registry order, which is close to dependency order
+    pub types: Vec<(String, TypeKind)>,
+    pub constants: HashMap<String, Constant>,
+    pub enums: HashMap<String, Vec<Constant>>,
+    pub commands: HashMap<String, Command>,
+    pub command_aliases: HashMap<String, String>,
+    features: Vec<(String, Element)>,
+    extensions: Vec<(String, Option<u32>, Element)>,
+}
+
+fn parse_int(value: &str) -> Option<i64> {
+    if value.starts_with("0x") {
+        i64::from_str_radix(&value[2..], 16).ok()
+    } else {
+        value.parse().ok()
+    }
+}
+
+fn attr<'a>(element: &'a Element, name: &str) -> Result<&'a str, String> {
+    element.attr(name).ok_or_else(|| format!("<{}> without a {} attribute", element.name, name))
+}
+
+fn enum_value(element: &Element, extnumber: Option<u32>) -> Result<Option<Value>, String> {
+    let name = attr(element, "name")?;
+    if let Some(bitpos) = element.attr("bitpos") {
+        return match bitpos.parse::<u32>() {
+            Ok(b) if b < 64 => Ok(Some(Value::Bits(1 << b))),
+            _ => Err(format!("{}: invalid bitpos {}", name, bitpos)),
+        };
+    }
+    if let Some(offset) = element.attr("offset") {
+        let extnumber = match element.attr("extnumber") {
+            Some(n) => Some(n.parse::<u32>().map_err(|_| format!("{}: invalid extnumber {}", name, n))?),
+            None => extnumber,
+        };
+        let extnumber = match extnumber {
+            Some(n) if n > 0 => n,
+            _ => return Err(format!("{}: offset outside of a numbered extension", name)),
+        };
+        let offset = offset.parse::<i64>().map_err(|_| format!("{}: invalid offset {}", name, offset))?;
+        let value = 1_000_000_000 + (extnumber as i64 - 1) * 1000 + offset;
+        return Ok(Some(Value::Int(if element.attr("dir") == Some("-") {-value} else {value})));
+    }
+    Ok(element.attr("value").map(|v| match parse_int(v) {
+        Some(v) => Value::Int(v),
+        None => Value::Literal(v.to_string()),
+    }))
+}
+
+impl Registry {
+    pub fn new(root: &Element) -> Result<Self, String> {
+        let mut registry = Registry{
+            types: Vec::new(),
+            constants: HashMap::new(),
+            enums: HashMap::new(),
+            commands: HashMap::new(),
+            command_aliases: HashMap::new(),
+            features: Vec::new(),
+            extensions: Vec::new(),
+        };
+
+        for types in root.elements("types") {
+            for ty in types.elements("type").filter(|t| t.is_vulkan()) {
+                if let Some((name, kind)) = Registry::parse_type(ty)? {
+                    registry.types.push((name, kind));
+                }
+            }
+        }
+
+        for enums in root.elements("enums") {
+            let name = enums.attr("name").unwrap_or("").to_string();
+            let mut values = Vec::new();
+            for e in enums.elements("enum").filter(|e| e.is_vulkan() && e.attr("alias").is_none()) {
+                if let Some(value) = enum_value(e, None)? {
+                    values.push(Constant{
+                        name: attr(e, "name")?.to_string(),
+                        value: value,
+                        ty: e.attr("type").map(|t| t.to_string()),
+                    });
+                }
+            }
+            match enums.attr("type") {
+                Some("enum") | Some("bitmask") => {registry.enums.insert(name, values);},
+                _ => for value in values {
+                    registry.constants.insert(value.name.clone(), value);
+                },
+            }
+        }
+
+        for commands in root.elements("commands") {
+            for command in commands.elements("command").filter(|c| c.is_vulkan()) {
+                if let (Some(name), Some(alias)) = (command.attr("name"), command.attr("alias")) {
+                    registry.command_aliases.insert(name.to_string(), alias.to_string());
+                    continue;
+                }
+                let proto = match command.element("proto").and_then(Declaration::parse) {
+                    Some(proto) => proto,
+                    None => continue,
+                };
+                let params = command.elements("param")
+                    .filter(|p| p.is_vulkan())
+                    .filter_map(Declaration::parse)
+                    .collect();
+                registry.commands.insert(proto.name, Command{ret: proto.base, params: params});
+            }
+        }
+
+        for child in &root.children {
+            if let Node::Element(ref e) = *child {
+                if e.name == "feature" && e.is_vulkan() {
+                    let name = attr(e, "name")?.to_string();
+                    registry.features.push((name, Registry::shallow_copy(e)));
+                }
+            }
+        }
+        for extensions in root.elements("extensions") {
+            for ext in extensions.elements("extension") {
+                let name = attr(ext, "name")?.to_string();
+                let number = match ext.attr("number") {
+                    Some(n) => Some(n.parse().map_err(|_| format!("{}: invalid number {}", name, n))?),
+                    None => None,
+                };
+                registry.extensions.push((name, number, Registry::shallow_copy(ext)));
+            }
+        }
+
+        Ok(registry)
+    }
+
+    // Keep only the <require> blocks, everything else in features is prose
+    fn shallow_copy(element: &Element) -> Element {
+        Element{
+            name: element.name.clone(),
+            attrs: element.attrs.clone(),
+            children: element.elements("require").filter(|r| r.is_vulkan()).map(|r| {
+                Node::Element(Element{
+                    name: r.name.clone(),
+                    attrs: r.attrs.clone(),
+                    children: r.children.iter().filter_map(|c| match *c {
+                        Node::Element(ref e) => Some(Node::Element(Element{
+                            name: e.name.clone(), attrs: e.attrs.clone(), children: Vec::new()
+                        })),
+                        Node::Text(_) => None,
+                    }).collect(),
+                })
+            }).collect(),
+        }
+    }
+
+    // Types vkrs-gen can't represent are skipped, malformed ones are errors
+    fn parse_type(ty: &Element) -> Result<Option<(String, TypeKind)>, String> {
+        let name = ty.attr("name").map(|n| n.to_string())
+            .or_else(|| ty.element("name").map(|n| n.text()))
+            .or_else(|| ty.element("proto").and_then(|p| p.element("name")).map(|n| n.text()));
+        let name = match name {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        if let Some(alias) = ty.attr("alias") {
+            return Ok(Some((name, TypeKind::Alias(alias.to_string()))));
+        }
+        let kind = match ty.attr("category") {
+            Some("basetype") => match ty.element("type") {
+                Some(underlying) => TypeKind::Basetype(underlying.text()),
+                None => TypeKind::External,
+            },
+            Some("bitmask") => TypeKind::Bitmask{
+                bits: ty.attr("requires").or(ty.attr("bitvalues")).map(|b| b.to_string()),
+                underlying: ty.element("type").map_or("VkFlags".to_string(), |t| t.text()),
+            },
+            Some("handle") => TypeKind::Handle,
+            Some("enum") => TypeKind::Enum,
+            // Newer registries describe function pointers like commands
+            Some("funcpointer") if ty.element("proto").is_some() => {
+                let mut ret = match Declaration::parse(ty.element("proto").unwrap()) {
+                    Some(ret) => ret,
+                    None => return Err(format!("{}: malformed return type", name)),
+                };
+                // The last pointer is the function pointer itself
+                ret.pointers.pop();
+                TypeKind::FuncPointer{
+                    ret: ret,
+                    params: ty.elements("param").filter_map(Declaration::parse).collect(),
+                }
+            },
+            Some("funcpointer") => {
+                let text = ty.text();
+                let ret = text.trim_left_matches("typedef").split('(').next().unwrap().trim();
+                let ret = match Declaration::parse_text(&format!("{} ret", ret)) {
+                    Some(ret) => ret,
+                    None => return Err(format!("{}: malformed return type", name)),
+                };
+                let params = text.rsplit(")(").next().unwrap()
+                    .trim_right_matches(';').trim_right_matches(')')
+                    .split(',')
+                    .filter(|p| p.trim() != "void")
+                    .filter_map(Declaration::parse_text)
+                    .collect();
+                TypeKind::FuncPointer{ret: ret, params: params}
+            },
+            Some(c) if c == "struct" || c == "union" => {
+                let members = ty.elements("member")
+                    .filter(|m| m.is_vulkan())
+                    .map(Declaration::parse)
+                    .collect::<Option<Vec<_>>>();
+                match members {
+                    Some(ref m) if c == "struct" => TypeKind::Struct(m.clone()),
+                    Some(m) => TypeKind::Union(m),
+                    // Contains bitfields
+                    None => return Ok(None),
+                }
+            },
+            Some(_) => return Ok(None),
+            None => TypeKind::External,
+        };
+        Ok(Some((name, kind)))
+    }
+
+    pub fn feature_names(&self) -> Vec<&str> {
+        self.features.iter().map(|&(ref n, _)| n.as_str()).collect()
+    }
+
+    // Collects everything required by the given features and extensions.
+    // Enum extensions are merged into self.enums as a side effect.
+    pub fn select(&mut self, features: &[String], extensions: &[String]) -> Result<Selection, String> {
+        let mut selection = Selection::default();
+        let mut requires = Vec::new();
+        for &(ref name, ref feature) in &self.features {
+            if features.contains(name) {
+                requires.push((None, None, feature));
+            }
+        }
+        for ext in extensions {
+            match self.extensions.iter().find(|&&(ref name, _, _)| name == ext) {
+                Some(&(ref name, number, ref element)) => requires.push((Some(name), number, element)),
+                None => return Err(format!("Unknown extension {}", ext)),
+            }
+        }
+
+        let mut additions = Vec::new();
+        for (extension, number, element) in requires {
+            let mut commands = Vec::new();
+            for require in element.elements("require") {
+                for item in require.elements("type") {
+                    selection.types.push(attr(item, "name")?.to_string());
+                }
+                for item in require.elements("command") {
+                    commands.push(attr(item, "name")?.to_string());
+                }
+                for item in require.elements("enum") {
+                    let name = attr(item, "name")?.to_string();
+                    match (item.attr("extends"), item.attr("alias")) {
+                        (Some(_), Some(_)) => {},
+                        (Some(extends), None) => if let Some(value) = enum_value(item, number)? {
+                            additions.push((extends.to_string(), Constant{name: name.clone(), value: value, ty: None}));
+                        },
+                        // Extension name and version constants
+                        (None, _) => if let Some(value) = enum_value(item, number)? {
+                            self.constants.insert(name.clone(), Constant{name: name.clone(), value: value, ty: None});
+                        },
+                    }
+                    if item.attr("extends").is_none() {
+                        selection.constants.push(name);
+                    }
+                }
+            }
+            selection.commands.extend(commands.iter().cloned());
+            if let Some(extension) = extension {
+                if !commands.is_empty() {
+                    selection.extension_commands.push((extension.clone(), commands));
+                }
+            }
+        }
+
+        for (extends, value) in additions {
+            let values = self.enums.entry(extends).or_insert_with(Vec::new);
+            if !values.iter().any(|v| v.name == value.name) {
+                values.push(value);
+            }
+        }
+        Ok(selection)
+    }
+}
+
+#[derive(Default)]
+pub struct Selection {
+    pub types: Vec<String>,
+    pub constants: Vec<String>,
+    pub commands: Vec<String>,
+    // The commands each selected extension adds, they get their own table
+    pub extension_commands: Vec<(String, Vec<String>)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use registry::*;
+
+    fn select(extension: &str) -> Result<Selection, String> {
+        let root = parse_xml(format!(r#"<registry>
+    <types>
+        <type name="VkResult" category="enum"/>
+    </types>
+    <enums name="VkResult" type="enum">
+        <enum value="0" name="VK_SUCCESS"/>
+    </enums>
+    <extensions>{}</extensions>
+</registry>"#, extension).as_bytes())?;
+        Registry::new(&root)?.select(&[], &["VK_EXT_foo".to_string()])
+    }
+
+    #[test]
+    fn malformed_entries() {
+        assert!(select(r#"<extension name="VK_EXT_foo" number="3"><require>
+            <enum offset="1" extends="VkResult" name="VK_FOO_EXT"/>
+        </require></extension>"#).is_ok());
+        assert_eq!(select(r#"<extension name="VK_EXT_foo"><require>
+            <enum offset="1" extends="VkResult" name="VK_FOO_EXT"/>
+        </require></extension>"#).err().unwrap(), "VK_FOO_EXT: offset outside of a numbered extension");
+        assert_eq!(select(r#"<extension name="VK_EXT_foo" number="3"><require>
+            <enum offset="one" extends="VkResult" name="VK_FOO_EXT"/>
+        </require></extension>"#).err().unwrap(), "VK_FOO_EXT: invalid offset one");
+        assert_eq!(select(r#"<extension name="VK_EXT_foo" number="3"><require>
+            <enum bitpos="64" extends="VkResult" name="VK_FOO_EXT"/>
+        </require></extension>"#).err().unwrap(), "VK_FOO_EXT: invalid bitpos 64");
+        assert_eq!(select(r#"<extension number="3"/>"#).err().unwrap(), "<extension> without a name attribute");
+        assert_eq!(select("").err().unwrap(), "Unknown extension VK_EXT_foo");
+        assert!(select("<extension>").err().unwrap().starts_with("Malformed registry"));
+    }
+}
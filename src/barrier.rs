@@ -0,0 +1,302 @@
+use sys::command_buffer::{VkMemoryBarrier, VkBufferMemoryBarrier, VkImageMemoryBarrier};
+use sys::common::*;
+use sys::image::{VkImage, VkImageLayout};
+use sys::buffer::VkBuffer;
+use sys::memory::VK_WHOLE_SIZE;
+use sys::queue::VK_QUEUE_FAMILY_IGNORED;
+use sys::instance::{VK_QUEUE_GRAPHICS_BIT, VK_QUEUE_COMPUTE_BIT};
+use device::{PipelineStageFlags, QueueFlags};
+use buffer::Buffer;
+use image::{Image, ImageSubresourceRange};
+use std::marker::PhantomData;
+use std::ptr;
+
+pub type AccessFlags = VkAccessFlags;
+pub type DependencyFlags = VkDependencyFlags;
+
+// The stages and accesses a queue family with `queue_flags` supports in
+// barriers
+fn queue_usage(queue_flags: QueueFlags) -> (PipelineStageFlags, AccessFlags) {
+    let mut stages = VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT | VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT |
+                     VK_PIPELINE_STAGE_TRANSFER_BIT | VK_PIPELINE_STAGE_HOST_BIT | VK_PIPELINE_STAGE_ALL_COMMANDS_BIT;
+    let mut access = VK_ACCESS_TRANSFER_READ_BIT | VK_ACCESS_TRANSFER_WRITE_BIT | VK_ACCESS_HOST_READ_BIT |
+                     VK_ACCESS_HOST_WRITE_BIT | VK_ACCESS_MEMORY_READ_BIT | VK_ACCESS_MEMORY_WRITE_BIT;
+    if queue_flags.intersects(VK_QUEUE_GRAPHICS_BIT | VK_QUEUE_COMPUTE_BIT) {
+        stages |= VK_PIPELINE_STAGE_DRAW_INDIRECT_BIT;
+        access |= VK_ACCESS_INDIRECT_COMMAND_READ_BIT | VK_ACCESS_UNIFORM_READ_BIT | VK_ACCESS_SHADER_READ_BIT |
+                  VK_ACCESS_SHADER_WRITE_BIT;
+    }
+    if queue_flags.contains(VK_QUEUE_COMPUTE_BIT) {
+        stages |= VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT;
+    }
+    if queue_flags.contains(VK_QUEUE_GRAPHICS_BIT) {
+        stages |= VK_PIPELINE_STAGE_VERTEX_INPUT_BIT | VK_PIPELINE_STAGE_VERTEX_SHADER_BIT |
+                  VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT |
+                  VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT | VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT |
+                  VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT | VK_PIPELINE_STAGE_EARLY_FRAGMENT_TESTS_BIT |
+                  VK_PIPELINE_STAGE_LATE_FRAGMENT_TESTS_BIT | VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT |
+                  VK_PIPELINE_STAGE_ALL_GRAPHICS_BIT;
+        access |= VK_ACCESS_INDEX_READ_BIT | VK_ACCESS_VERTEX_ATTRIBUTE_READ_BIT |
+                  VK_ACCESS_INPUT_ATTACHMENT_READ_BIT | VK_ACCESS_COLOR_ATTACHMENT_READ_BIT |
+                  VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT | VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_READ_BIT |
+                  VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT;
+    }
+    (stages, access)
+}
+
+// The stages and accesses which typically use an image in `layout`, to wait
+// for or block around a layout transition on a queue of a family with
+// `queue_flags`. Stages the family doesn't support are left out, e.g. only the
+// compute shader reads SHADER_READ_ONLY_OPTIMAL images on a compute queue.
+// Layouts with none left (attachments outside a graphics queue) wait for all
+// commands.
+pub fn layout_usage(layout: VkImageLayout, queue_flags: QueueFlags) -> (PipelineStageFlags, AccessFlags) {
+    let (stages, access) = match layout {
+        VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED =>
+            (VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT, AccessFlags::empty()),
+        VkImageLayout::VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL =>
+            (VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT,
+             VK_ACCESS_COLOR_ATTACHMENT_READ_BIT | VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT),
+        VkImageLayout::VK_IMAGE_LAYOUT_DEPTH_STENCIL_ATTACHMENT_OPTIMAL =>
+            (VK_PIPELINE_STAGE_EARLY_FRAGMENT_TESTS_BIT | VK_PIPELINE_STAGE_LATE_FRAGMENT_TESTS_BIT,
+             VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_READ_BIT | VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT),
+        VkImageLayout::VK_IMAGE_LAYOUT_DEPTH_STENCIL_READ_ONLY_OPTIMAL =>
+            (VK_PIPELINE_STAGE_EARLY_FRAGMENT_TESTS_BIT | VK_PIPELINE_STAGE_LATE_FRAGMENT_TESTS_BIT |
+             VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT,
+             VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_READ_BIT | VK_ACCESS_SHADER_READ_BIT | VK_ACCESS_INPUT_ATTACHMENT_READ_BIT),
+        VkImageLayout::VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL =>
+            (VK_PIPELINE_STAGE_VERTEX_SHADER_BIT | VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT |
+             VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT,
+             VK_ACCESS_SHADER_READ_BIT | VK_ACCESS_INPUT_ATTACHMENT_READ_BIT),
+        VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL =>
+            (VK_PIPELINE_STAGE_TRANSFER_BIT, VK_ACCESS_TRANSFER_READ_BIT),
+        VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL =>
+            (VK_PIPELINE_STAGE_TRANSFER_BIT, VK_ACCESS_TRANSFER_WRITE_BIT),
+        VkImageLayout::VK_IMAGE_LAYOUT_PREINITIALIZED =>
+            (VK_PIPELINE_STAGE_HOST_BIT, VK_ACCESS_HOST_WRITE_BIT),
+        // VK_IMAGE_LAYOUT_GENERAL, and anything added by extensions
+        _ => (VK_PIPELINE_STAGE_ALL_COMMANDS_BIT, VK_ACCESS_MEMORY_READ_BIT | VK_ACCESS_MEMORY_WRITE_BIT),
+    };
+    let (supported_stages, supported_access) = queue_usage(queue_flags);
+    let stages = stages & supported_stages;
+    (if stages.is_empty() {VK_PIPELINE_STAGE_ALL_COMMANDS_BIT} else {stages}, access & supported_access)
+}
+
+// Makes writes with `src_access` available to accesses with `dst_access`,
+// for all resources
+#[derive(Clone, Copy)]
+pub struct MemoryBarrier {
+    raw: VkMemoryBarrier,
+}
+
+impl MemoryBarrier {
+    pub fn new(src_access: AccessFlags, dst_access: AccessFlags) -> Self {
+        MemoryBarrier {
+            raw: VkMemoryBarrier {
+                s_type: VkStructureType::VK_STRUCTURE_TYPE_MEMORY_BARRIER,
+                p_next: ptr::null(),
+                src_access_mask: src_access,
+                dst_access_mask: dst_access,
+            }
+        }
+    }
+
+    pub(crate) fn raw(&self) -> VkMemoryBarrier {self.raw}
+}
+
+#[derive(Clone, Copy)]
+pub struct BufferMemoryBarrier<'r> {
+    raw: VkBufferMemoryBarrier,
+    buffer: PhantomData<&'r VkBuffer>,
+}
+
+impl<'r> BufferMemoryBarrier<'r> {
+    // Covers the whole buffer
+    pub fn new<'a, 'm>(buffer: &'r Buffer<'a, 'm>, src_access: AccessFlags, dst_access: AccessFlags) -> Self {
+        BufferMemoryBarrier {
+            raw: VkBufferMemoryBarrier {
+                s_type: VkStructureType::VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER,
+                p_next: ptr::null(),
+                src_access_mask: src_access,
+                dst_access_mask: dst_access,
+                src_queue_family_index: VK_QUEUE_FAMILY_IGNORED,
+                dst_queue_family_index: VK_QUEUE_FAMILY_IGNORED,
+                buffer: *buffer.handle(),
+                offset: 0,
+                size: VK_WHOLE_SIZE,
+            },
+            buffer: PhantomData,
+        }
+    }
+
+    pub fn range(mut self, offset: u64, size: u64) -> Self {
+        self.raw.offset = offset;
+        self.raw.size = size;
+        self
+    }
+
+    // Releases ownership from `src_family` and acquires it for `dst_family`.
+    // The same barrier has to be recorded on a queue of each family.
+    pub fn queue_transfer(mut self, src_family: u32, dst_family: u32) -> Self {
+        self.raw.src_queue_family_index = src_family;
+        self.raw.dst_queue_family_index = dst_family;
+        self
+    }
+
+    pub(crate) fn raw(&self) -> VkBufferMemoryBarrier {self.raw}
+}
+
+#[derive(Clone, Copy)]
+pub struct ImageMemoryBarrier<'r> {
+    raw: VkImageMemoryBarrier,
+    image: PhantomData<&'r VkImage>,
+}
+
+impl<'r> ImageMemoryBarrier<'r> {
+    pub fn new<'a, 'm>(image: &'r Image<'a, 'm>, src_access: AccessFlags, dst_access: AccessFlags,
+                       old_layout: VkImageLayout, new_layout: VkImageLayout,
+                       subresource_range: ImageSubresourceRange) -> Self {
+        ImageMemoryBarrier {
+            raw: VkImageMemoryBarrier {
+                s_type: VkStructureType::VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER,
+                p_next: ptr::null(),
+                src_access_mask: src_access,
+                dst_access_mask: dst_access,
+                old_layout: old_layout,
+                new_layout: new_layout,
+                src_queue_family_index: VK_QUEUE_FAMILY_IGNORED,
+                dst_queue_family_index: VK_QUEUE_FAMILY_IGNORED,
+                image: *image.handle(),
+                subresource_range: subresource_range,
+            },
+            image: PhantomData,
+        }
+    }
+
+    // With the access masks of `layout_usage`, for a queue of a family with
+    // `queue_flags`. The old contents are discarded when `old_layout` is
+    // VK_IMAGE_LAYOUT_UNDEFINED.
+    pub fn transition<'a, 'm>(image: &'r Image<'a, 'm>, old_layout: VkImageLayout, new_layout: VkImageLayout,
+                              subresource_range: ImageSubresourceRange, queue_flags: QueueFlags) -> Self {
+        let (_, src_access) = layout_usage(old_layout, queue_flags);
+        let (_, dst_access) = layout_usage(new_layout, queue_flags);
+        ImageMemoryBarrier::new(image, src_access, dst_access, old_layout, new_layout, subresource_range)
+    }
+
+    // Releases ownership from `src_family` and acquires it for `dst_family`.
+    // The same barrier has to be recorded on a queue of each family.
+    pub fn queue_transfer(mut self, src_family: u32, dst_family: u32) -> Self {
+        self.raw.src_queue_family_index = src_family;
+        self.raw.dst_queue_family_index = dst_family;
+        self
+    }
+
+    pub fn old_layout(&self) -> VkImageLayout {self.raw.old_layout}
+    pub fn new_layout(&self) -> VkImageLayout {self.raw.new_layout}
+
+    pub(crate) fn raw(&self) -> VkImageMemoryBarrier {self.raw}
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority, SharingMode};
+    use command_pool::{SplitCommandPool, CommandPool};
+    use command_buffer::{CommandBuffer, PrimaryCommandBuffer, RecordingCommandBuffer, OutsideRenderPass};
+    use sys::command_buffer::VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT;
+    use sys::image::{VkImageType, VkFormat, VK_IMAGE_USAGE_TRANSFER_DST_BIT, VK_IMAGE_USAGE_SAMPLED_BIT,
+                     VK_IMAGE_ASPECT_COLOR_BIT};
+    use sys::buffer::VK_BUFFER_USAGE_TRANSFER_DST_BIT;
+    use sys::instance::VK_QUEUE_TRANSFER_BIT;
+    use image::ImageBuilder;
+    use memory::{DeviceMemory, MemoryPropertyFlags};
+    use fence::Fence;
+    use std::collections::HashMap;
+
+    use barrier::*;
+
+    #[test]
+    fn transition_usage() {
+        let queue_flags = VK_QUEUE_GRAPHICS_BIT | VK_QUEUE_COMPUTE_BIT | VK_QUEUE_TRANSFER_BIT;
+        let (src_stages, src_access) = layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED, queue_flags);
+        assert_eq!((src_stages, src_access), (VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT, AccessFlags::empty()));
+        let (dst_stages, dst_access) = layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL, queue_flags);
+        assert_eq!((dst_stages, dst_access), (VK_PIPELINE_STAGE_TRANSFER_BIT, VK_ACCESS_TRANSFER_WRITE_BIT));
+        let (read_stages, read_access) = layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL,
+                                                      queue_flags);
+        assert!(read_stages.contains(VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT | VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT));
+        assert!(read_access.contains(VK_ACCESS_SHADER_READ_BIT | VK_ACCESS_INPUT_ATTACHMENT_READ_BIT));
+        assert_eq!(layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_GENERAL, queue_flags).0,
+                   VK_PIPELINE_STAGE_ALL_COMMANDS_BIT);
+    }
+
+    #[test]
+    fn compute_transition_usage() {
+        let queue_flags = VK_QUEUE_COMPUTE_BIT | VK_QUEUE_TRANSFER_BIT;
+        assert_eq!(layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL, queue_flags),
+                   (VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT, VK_ACCESS_SHADER_READ_BIT));
+        assert_eq!(layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL, queue_flags),
+                   (VK_PIPELINE_STAGE_TRANSFER_BIT, VK_ACCESS_TRANSFER_WRITE_BIT));
+        // Only graphics stages use attachments
+        assert_eq!(layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL, queue_flags),
+                   (VK_PIPELINE_STAGE_ALL_COMMANDS_BIT, AccessFlags::empty()));
+        assert_eq!(layout_usage(VkImageLayout::VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL, VK_QUEUE_TRANSFER_BIT),
+                   (VK_PIPELINE_STAGE_ALL_COMMANDS_BIT, AccessFlags::empty()));
+    }
+
+    #[test]
+    fn pipeline_barrier() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let extent = VkExtent3D{width: 64, height: 64, depth: 1};
+        let image_memory;
+        let mut image = ImageBuilder::new(VkImageType::VK_IMAGE_TYPE_2D, VkFormat::VK_FORMAT_R8G8B8A8_UNORM, extent)
+            .usage(VK_IMAGE_USAGE_TRANSFER_DST_BIT | VK_IMAGE_USAGE_SAMPLED_BIT)
+            .build(&device).unwrap();
+        let requirements = image.memory_requirements();
+        image_memory = DeviceMemory::allocate(&device, requirements.size, requirements.memory_type_bits,
+                                              MemoryPropertyFlags::empty(), MemoryPropertyFlags::empty()).unwrap();
+        image.bind_memory(&image_memory, 0).unwrap();
+        let range = image.full_range(VK_IMAGE_ASPECT_COLOR_BIT);
+
+        let buffer_memory;
+        let mut buffer = Buffer::new(&device, 256, VK_BUFFER_USAGE_TRANSFER_DST_BIT, &SharingMode::Exclusive).unwrap();
+        let requirements = buffer.memory_requirements();
+        buffer_memory = DeviceMemory::allocate(&device, requirements.size, requirements.memory_type_bits,
+                                               MemoryPropertyFlags::empty(), MemoryPropertyFlags::empty()).unwrap();
+        buffer.bind_memory(&buffer_memory, 0).unwrap();
+
+        let transition = ImageMemoryBarrier::transition(&image, VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL,
+                                                        VkImageLayout::VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL,
+                                                        range, device.queue_family_properties()[0].queue_flags);
+        assert_eq!(transition.new_layout(), VkImageLayout::VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL);
+
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+        let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+        let mut cmd_buf = cmd_buf.begin(VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap();
+        cmd_buf.transition_image_layout(&image, VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED,
+                                        VkImageLayout::VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL, range);
+        cmd_buf.pipeline_barrier(VK_PIPELINE_STAGE_TRANSFER_BIT,
+                                 VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT | VK_PIPELINE_STAGE_HOST_BIT,
+                                 DependencyFlags::empty(),
+                                 &[MemoryBarrier::new(VK_ACCESS_TRANSFER_WRITE_BIT, VK_ACCESS_HOST_READ_BIT)],
+                                 &[BufferMemoryBarrier::new(&buffer, VK_ACCESS_TRANSFER_WRITE_BIT,
+                                                            VK_ACCESS_SHADER_READ_BIT).range(0, 128)],
+                                 &[transition]);
+        let cmd_buf = cmd_buf.end().unwrap();
+        queue.submit(&[&cmd_buf], &[], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,151 @@
+use sys::buffer::*;
+use sys::memory::VkMemoryRequirements;
+use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
+use device::{Device, SharingMode};
+use memory::{DeviceMemory, check_binding};
+use error::{Error, ValidationError};
+use std::mem;
+use std::ptr;
+
+pub type BufferUsageFlags = VkBufferUsageFlags;
+
+pub struct Buffer<'a: 'm, 'm> {
+    handle: VkBuffer,
+    device: &'a Device<'a>,
+    size: u64,
+    usage: BufferUsageFlags,
+    memory: Option<&'m DeviceMemory<'a>>,
+}
+
+impl<'a, 'm> Buffer<'a, 'm> {
+    pub fn new(device: &'a Device, size: u64, usage: BufferUsageFlags, sharing_mode: &SharingMode)
+               -> Result<Self, Error> {
+        let (sharing_mode, families) = sharing_mode.raw(device)?;
+        let create_info = VkBufferCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkBufferCreateFlags::empty(),
+            size: size,
+            usage: usage,
+            sharing_mode: sharing_mode,
+            queue_family_index_count: families.len() as u32,
+            p_queue_family_indices: families.as_ptr(),
+        };
+        let mut buffer = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateBuffer)(*device.handle(), &create_info, ptr::null(), &mut buffer)} {
+            VkResult::VK_SUCCESS => Ok(Buffer{handle: buffer, device: device, size: size, usage: usage, memory: None}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn memory_requirements(&self) -> VkMemoryRequirements {
+        let mut requirements = unsafe {mem::zeroed()};
+        unsafe {(self.device.fns().vkGetBufferMemoryRequirements)(*self.device.handle(), self.handle, &mut requirements)};
+        requirements
+    }
+
+    // A buffer can only be bound once, and the memory must outlive it
+    pub fn bind_memory(&mut self, memory: &'m DeviceMemory<'a>, offset: u64) -> Result<(), Error> {
+        if self.memory.is_some() {
+            return Err(Error::from(ValidationError::MemoryAlreadyBound));
+        }
+        check_binding(memory, offset, &self.memory_requirements())?;
+        match unsafe {(self.device.fns().vkBindBufferMemory)(*self.device.handle(), self.handle,
+                                                             *memory.handle(), offset)} {
+            VkResult::VK_SUCCESS => {
+                self.memory = Some(memory);
+                Ok(())
+            }
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkBuffer {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn size(&self) -> u64 {self.size}
+    pub fn usage(&self) -> BufferUsageFlags {self.usage}
+    pub fn memory(&self) -> Option<&'m DeviceMemory<'a>> {self.memory}
+}
+
+impl<'a, 'm> Drop for Buffer<'a, 'm> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyBuffer)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority, SharingMode};
+    use memory::{DeviceMemory, MemoryPropertyFlags};
+    use error::{Error, ValidationError};
+    use std::collections::HashMap;
+
+    use buffer::*;
+
+    #[test]
+    fn create_buffer() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let buffer = Buffer::new(&device, 1024, VK_BUFFER_USAGE_STORAGE_BUFFER_BIT, &SharingMode::Exclusive).unwrap();
+        assert!(buffer.memory_requirements().size >= 1024);
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn bind_buffer_memory() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        // Declared first, so it outlives the buffer
+        let memory;
+        let mut buffer = Buffer::new(&device, 1024, VK_BUFFER_USAGE_STORAGE_BUFFER_BIT, &SharingMode::Exclusive).unwrap();
+        let requirements = buffer.memory_requirements();
+        memory = DeviceMemory::allocate(&device, requirements.size * 2, requirements.memory_type_bits,
+                                            MemoryPropertyFlags::empty(), MemoryPropertyFlags::empty()).unwrap();
+        if requirements.alignment > 1 {
+            match buffer.bind_memory(&memory, 1) {
+                Err(Error::Validation(ValidationError::MisalignedMemoryOffset{offset: 1, ..})) => {},
+                _ => panic!("Expected a misaligned offset error"),
+            }
+        }
+        match buffer.bind_memory(&memory, requirements.size * 2) {
+            Err(Error::Validation(ValidationError::MemoryRangeOutOfBounds{..})) => {},
+            _ => panic!("Expected an out of bounds error"),
+        }
+        buffer.bind_memory(&memory, requirements.size).unwrap();
+        assert_eq!(buffer.bind_memory(&memory, 0), Err(Error::from(ValidationError::MemoryAlreadyBound)));
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn invalid_sharing_mode() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        match Buffer::new(&device, 1024, VK_BUFFER_USAGE_STORAGE_BUFFER_BIT, &SharingMode::Concurrent(vec!(0, 99))) {
+            Err(Error::Validation(ValidationError::InvalidQueueFamily(99))) => {},
+            _ => panic!("Expected an invalid queue family error"),
+        }
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
@@ -1,9 +1,58 @@
-use sys::common::{VkResult, VkStructureType};
+extern crate libc;
+use self::libc::c_void;
+use sys::common::{VkBool32, VkResult, VkStructureType, VK_NULL_HANDLE};
 use command_pool::{SplitCommandPool, CommandPool};
 use sys::command_buffer::*;
+use sys::render_pass::{VkRenderPassBeginInfo, VkSubpassContents};
+use sys::common::VkRect2D;
+use sys::query::{VkQueryControlFlags, VkQueryPipelineStatisticFlags};
+use device::{Device, PipelineStageFlags, QueueFlags};
+use pipeline::{Pipeline, PipelineLayout, ShaderStageFlags, Viewport, check_push_constants};
+use descriptor::{DescriptorSet, DescriptorPool};
+use sys::pipeline::VkPipelineBindPoint;
+use buffer::Buffer;
+use render_pass::{RenderPass, Framebuffer, ClearValue};
+use query::QueryPool;
+use barrier::{MemoryBarrier, BufferMemoryBarrier, ImageMemoryBarrier, DependencyFlags, layout_usage};
+use image::{Image, ImageSubresourceRange};
+use sys::image::VkImageLayout;
+use std::marker::PhantomData;
+use std::mem;
 use std::ptr;
+use error::{Error, ValidationError};
 
 pub type CommandBufferResetFlags = VkCommandBufferResetFlags;
+pub type CommandBufferUsageFlags = VkCommandBufferUsageFlags;
+pub type QueryControlFlags = VkQueryControlFlags;
+pub type QueryPipelineStatisticFlags = VkQueryPipelineStatisticFlags;
+pub type StencilFaceFlags = VkStencilFaceFlags;
+
+// Command buffer states. Buffers are allocated in the initial state, `begin`
+// starts recording and `end` makes them executable (submittable). While
+// recording, a buffer is InRenderPass between `begin_render_pass` and
+// `end_render_pass`, or for its whole lifetime if it's a secondary buffer
+// begun with `begin_in_render_pass`. Such a buffer ends up
+// ExecutableInRenderPass, and can only be executed inside a render pass.
+pub struct Initial;
+pub struct Recording;
+pub struct InRenderPass;
+pub struct Executable;
+pub struct ExecutableInRenderPass;
+
+// States in which commands can be recorded
+pub trait RecordingState {}
+impl RecordingState for Recording {}
+impl RecordingState for InRenderPass {}
+
+// States in which a buffer can be executed
+pub trait ExecutableState {}
+impl ExecutableState for Executable {}
+impl ExecutableState for ExecutableInRenderPass {}
+
+// Secondary buffers a primary buffer can execute, ended in state `S`
+pub trait SecondaryBuffer<S: ExecutableState> {
+    fn handle(&self) -> &VkCommandBuffer;
+}
 
 pub trait CommandBuffer<'a, P> : Sized
     where P: CommandPool<'a> + 'a {
@@ -11,8 +60,9 @@ pub trait CommandBuffer<'a, P> : Sized
 
     unsafe fn _new(handle: VkCommandBuffer, pool: &'a P) -> Self;
     fn handle(&self) -> &VkCommandBuffer;
+    fn pool(&self) -> &'a P;
 
-    fn allocate(pool: &'a P, n: u32) -> Result<Vec<Self>, VkResult> {
+    fn allocate(pool: &'a P, n: u32) -> Result<Vec<Self>, Error> {
         let allocate_info = VkCommandBufferAllocateInfo{
             s_type: VkStructureType::VK_STRUCTURE_TYPE_COMMAND_BUFFER_ALLOCATE_INFO,
             p_next: ptr::null(),
@@ -21,26 +71,26 @@ pub trait CommandBuffer<'a, P> : Sized
             command_buffer_count: n,
         };
 
-        let device = pool.device().handle();
+        let device = pool.device();
         let mut buffers = Vec::<VkCommandBuffer>::with_capacity(n as usize);
-        match unsafe {vkAllocateCommandBuffers(*device, &allocate_info,
-                                               buffers.as_mut_ptr())} {
+        match unsafe {(device.fns().vkAllocateCommandBuffers)(*device.handle(), &allocate_info,
+                                                              buffers.as_mut_ptr())} {
             VkResult::VK_SUCCESS => {
                 unsafe {buffers.set_len(n as usize)};
                 Ok(buffers.into_iter().map(|buf| {
                     unsafe{Self::_new(buf, pool)}
                 }).collect())
             },
-            x => Err(x)
+            x => Err(Error::from(x))
         }
     }
 }
 
 pub trait ResetableCommandBuffer<'a> : CommandBuffer<'a, SplitCommandPool<'a>> {
-    fn reset(&mut self, flags: CommandBufferResetFlags) -> Result<(), VkResult> {
-        match unsafe {vkResetCommandBuffer(*self.handle(), flags)} {
+    fn reset(&mut self, flags: CommandBufferResetFlags) -> Result<(), Error> {
+        match unsafe {(self.pool().device().fns().vkResetCommandBuffer)(*self.handle(), flags)} {
             VkResult::VK_SUCCESS => Ok(()),
-            x => Err(x)
+            x => Err(Error::from(x))
         }
     }
 }
@@ -48,32 +98,586 @@ pub trait ResetableCommandBuffer<'a> : CommandBuffer<'a, SplitCommandPool<'a>> {
 impl<'a, B> ResetableCommandBuffer<'a> for B
     where B : CommandBuffer<'a, SplitCommandPool<'a>> {}
 
-pub struct PrimaryCommandBuffer<'a, P>
+// Calls shared by primary and secondary buffers, `P` is only used for the device
+fn begin<'a, P>(pool: &P, handle: VkCommandBuffer, flags: CommandBufferUsageFlags,
+                inheritance_info: *const VkCommandBufferInheritanceInfo) -> Result<(), Error>
+    where P: CommandPool<'a>
+{
+    let begin_info = VkCommandBufferBeginInfo {
+        s_type: VkStructureType::VK_STRUCTURE_TYPE_COMMAND_BUFFER_BEGIN_INFO,
+        p_next: ptr::null(),
+        flags: flags,
+        p_inheritance_info: inheritance_info,
+    };
+    match unsafe {(pool.device().fns().vkBeginCommandBuffer)(handle, &begin_info)} {
+        VkResult::VK_SUCCESS => Ok(()),
+        x => Err(Error::from(x)),
+    }
+}
+
+fn end<'a, P: CommandPool<'a>>(pool: &P, handle: VkCommandBuffer) -> Result<(), Error> {
+    match unsafe {(pool.device().fns().vkEndCommandBuffer)(handle)} {
+        VkResult::VK_SUCCESS => Ok(()),
+        x => Err(Error::from(x)),
+    }
+}
+
+fn reset<'a, P: CommandPool<'a>>(pool: &P, handle: VkCommandBuffer, flags: CommandBufferResetFlags)
+                                 -> Result<(), Error> {
+    match unsafe {(pool.device().fns().vkResetCommandBuffer)(handle, flags)} {
+        VkResult::VK_SUCCESS => Ok(()),
+        x => Err(Error::from(x)),
+    }
+}
+
+// Commands which can be recorded into both primary and secondary buffers,
+// inside or outside a render pass. Anything used by a command must stay alive
+// until the buffer has executed.
+pub trait RecordingCommandBuffer<'a> {
+    fn handle(&self) -> &VkCommandBuffer;
+    fn device(&self) -> &Device<'a>;
+    fn queue_family_index(&self) -> u32;
+
+    // Of the family the buffer's pool was created for
+    fn queue_flags(&self) -> QueueFlags {
+        self.device().queue_family_properties()[self.queue_family_index() as usize].queue_flags
+    }
+
+    fn bind_pipeline<T: Pipeline>(&mut self, pipeline: &T) {
+        unsafe {(self.device().fns().vkCmdBindPipeline)(*self.handle(), T::BIND_POINT, *pipeline.handle())}
+    }
+
+    // Binds `sets` to set numbers from `first_set` onwards, with one offset
+    // for each dynamic buffer descriptor
+    fn bind_descriptor_sets<'b, 'r, P>(&mut self, bind_point: VkPipelineBindPoint, layout: &PipelineLayout,
+                                       first_set: u32, sets: &[&DescriptorSet<'b, 'r, P>], dynamic_offsets: &[u32])
+        where P: DescriptorPool<'b>
+    {
+        let sets = sets.iter().map(|s| *s.handle()).collect::<Vec<_>>();
+        unsafe {
+            (self.device().fns().vkCmdBindDescriptorSets)(*self.handle(), bind_point, *layout.handle(), first_set,
+                                                          sets.len() as u32, sets.as_ptr(),
+                                                          dynamic_offsets.len() as u32, dynamic_offsets.as_ptr())
+        }
+    }
+
+    // Updates push constants from `offset` for `stages`, which must match the
+    // ranges of `layout` the bytes fall in
+    fn push_constants(&mut self, layout: &PipelineLayout, stages: ShaderStageFlags, offset: u32, values: &[u8])
+                      -> Result<(), Error> {
+        check_push_constants(layout.push_constant_ranges(), stages, offset, values.len() as u32)?;
+        unsafe {
+            (self.device().fns().vkCmdPushConstants)(*self.handle(), *layout.handle(), stages, offset,
+                                                     values.len() as u32, values.as_ptr() as *const c_void)
+        }
+        Ok(())
+    }
+
+    // Binds (buffer, offset) pairs to vertex input bindings from `first_binding` onwards
+    fn bind_vertex_buffers(&mut self, first_binding: u32, buffers: &[(&Buffer, u64)]) {
+        let (handles, offsets): (Vec<_>, Vec<_>) = buffers.iter().map(|&(b, offset)| (*b.handle(), offset)).unzip();
+        unsafe {
+            (self.device().fns().vkCmdBindVertexBuffers)(*self.handle(), first_binding, handles.len() as u32,
+                                                         handles.as_ptr(), offsets.as_ptr())
+        }
+    }
+
+    fn bind_index_buffer(&mut self, buffer: &Buffer, offset: u64, index_type: VkIndexType) {
+        unsafe {(self.device().fns().vkCmdBindIndexBuffer)(*self.handle(), *buffer.handle(), offset, index_type)}
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_VIEWPORT
+    fn set_viewports(&mut self, first_viewport: u32, viewports: &[Viewport]) {
+        unsafe {
+            (self.device().fns().vkCmdSetViewport)(*self.handle(), first_viewport, viewports.len() as u32,
+                                                   viewports.as_ptr())
+        }
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_SCISSOR
+    fn set_scissors(&mut self, first_scissor: u32, scissors: &[VkRect2D]) {
+        unsafe {
+            (self.device().fns().vkCmdSetScissor)(*self.handle(), first_scissor, scissors.len() as u32,
+                                                  scissors.as_ptr())
+        }
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_LINE_WIDTH. Widths other than
+    // 1.0 need the wide_lines feature.
+    fn set_line_width(&mut self, line_width: f32) -> Result<(), Error> {
+        if line_width != 1.0 && self.device().features().wide_lines != VkBool32::True {
+            return Err(Error::from(ValidationError::FeatureNotEnabled("wide_lines")));
+        }
+        unsafe {(self.device().fns().vkCmdSetLineWidth)(*self.handle(), line_width)};
+        Ok(())
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_DEPTH_BIAS. A non-zero clamp
+    // needs the depth_bias_clamp feature.
+    fn set_depth_bias(&mut self, constant_factor: f32, clamp: f32, slope_factor: f32) -> Result<(), Error> {
+        if clamp != 0.0 && self.device().features().depth_bias_clamp != VkBool32::True {
+            return Err(Error::from(ValidationError::FeatureNotEnabled("depth_bias_clamp")));
+        }
+        unsafe {(self.device().fns().vkCmdSetDepthBias)(*self.handle(), constant_factor, clamp, slope_factor)};
+        Ok(())
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_BLEND_CONSTANTS
+    fn set_blend_constants(&mut self, blend_constants: [f32; 4]) {
+        unsafe {(self.device().fns().vkCmdSetBlendConstants)(*self.handle(), blend_constants.as_ptr())}
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_DEPTH_BOUNDS, both bounds
+    // between 0.0 and 1.0
+    fn set_depth_bounds(&mut self, min: f32, max: f32) {
+        unsafe {(self.device().fns().vkCmdSetDepthBounds)(*self.handle(), min, max)}
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_STENCIL_COMPARE_MASK
+    fn set_stencil_compare_mask(&mut self, faces: StencilFaceFlags, compare_mask: u32) {
+        unsafe {(self.device().fns().vkCmdSetStencilCompareMask)(*self.handle(), faces, compare_mask)}
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_STENCIL_WRITE_MASK
+    fn set_stencil_write_mask(&mut self, faces: StencilFaceFlags, write_mask: u32) {
+        unsafe {(self.device().fns().vkCmdSetStencilWriteMask)(*self.handle(), faces, write_mask)}
+    }
+
+    // Only for pipelines with VK_DYNAMIC_STATE_STENCIL_REFERENCE
+    fn set_stencil_reference(&mut self, faces: StencilFaceFlags, reference: u32) {
+        unsafe {(self.device().fns().vkCmdSetStencilReference)(*self.handle(), faces, reference)}
+    }
+
+    // The query must have been reset since it was last used
+    fn begin_query(&mut self, pool: &QueryPool, query: u32, flags: QueryControlFlags) {
+        unsafe {(self.device().fns().vkCmdBeginQuery)(*self.handle(), *pool.handle(), query, flags)}
+    }
+
+    fn end_query(&mut self, pool: &QueryPool, query: u32) {
+        unsafe {(self.device().fns().vkCmdEndQuery)(*self.handle(), *pool.handle(), query)}
+    }
+
+    // Written once all previous commands have completed `stage`, which must
+    // be a single bit
+    fn write_timestamp(&mut self, stage: PipelineStageFlags, pool: &QueryPool, query: u32) {
+        unsafe {(self.device().fns().vkCmdWriteTimestamp)(*self.handle(), stage, *pool.handle(), query)}
+    }
+
+    // Commands in `dst_stages` wait for those before the barrier in
+    // `src_stages`. Inside a render pass, the subpass needs a dependency on
+    // itself and image barriers can't change layouts.
+    fn pipeline_barrier(&mut self, src_stages: PipelineStageFlags, dst_stages: PipelineStageFlags,
+                        dependency_flags: DependencyFlags, memory_barriers: &[MemoryBarrier],
+                        buffer_barriers: &[BufferMemoryBarrier], image_barriers: &[ImageMemoryBarrier]) {
+        let memory_barriers = memory_barriers.iter().map(|b| b.raw()).collect::<Vec<_>>();
+        let buffer_barriers = buffer_barriers.iter().map(|b| b.raw()).collect::<Vec<_>>();
+        let image_barriers = image_barriers.iter().map(|b| b.raw()).collect::<Vec<_>>();
+        unsafe {
+            (self.device().fns().vkCmdPipelineBarrier)(*self.handle(), src_stages, dst_stages, dependency_flags,
+                                                       memory_barriers.len() as u32, memory_barriers.as_ptr(),
+                                                       buffer_barriers.len() as u32, buffer_barriers.as_ptr(),
+                                                       image_barriers.len() as u32, image_barriers.as_ptr())
+        }
+    }
+}
+
+// Commands which can only be recorded outside a render pass
+pub trait OutsideRenderPass<'a> : RecordingCommandBuffer<'a> {
+    // Records a dispatch of x * y * z local workgroups
+    fn dispatch(&mut self, x: u32, y: u32, z: u32) {
+        unsafe {(self.device().fns().vkCmdDispatch)(*self.handle(), x, y, z)}
+    }
+
+    // Reads the group counts from a VkDispatchIndirectCommand in `buffer`
+    fn dispatch_indirect(&mut self, buffer: &Buffer, offset: u64) {
+        unsafe {(self.device().fns().vkCmdDispatchIndirect)(*self.handle(), *buffer.handle(), offset)}
+    }
+
+    // Waits for and blocks the stages which typically use each layout and
+    // which the buffer's queue family supports, see `layout_usage`
+    fn transition_image_layout(&mut self, image: &Image, old_layout: VkImageLayout, new_layout: VkImageLayout,
+                               subresource_range: ImageSubresourceRange) {
+        let queue_flags = self.queue_flags();
+        let (src_stages, _) = layout_usage(old_layout, queue_flags);
+        let (dst_stages, _) = layout_usage(new_layout, queue_flags);
+        let barrier = ImageMemoryBarrier::transition(image, old_layout, new_layout, subresource_range, queue_flags);
+        self.pipeline_barrier(src_stages, dst_stages, DependencyFlags::empty(), &[], &[], &[barrier]);
+    }
+
+    fn reset_query_pool(&mut self, pool: &QueryPool, first_query: u32, query_count: u32) {
+        unsafe {(self.device().fns().vkCmdResetQueryPool)(*self.handle(), *pool.handle(), first_query, query_count)}
+    }
+}
+
+// Commands which can only be recorded inside a render pass, into a subpass
+// with VK_SUBPASS_CONTENTS_INLINE (or a secondary buffer)
+pub trait InsideRenderPass<'a> : RecordingCommandBuffer<'a> {
+    fn draw(&mut self, vertex_count: u32, instance_count: u32, first_vertex: u32, first_instance: u32) {
+        unsafe {
+            (self.device().fns().vkCmdDraw)(*self.handle(), vertex_count, instance_count, first_vertex, first_instance)
+        }
+    }
+
+    // Indices are read from the bound index buffer, starting at `first_index`
+    fn draw_indexed(&mut self, index_count: u32, instance_count: u32, first_index: u32, vertex_offset: i32,
+                    first_instance: u32) {
+        unsafe {
+            (self.device().fns().vkCmdDrawIndexed)(*self.handle(), index_count, instance_count, first_index,
+                                                   vertex_offset, first_instance)
+        }
+    }
+
+    // Reads `count` VkDrawIndirectCommands from `buffer`, `stride` bytes apart
+    fn draw_indirect(&mut self, buffer: &Buffer, offset: u64, count: u32, stride: u32) {
+        unsafe {(self.device().fns().vkCmdDrawIndirect)(*self.handle(), *buffer.handle(), offset, count, stride)}
+    }
+}
+
+pub struct PrimaryCommandBuffer<'a, P, S = Initial>
     where P : CommandPool<'a> + 'a
 {
     handle: VkCommandBuffer,
     pool: &'a P,
+    // The current subpass, out of the render pass's, while InRenderPass
+    subpass: u32,
+    subpass_count: u32,
+    state: PhantomData<S>,
 }
 
-impl<'a, P> CommandBuffer<'a, P> for PrimaryCommandBuffer<'a, P>
+impl<'a, P> CommandBuffer<'a, P> for PrimaryCommandBuffer<'a, P, Initial>
     where P: CommandPool<'a>
 {
     const LEVEL: VkCommandBufferLevel = VkCommandBufferLevel::VK_COMMAND_BUFFER_LEVEL_PRIMARY;
 
     unsafe fn _new(handle: VkCommandBuffer, pool: &'a P) -> Self {
-        PrimaryCommandBuffer{handle: handle, pool: pool}
+        PrimaryCommandBuffer{handle: handle, pool: pool, subpass: 0, subpass_count: 0, state: PhantomData}
+    }
+    fn handle(&self) -> &VkCommandBuffer {&self.handle}
+    fn pool(&self) -> &'a P {self.pool}
+}
+
+impl<'a, P, S> PrimaryCommandBuffer<'a, P, S>
+    where P: CommandPool<'a>
+{
+    pub fn handle(&self) -> &VkCommandBuffer {&self.handle}
+    pub fn pool(&self) -> &'a P {self.pool}
+
+    fn into_state<T>(self) -> PrimaryCommandBuffer<'a, P, T> {
+        let buffer = PrimaryCommandBuffer{handle: self.handle, pool: self.pool, subpass: self.subpass,
+                                          subpass_count: self.subpass_count, state: PhantomData};
+        mem::forget(self);
+        buffer
+    }
+}
+
+impl<'a, P> PrimaryCommandBuffer<'a, P, Initial>
+    where P: CommandPool<'a>
+{
+    // The buffer is free'd if this fails
+    pub fn begin(self, flags: CommandBufferUsageFlags)
+                 -> Result<PrimaryCommandBuffer<'a, P, Recording>, Error> {
+        begin(self.pool, self.handle, flags, ptr::null()).map(|_| self.into_state())
+    }
+}
+
+impl<'a, P> PrimaryCommandBuffer<'a, P, Recording>
+    where P: CommandPool<'a>
+{
+    // Secondary buffers must stay alive (and unchanged) until this buffer is
+    // no longer in use.
+    pub fn execute_commands<B: SecondaryBuffer<Executable>>(&mut self, buffers: &[&B]) {
+        let buffers = buffers.iter().map(|b| *b.handle()).collect::<Vec<_>>();
+        unsafe {
+            (self.pool.device().fns().vkCmdExecuteCommands)(self.handle, buffers.len() as u32, buffers.as_ptr())
+        }
+    }
+
+    // Clear values are indexed by attachment number, and only read for
+    // attachments with VK_ATTACHMENT_LOAD_OP_CLEAR. The render area must be
+    // within the framebuffer, the buffer is free'd if it isn't.
+    pub fn begin_render_pass<'b, 'r>(self, framebuffer: &Framebuffer<'b, 'r>, render_area: VkRect2D,
+                                     clear_values: &[ClearValue], contents: VkSubpassContents)
+                                     -> Result<PrimaryCommandBuffer<'a, P, InRenderPass>, Error> {
+        let extent = framebuffer.extent();
+        let (offset, size) = (render_area.offset, render_area.extent);
+        if offset.x < 0 || offset.y < 0 || offset.x as u64 + size.width as u64 > extent.width as u64 ||
+            offset.y as u64 + size.height as u64 > extent.height as u64 {
+            return Err(Error::from(ValidationError::InvalidRenderArea{
+                x: offset.x, y: offset.y, width: size.width, height: size.height
+            }));
+        }
+        let begin_info = VkRenderPassBeginInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_RENDER_PASS_BEGIN_INFO,
+            p_next: ptr::null(),
+            render_pass: *framebuffer.render_pass().handle(),
+            framebuffer: *framebuffer.handle(),
+            render_area: render_area,
+            clear_value_count: clear_values.len() as u32,
+            p_clear_values: clear_values.as_ptr(),
+        };
+        unsafe {(self.pool.device().fns().vkCmdBeginRenderPass)(self.handle, &begin_info, contents)};
+        let mut buffer = self.into_state::<InRenderPass>();
+        buffer.subpass = 0;
+        buffer.subpass_count = framebuffer.render_pass().subpass_count();
+        Ok(buffer)
+    }
+
+    pub fn end(self) -> Result<PrimaryCommandBuffer<'a, P, Executable>, Error> {
+        end(self.pool, self.handle).map(|_| self.into_state())
+    }
+}
+
+impl<'a, P> PrimaryCommandBuffer<'a, P, InRenderPass>
+    where P: CommandPool<'a>
+{
+    // Only valid in a subpass begun with VK_SUBPASS_CONTENTS_SECONDARY_COMMAND_BUFFERS
+    pub fn execute_commands<B: SecondaryBuffer<ExecutableInRenderPass>>(&mut self, buffers: &[&B]) {
+        let buffers = buffers.iter().map(|b| *b.handle()).collect::<Vec<_>>();
+        unsafe {
+            (self.pool.device().fns().vkCmdExecuteCommands)(self.handle, buffers.len() as u32, buffers.as_ptr())
+        }
+    }
+
+    // Must be called once for each subpass after the first
+    pub fn next_subpass(&mut self, contents: VkSubpassContents) -> Result<(), Error> {
+        if self.subpass + 1 >= self.subpass_count {
+            return Err(Error::from(ValidationError::InvalidSubpass(self.subpass + 1)));
+        }
+        unsafe {(self.pool.device().fns().vkCmdNextSubpass)(self.handle, contents)};
+        self.subpass += 1;
+        Ok(())
+    }
+
+    pub fn subpass(&self) -> u32 {self.subpass}
+
+    // Only in the last subpass, the buffer is free'd otherwise
+    pub fn end_render_pass(self) -> Result<PrimaryCommandBuffer<'a, P, Recording>, Error> {
+        if self.subpass + 1 != self.subpass_count {
+            return Err(Error::from(ValidationError::RenderPassNotFinished{
+                subpass: self.subpass, subpass_count: self.subpass_count
+            }));
+        }
+        unsafe {(self.pool.device().fns().vkCmdEndRenderPass)(self.handle)};
+        Ok(self.into_state())
+    }
+}
+
+impl<'a, P, S> RecordingCommandBuffer<'a> for PrimaryCommandBuffer<'a, P, S>
+    where P: CommandPool<'a>, S: RecordingState
+{
+    fn handle(&self) -> &VkCommandBuffer {&self.handle}
+    fn device(&self) -> &Device<'a> {self.pool.device()}
+    fn queue_family_index(&self) -> u32 {self.pool.queue_family_index()}
+}
+
+impl<'a, P> OutsideRenderPass<'a> for PrimaryCommandBuffer<'a, P, Recording>
+    where P: CommandPool<'a> {}
+
+impl<'a, P> InsideRenderPass<'a> for PrimaryCommandBuffer<'a, P, InRenderPass>
+    where P: CommandPool<'a> {}
+
+impl<'a> PrimaryCommandBuffer<'a, SplitCommandPool<'a>, Executable> {
+    // Only buffers from a SplitCommandPool can be reset individually
+    pub fn reset(self, flags: CommandBufferResetFlags)
+                 -> Result<PrimaryCommandBuffer<'a, SplitCommandPool<'a>, Initial>, Error> {
+        reset(self.pool, self.handle, flags).map(|_| self.into_state())
+    }
+}
+
+impl<'a, P: CommandPool<'a>, S> Drop for PrimaryCommandBuffer<'a, P, S> {
+    fn drop(&mut self) {
+        unsafe {
+            (self.pool.device().fns().vkFreeCommandBuffers)(*self.pool.device().handle(), *self.pool.handle(), 1, &self.handle)
+        }
+    }
+}
+
+// State a secondary buffer inherits from the primary buffer executing it.
+// The render pass, subpass and framebuffer are only used by
+// `begin_in_render_pass`, which needs the render pass. The framebuffer may
+// be left out.
+#[derive(Clone, Copy)]
+pub struct Inheritance<'r, 'a: 'r> {
+    pub render_pass: Option<&'r RenderPass<'a>>,
+    pub subpass: u32,
+    pub framebuffer: Option<&'r Framebuffer<'a, 'r>>,
+    // Enables inheriting occlusion queries, with the given control flags
+    pub occlusion_query: Option<QueryControlFlags>,
+    pub pipeline_statistics: QueryPipelineStatisticFlags,
+}
+
+impl<'r, 'a> Default for Inheritance<'r, 'a> {
+    fn default() -> Self {
+        Inheritance {
+            render_pass: None,
+            subpass: 0,
+            framebuffer: None,
+            occlusion_query: None,
+            pipeline_statistics: QueryPipelineStatisticFlags::empty(),
+        }
+    }
+}
+
+impl<'r, 'a> Inheritance<'r, 'a> {
+    fn raw(&self) -> VkCommandBufferInheritanceInfo {
+        VkCommandBufferInheritanceInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_INFO,
+            p_next: ptr::null(),
+            render_pass: self.render_pass.map_or(VK_NULL_HANDLE, |r| *r.handle()),
+            subpass: self.subpass,
+            framebuffer: self.framebuffer.map_or(VK_NULL_HANDLE, |f| *f.handle()),
+            occlusion_query_enable: if self.occlusion_query.is_some() {
+                VkBool32::True
+            } else {
+                VkBool32::False
+            },
+            query_flags: self.occlusion_query.unwrap_or(QueryControlFlags::empty()),
+            pipeline_statistics: self.pipeline_statistics,
+        }
+    }
+}
+
+pub struct SecondaryCommandBuffer<'a, P, S = Initial>
+    where P : CommandPool<'a> + 'a
+{
+    handle: VkCommandBuffer,
+    pool: &'a P,
+    state: PhantomData<S>,
+}
+
+impl<'a, P> CommandBuffer<'a, P> for SecondaryCommandBuffer<'a, P, Initial>
+    where P: CommandPool<'a>
+{
+    const LEVEL: VkCommandBufferLevel = VkCommandBufferLevel::VK_COMMAND_BUFFER_LEVEL_SECONDARY;
+
+    unsafe fn _new(handle: VkCommandBuffer, pool: &'a P) -> Self {
+        SecondaryCommandBuffer{handle: handle, pool: pool, state: PhantomData}
     }
     fn handle(&self) -> &VkCommandBuffer {&self.handle}
+    fn pool(&self) -> &'a P {self.pool}
 }
 
-impl<'a, P: CommandPool<'a>> Drop for PrimaryCommandBuffer<'a, P> {
+impl<'a, P, S> SecondaryCommandBuffer<'a, P, S>
+    where P: CommandPool<'a>
+{
+    pub fn handle(&self) -> &VkCommandBuffer {&self.handle}
+    pub fn pool(&self) -> &'a P {self.pool}
+
+    fn into_state<T>(self) -> SecondaryCommandBuffer<'a, P, T> {
+        let buffer = SecondaryCommandBuffer{handle: self.handle, pool: self.pool, state: PhantomData};
+        mem::forget(self);
+        buffer
+    }
+}
+
+impl<'a, P> SecondaryCommandBuffer<'a, P, Initial>
+    where P: CommandPool<'a>
+{
+    pub fn begin(self, flags: CommandBufferUsageFlags, inheritance: &Inheritance)
+                 -> Result<SecondaryCommandBuffer<'a, P, Recording>, Error> {
+        let inheritance_info = inheritance.raw();
+        begin(self.pool, self.handle, flags, &inheritance_info).map(|_| self.into_state())
+    }
+
+    // For a buffer executed entirely inside the inherited render pass and
+    // subpass, adds VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT
+    pub fn begin_in_render_pass(self, flags: CommandBufferUsageFlags, inheritance: &Inheritance)
+                                -> Result<SecondaryCommandBuffer<'a, P, InRenderPass>, Error> {
+        match inheritance.render_pass {
+            Some(render_pass) if inheritance.subpass >= render_pass.subpass_count() =>
+                return Err(Error::from(ValidationError::InvalidSubpass(inheritance.subpass))),
+            None => return Err(Error::from(ValidationError::NoInheritedRenderPass)),
+            _ => {},
+        }
+        let inheritance_info = inheritance.raw();
+        begin(self.pool, self.handle, flags | VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT,
+              &inheritance_info).map(|_| self.into_state())
+    }
+}
+
+impl<'a, P> SecondaryCommandBuffer<'a, P, Recording>
+    where P: CommandPool<'a>
+{
+    pub fn end(self) -> Result<SecondaryCommandBuffer<'a, P, Executable>, Error> {
+        end(self.pool, self.handle).map(|_| self.into_state())
+    }
+}
+
+impl<'a, P> SecondaryCommandBuffer<'a, P, InRenderPass>
+    where P: CommandPool<'a>
+{
+    pub fn end(self) -> Result<SecondaryCommandBuffer<'a, P, ExecutableInRenderPass>, Error> {
+        end(self.pool, self.handle).map(|_| self.into_state())
+    }
+}
+
+impl<'a, P, S> RecordingCommandBuffer<'a> for SecondaryCommandBuffer<'a, P, S>
+    where P: CommandPool<'a>, S: RecordingState
+{
+    fn handle(&self) -> &VkCommandBuffer {&self.handle}
+    fn device(&self) -> &Device<'a> {self.pool.device()}
+    fn queue_family_index(&self) -> u32 {self.pool.queue_family_index()}
+}
+
+impl<'a, P> OutsideRenderPass<'a> for SecondaryCommandBuffer<'a, P, Recording>
+    where P: CommandPool<'a> {}
+
+impl<'a, P> InsideRenderPass<'a> for SecondaryCommandBuffer<'a, P, InRenderPass>
+    where P: CommandPool<'a> {}
+
+impl<'a, P, S> SecondaryCommandBuffer<'a, P, S>
+    where P: CommandPool<'a>, S: ExecutableState
+{
+    // Gives up freeing the buffer, leaving it to the pool when it's reset or
+    // destroyed. Executing a buffer doesn't use its pool, so a detached one
+    // can be sent back to the thread recording the primary buffer while
+    // another thread keeps using the pool.
+    pub fn detach(self) -> DetachedCommandBuffer<'a, S> {
+        let buffer = DetachedCommandBuffer{handle: self.handle, pool: PhantomData, state: PhantomData};
+        mem::forget(self);
+        buffer
+    }
+}
+
+impl<'a, P, S> SecondaryBuffer<S> for SecondaryCommandBuffer<'a, P, S>
+    where P: CommandPool<'a>, S: ExecutableState
+{
+    fn handle(&self) -> &VkCommandBuffer {&self.handle}
+}
+
+impl<'a, S> SecondaryCommandBuffer<'a, SplitCommandPool<'a>, S>
+    where S: ExecutableState
+{
+    pub fn reset(self, flags: CommandBufferResetFlags)
+                 -> Result<SecondaryCommandBuffer<'a, SplitCommandPool<'a>, Initial>, Error> {
+        reset(self.pool, self.handle, flags).map(|_| self.into_state())
+    }
+}
+
+impl<'a, P: CommandPool<'a>, S> Drop for SecondaryCommandBuffer<'a, P, S> {
     fn drop(&mut self) {
         unsafe {
-            vkFreeCommandBuffers(*self.pool.device().handle(), *self.pool.handle(), 1, &self.handle)
+            (self.pool.device().fns().vkFreeCommandBuffers)(*self.pool.device().handle(), *self.pool.handle(), 1, &self.handle)
         }
     }
 }
 
+// An executable secondary buffer which no longer holds on to its pool, see
+// SecondaryCommandBuffer::detach. It can't outlive the pool's borrow.
+pub struct DetachedCommandBuffer<'a, S> {
+    handle: VkCommandBuffer,
+    pool: PhantomData<&'a ()>,
+    state: PhantomData<S>,
+}
+
+impl<'a, S> DetachedCommandBuffer<'a, S> {
+    pub fn handle(&self) -> &VkCommandBuffer {&self.handle}
+}
+
+impl<'a, S: ExecutableState> SecondaryBuffer<S> for DetachedCommandBuffer<'a, S> {
+    fn handle(&self) -> &VkCommandBuffer {&self.handle}
+}
+
 #[cfg(test)]
 mod test {
     use instance::debug_instance;
@@ -84,6 +688,8 @@ mod test {
     use std::collections::HashMap;
     use command_pool::{SplitCommandPool, CommandPool};
     use command_buffer::*;
+    use fence::Fence;
+    use std::thread;
 
     #[test]
     fn allocate_command_buffer() {
@@ -114,4 +720,87 @@ mod test {
         drop(dbg);
         assert!(!errs.load(Ordering::Relaxed));
     }
+
+    #[test]
+    fn record_command_buffer() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+        let primary_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+
+        let primary_buf = primary_buf.begin(VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap().end().unwrap();
+        queue.submit(&[&primary_buf], &[], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+
+        let primary_buf = primary_buf.reset(CommandBufferResetFlags::empty()).unwrap();
+        assert!(primary_buf.begin(CommandBufferUsageFlags::empty()).unwrap().end().is_ok());
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn execute_secondary() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+        let secondary_bufs = SecondaryCommandBuffer::allocate(&cmd_pool, 2).unwrap().into_iter()
+            .map(|b| b.begin(CommandBufferUsageFlags::empty(), &Inheritance::default()).unwrap().end().unwrap())
+            .collect::<Vec<_>>();
+
+        let mut primary_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+            .begin(VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap();
+        primary_buf.execute_commands(&secondary_bufs.iter().collect::<Vec<_>>());
+        let primary_buf = primary_buf.end().unwrap();
+        queue.submit(&[&primary_buf], &[], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn execute_secondaries_from_threads() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        // One pool per thread, each borrowed mutably by its thread only
+        let mut pools = (0..4).map(|_| SplitCommandPool::new(&device, 0, false).unwrap()).collect::<Vec<_>>();
+        let secondary_bufs = thread::scope(|scope| {
+            let threads = pools.iter_mut().map(|pool| scope.spawn(move || {
+                let pool: &SplitCommandPool = pool;
+                SecondaryCommandBuffer::allocate(pool, 2).unwrap().into_iter()
+                    .map(|b| b.begin(CommandBufferUsageFlags::empty(), &Inheritance::default()).unwrap()
+                         .end().unwrap().detach())
+                    .collect::<Vec<_>>()
+            })).collect::<Vec<_>>();
+            threads.into_iter().flat_map(|t| t.join().unwrap()).collect::<Vec<_>>()
+        });
+        assert_eq!(secondary_bufs.len(), 8);
+
+        let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+        let mut primary_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+            .begin(VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap();
+        primary_buf.execute_commands(&secondary_bufs.iter().collect::<Vec<_>>());
+        let primary_buf = primary_buf.end().unwrap();
+        queue.submit(&[&primary_buf], &[], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
 }
@@ -2,18 +2,28 @@ use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
 use device::Device;
 use sys::command_pool::*;
 use std::ptr;
+use std::cell::Cell;
+use std::marker::PhantomData;
+use error::Error;
 
 pub type CommandPoolCreateFlags = VkCommandPoolCreateFlags;
 pub type CommandPoolResetFlags = VkCommandPoolResetFlags;
 
+// Vulkan requires a pool and the command buffers allocated from it to be used
+// by one thread at a time. Pools can be sent to another thread but aren't
+// Sync, so their buffers (which borrow the pool) can't leave the thread that
+// owns it. Record in parallel with one pool per thread, and detach the
+// executable secondary buffers to send them to the thread recording the
+// primary buffer.
 pub trait CommandPool<'a> : Sized {
     const BUFFER_RESET: bool;
 
-    unsafe fn _new(handle: VkCommandPool, device: &'a Device<'a>) -> Self;
+    unsafe fn _new(handle: VkCommandPool, device: &'a Device<'a>, queue_family_index: u32) -> Self;
     fn handle(&self) -> &VkCommandPool;
     fn device(&self) -> &Device<'a>;
+    fn queue_family_index(&self) -> u32;
 
-    fn new(device: &'a Device, queue_family_index: u32, transient: bool) -> Result<Self, VkResult> {
+    fn new(device: &'a Device, queue_family_index: u32, transient: bool) -> Result<Self, Error> {
         let create_info = VkCommandPoolCreateInfo {
             s_type: VkStructureType::VK_STRUCTURE_TYPE_COMMAND_POOL_CREATE_INFO,
             p_next: ptr::null(),
@@ -30,62 +40,68 @@ pub trait CommandPool<'a> : Sized {
         };
 
         let mut command_pool = VK_NULL_HANDLE;
-        match unsafe {vkCreateCommandPool(*device.handle(), &create_info,
-                                          ptr::null(), &mut command_pool)} {
-            VkResult::VK_SUCCESS => Ok(unsafe {Self::_new(command_pool, device)}),
-            x => Err(x)
+        match unsafe {(device.fns().vkCreateCommandPool)(*device.handle(), &create_info,
+                                                         ptr::null(), &mut command_pool)} {
+            VkResult::VK_SUCCESS => Ok(unsafe {Self::_new(command_pool, device, queue_family_index)}),
+            x => Err(Error::from(x))
         }
     }
 
     // Command buffers must be dropped (free'd) before resetting.
     // Would be useful to add an implicit drop somehow.
-    fn reset(&mut self, flags: CommandPoolResetFlags) -> Result<(), VkResult> {
-        match unsafe {vkResetCommandPool(*self.device().handle(), *self.handle(), flags)} {
+    fn reset(&mut self, flags: CommandPoolResetFlags) -> Result<(), Error> {
+        match unsafe {(self.device().fns().vkResetCommandPool)(*self.device().handle(), *self.handle(), flags)} {
             VkResult::VK_SUCCESS => Ok(()),
-            x => Err(x),
+            x => Err(Error::from(x)),
         }
     }
 }
 
 pub struct SplitCommandPool<'a> {
     handle: VkCommandPool,
-    device: &'a Device<'a>
+    device: &'a Device<'a>,
+    queue_family_index: u32,
+    not_sync: PhantomData<Cell<()>>,
 }
 
 impl<'a> CommandPool<'a> for SplitCommandPool<'a> {
     const BUFFER_RESET: bool = true;
 
-    unsafe fn _new(handle: VkCommandPool, device: &'a Device<'a>) -> Self {
-        SplitCommandPool{handle: handle, device: device}
+    unsafe fn _new(handle: VkCommandPool, device: &'a Device<'a>, queue_family_index: u32) -> Self {
+        SplitCommandPool{handle: handle, device: device, queue_family_index: queue_family_index, not_sync: PhantomData}
     }
     fn handle(&self) -> &VkCommandPool {&self.handle}
     fn device(&self) -> &Device<'a> {self.device}
+    fn queue_family_index(&self) -> u32 {self.queue_family_index}
 }
 
 impl <'a> Drop for SplitCommandPool<'a> {
     fn drop(&mut self) {
-        unsafe {vkDestroyCommandPool(*self.device.handle(), self.handle, ptr::null())}
+        unsafe {(self.device.fns().vkDestroyCommandPool)(*self.device.handle(), self.handle, ptr::null())}
     }
 }
 
 pub struct UnifiedCommandPool<'a> {
     handle: VkCommandPool,
-    device: &'a Device<'a>
+    device: &'a Device<'a>,
+    queue_family_index: u32,
+    not_sync: PhantomData<Cell<()>>,
 }
 
 impl<'a> CommandPool<'a> for UnifiedCommandPool<'a> {
     const BUFFER_RESET: bool = false;
 
-    unsafe fn _new(handle: VkCommandPool, device: &'a Device<'a>) -> Self {
-        UnifiedCommandPool{handle: handle, device: device}
+    unsafe fn _new(handle: VkCommandPool, device: &'a Device<'a>, queue_family_index: u32) -> Self {
+        UnifiedCommandPool{handle: handle, device: device, queue_family_index: queue_family_index, not_sync: PhantomData}
     }
     fn handle(&self) -> &VkCommandPool {&self.handle}
     fn device(&self) -> &Device<'a> {self.device}
+    fn queue_family_index(&self) -> u32 {self.queue_family_index}
 }
 
 impl <'a> Drop for UnifiedCommandPool<'a> {
     fn drop(&mut self) {
-        unsafe {vkDestroyCommandPool(*self.device.handle(), self.handle, ptr::null())}
+        unsafe {(self.device.fns().vkDestroyCommandPool)(*self.device.handle(), self.handle, ptr::null())}
     }
 }
 
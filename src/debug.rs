@@ -1,23 +1,22 @@
 extern crate libc;
 use self::libc::{c_char, uint64_t, int32_t, c_void, size_t};
-use std::ffi::{CStr, CString};
+use std::ffi::CStr;
 use sys::common::{VkStructureType, VkResult, VK_NULL_HANDLE, VkBool32};
-use sys::instance::{vkGetInstanceProcAddr, PFNvkVoidFunction};
 use sys::debug::*;
+use sys::loader::DebugReportFns;
 use std::panic::{catch_unwind, AssertUnwindSafe, UnwindSafe};
 use instance::Instance;
+use error::{Error, ValidationError};
 
 use std::ptr;
 use std::io::{self, Write};
-use std::mem::transmute;
 
 pub type DebugReportFlagsEXT = VkDebugReportFlagsEXT;
 
 pub struct DebugReportCallbackEXT<'a, 'b> {
     handle: VkDebugReportCallbackEXT,
     instance: &'a Instance,
-    destructor: PFNvkDestroyDebugReportCallbackEXT,
-    message: PFNvkDebugReportMessageEXT,
+    fns: DebugReportFns,
     #[allow(dead_code)] // used in callback_handler
     #[allow(type_complexity)]
     callback: Box<Box<Fn(VkDebugReportFlagsEXT, VkDebugReportObjectTypeEXT, uint64_t, size_t, int32_t, &CStr, &CStr) -> VkBool32 + 'b + Sync>>,
@@ -37,7 +36,7 @@ extern fn callback_handler(flags: VkDebugReportFlagsEXT, object_type: VkDebugRep
 
 impl<'a, 'b> DebugReportCallbackEXT<'a, 'b> {
     #[allow(type_complexity)]
-    pub fn new<F>(instance: &'a Instance, callback: F, flags: VkDebugReportFlagsEXT) -> Result<Self, VkResult>
+    pub fn new<F>(instance: &'a Instance, callback: F, flags: VkDebugReportFlagsEXT) -> Result<Self, Error>
         where F: Fn(VkDebugReportFlagsEXT, VkDebugReportObjectTypeEXT, uint64_t, size_t, int32_t, &CStr, &CStr) -> VkBool32 + 'b + Sync + UnwindSafe
     {
         // Type annotation here is necessary
@@ -51,35 +50,15 @@ impl<'a, 'b> DebugReportCallbackEXT<'a, 'b> {
             p_user_data: &*callback as *const Box<_> as *mut c_void,
         };
 
-        let create_fn : PFNvkCreateDebugReportCallbackEXT;
-        let create_name = CString::new("vkCreateDebugReportCallbackEXT").unwrap();
-        let destroy_fn : PFNvkDestroyDebugReportCallbackEXT;
-        let destroy_name = CString::new("vkDestroyDebugReportCallbackEXT").unwrap();
-        let message_fn : PFNvkDebugReportMessageEXT;
-        let message_name = CString::new("vkDebugReportMessageEXT").unwrap();
-
-        unsafe {
-            create_fn = transmute::<PFNvkVoidFunction, PFNvkCreateDebugReportCallbackEXT>(
-                match vkGetInstanceProcAddr(*instance.handle(), create_name.as_ptr()) {
-                    None => return Err(VkResult::VK_ERROR_EXTENSION_NOT_PRESENT),
-                    Some(x) => x,
-                });
-            destroy_fn = transmute::<PFNvkVoidFunction, PFNvkDestroyDebugReportCallbackEXT>(
-                match vkGetInstanceProcAddr(*instance.handle(), destroy_name.as_ptr()) {
-                    None => return Err(VkResult::VK_ERROR_EXTENSION_NOT_PRESENT),
-                    Some(x) => x,
-                });
-            message_fn = transmute::<PFNvkVoidFunction, PFNvkDebugReportMessageEXT>(
-                match vkGetInstanceProcAddr(*instance.handle(), message_name.as_ptr()) {
-                    None => return Err(VkResult::VK_ERROR_EXTENSION_NOT_PRESENT),
-                    Some(x) => x,
-                });
-        }
+        let fns = match instance.debug_report_fns() {
+            Some(fns) => *fns,
+            None => return Err(Error::from(ValidationError::ExtensionNotEnabled("VK_EXT_debug_report"))),
+        };
 
         let mut handle = VK_NULL_HANDLE as VkDebugReportCallbackEXT;
-        match create_fn(*instance.handle(), &create_info, ptr::null(), &mut handle) {
-            VkResult::VK_SUCCESS => Ok(DebugReportCallbackEXT{handle: handle, instance: instance, destructor: destroy_fn, message: message_fn, callback: callback}),
-            x => Err(x),
+        match unsafe {(fns.vkCreateDebugReportCallbackEXT)(*instance.handle(), &create_info, ptr::null(), &mut handle)} {
+            VkResult::VK_SUCCESS => Ok(DebugReportCallbackEXT{handle: handle, instance: instance, fns: fns, callback: callback}),
+            x => Err(Error::from(x)),
         }
     }
 
@@ -87,13 +66,13 @@ impl<'a, 'b> DebugReportCallbackEXT<'a, 'b> {
     // Move to Instance? Add a trait to Instance?
     #[allow(too_many_arguments)]
     pub fn message(&self, flags: VkDebugReportFlagsEXT, object_type: VkDebugReportObjectTypeEXT, object: uint64_t, location: size_t, message_code: int32_t, layer_prefix: &CStr, message: &CStr) {
-        (self.message)(*self.instance.handle(), flags, object_type, object, location, message_code, layer_prefix.as_ptr(), message.as_ptr());
+        unsafe {(self.fns.vkDebugReportMessageEXT)(*self.instance.handle(), flags, object_type, object, location, message_code, layer_prefix.as_ptr(), message.as_ptr())};
     }
 }
 
 impl<'a, 'b> Drop for DebugReportCallbackEXT<'a, 'b> {
     fn drop(&mut self) {
-        (self.destructor)(*self.instance.handle(), self.handle, ptr::null());
+        unsafe {(self.fns.vkDestroyDebugReportCallbackEXT)(*self.instance.handle(), self.handle, ptr::null())};
     }
 }
 
@@ -0,0 +1,453 @@
+use sys::descriptor::*;
+use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
+use sys::image::VkImageLayout;
+use pipeline::ShaderStageFlags;
+use device::Device;
+use buffer::Buffer;
+use image::ImageView;
+use sampler::Sampler;
+use error::{Error, ValidationError};
+use std::marker::PhantomData;
+use std::cell::Cell;
+use std::ptr;
+
+pub type DescriptorPoolSize = VkDescriptorPoolSize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DescriptorSetLayoutBinding {
+    pub binding: u32,
+    pub descriptor_type: VkDescriptorType,
+    // The number of array elements
+    pub descriptor_count: u32,
+    pub stage_flags: ShaderStageFlags,
+}
+
+pub struct DescriptorSetLayout<'a> {
+    handle: VkDescriptorSetLayout,
+    device: &'a Device<'a>,
+    bindings: Vec<DescriptorSetLayoutBinding>,
+}
+
+impl<'a> DescriptorSetLayout<'a> {
+    pub fn new(device: &'a Device, bindings: &[DescriptorSetLayoutBinding]) -> Result<Self, Error> {
+        for (i, binding) in bindings.iter().enumerate() {
+            if bindings[..i].iter().any(|b| b.binding == binding.binding) {
+                return Err(Error::from(ValidationError::DuplicateBinding(binding.binding)));
+            }
+        }
+        let raw_bindings = bindings.iter()
+            .map(|binding| VkDescriptorSetLayoutBinding {
+                binding: binding.binding,
+                descriptor_type: binding.descriptor_type,
+                descriptor_count: binding.descriptor_count,
+                stage_flags: binding.stage_flags,
+                p_immutable_samplers: ptr::null(),
+            })
+            .collect::<Vec<_>>();
+        let create_info = VkDescriptorSetLayoutCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkDescriptorSetLayoutCreateFlags::Reserved,
+            binding_count: raw_bindings.len() as u32,
+            p_bindings: raw_bindings.as_ptr(),
+        };
+        let mut layout = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateDescriptorSetLayout)(*device.handle(), &create_info, ptr::null(), &mut layout)} {
+            VkResult::VK_SUCCESS => Ok(DescriptorSetLayout{handle: layout, device: device, bindings: bindings.to_vec()}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkDescriptorSetLayout {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn bindings(&self) -> &[DescriptorSetLayoutBinding] {&self.bindings}
+}
+
+impl<'a> Drop for DescriptorSetLayout<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyDescriptorSetLayout)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+// Like command pools, a descriptor pool must only be used by one thread at a
+// time, and allocating and freeing sets uses it. Pools can be sent to
+// another thread but aren't Sync, so their sets (which borrow the pool) stay
+// on the thread that owns it.
+pub trait DescriptorPool<'a> : Sized {
+    const FREE_DESCRIPTOR_SET: bool;
+
+    unsafe fn _new(handle: VkDescriptorPool, device: &'a Device<'a>) -> Self;
+    fn handle(&self) -> &VkDescriptorPool;
+    fn device(&self) -> &Device<'a>;
+
+    fn new(device: &'a Device, max_sets: u32, pool_sizes: &[DescriptorPoolSize]) -> Result<Self, Error> {
+        let create_info = VkDescriptorPoolCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_DESCRIPTOR_POOL_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: if Self::FREE_DESCRIPTOR_SET {
+                VK_DESCRIPTOR_POOL_CREATE_FREE_DESCRIPTOR_SET_BIT
+            } else {
+                VkDescriptorPoolCreateFlags::empty()
+            },
+            max_sets: max_sets,
+            pool_size_count: pool_sizes.len() as u32,
+            p_pool_sizes: pool_sizes.as_ptr(),
+        };
+
+        let mut descriptor_pool = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateDescriptorPool)(*device.handle(), &create_info,
+                                                            ptr::null(), &mut descriptor_pool)} {
+            VkResult::VK_SUCCESS => Ok(unsafe {Self::_new(descriptor_pool, device)}),
+            x => Err(Error::from(x))
+        }
+    }
+
+    // Returns every set to the pool, so they must be dropped first
+    fn reset(&mut self) -> Result<(), Error> {
+        match unsafe {(self.device().fns().vkResetDescriptorPool)(*self.device().handle(), *self.handle(),
+                                                                  VkDescriptorPoolResetFlags::Reserved)} {
+            VkResult::VK_SUCCESS => Ok(()),
+            x => Err(Error::from(x)),
+        }
+    }
+}
+
+// Sets are free'd individually when dropped
+pub struct SplitDescriptorPool<'a> {
+    handle: VkDescriptorPool,
+    device: &'a Device<'a>,
+    not_sync: PhantomData<Cell<()>>,
+}
+
+impl<'a> DescriptorPool<'a> for SplitDescriptorPool<'a> {
+    const FREE_DESCRIPTOR_SET: bool = true;
+
+    unsafe fn _new(handle: VkDescriptorPool, device: &'a Device<'a>) -> Self {
+        SplitDescriptorPool{handle: handle, device: device, not_sync: PhantomData}
+    }
+    fn handle(&self) -> &VkDescriptorPool {&self.handle}
+    fn device(&self) -> &Device<'a> {self.device}
+}
+
+impl <'a> Drop for SplitDescriptorPool<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyDescriptorPool)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+// Sets are only returned to the pool by `reset`
+pub struct UnifiedDescriptorPool<'a> {
+    handle: VkDescriptorPool,
+    device: &'a Device<'a>,
+    not_sync: PhantomData<Cell<()>>,
+}
+
+impl<'a> DescriptorPool<'a> for UnifiedDescriptorPool<'a> {
+    const FREE_DESCRIPTOR_SET: bool = false;
+
+    unsafe fn _new(handle: VkDescriptorPool, device: &'a Device<'a>) -> Self {
+        UnifiedDescriptorPool{handle: handle, device: device, not_sync: PhantomData}
+    }
+    fn handle(&self) -> &VkDescriptorPool {&self.handle}
+    fn device(&self) -> &Device<'a> {self.device}
+}
+
+impl <'a> Drop for UnifiedDescriptorPool<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyDescriptorPool)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+// Resources written into the set are borrowed for 'r
+pub struct DescriptorSet<'a, 'r, P>
+    where P: DescriptorPool<'a> + 'a
+{
+    handle: VkDescriptorSet,
+    pool: &'a P,
+    bindings: Vec<DescriptorSetLayoutBinding>,
+    resources: PhantomData<&'r ()>,
+}
+
+impl<'a, 'r, P> DescriptorSet<'a, 'r, P>
+    where P: DescriptorPool<'a>
+{
+    pub fn allocate(pool: &'a P, layouts: &[&DescriptorSetLayout]) -> Result<Vec<Self>, Error> {
+        let set_layouts = layouts.iter().map(|l| l.handle).collect::<Vec<_>>();
+        let allocate_info = VkDescriptorSetAllocateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_DESCRIPTOR_SET_ALLOCATE_INFO,
+            p_next: ptr::null(),
+            descriptor_pool: *pool.handle(),
+            descriptor_set_count: set_layouts.len() as u32,
+            p_set_layouts: set_layouts.as_ptr(),
+        };
+        let device = pool.device();
+        let mut sets = Vec::<VkDescriptorSet>::with_capacity(set_layouts.len());
+        match unsafe {(device.fns().vkAllocateDescriptorSets)(*device.handle(), &allocate_info, sets.as_mut_ptr())} {
+            VkResult::VK_SUCCESS => {
+                unsafe {sets.set_len(set_layouts.len())};
+                Ok(sets.into_iter().zip(layouts).map(|(set, layout)| {
+                    DescriptorSet{handle: set, pool: pool, bindings: layout.bindings.clone(), resources: PhantomData}
+                }).collect())
+            },
+            x => Err(Error::from(x))
+        }
+    }
+
+    pub fn handle(&self) -> &VkDescriptorSet {&self.handle}
+    pub fn pool(&self) -> &'a P {self.pool}
+
+    // The type of `count` descriptors from `array_element` in `binding`
+    fn descriptor_type(&self, binding: u32, array_element: u32, count: u32) -> Result<VkDescriptorType, Error> {
+        match self.bindings.iter().find(|b| b.binding == binding) {
+            Some(b) if array_element < b.descriptor_count && count <= b.descriptor_count - array_element =>
+                Ok(b.descriptor_type),
+            _ => Err(Error::from(ValidationError::InvalidDescriptor{binding: binding, array_element: array_element})),
+        }
+    }
+
+    fn write(&mut self, binding: u32, array_element: u32, descriptor_type: VkDescriptorType,
+             image_info: *const VkDescriptorImageInfo, buffer_info: *const VkDescriptorBufferInfo) {
+        let write = VkWriteDescriptorSet {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET,
+            p_next: ptr::null(),
+            dst_set: self.handle,
+            dst_binding: binding,
+            dst_array_element: array_element,
+            descriptor_count: 1,
+            descriptor_type: descriptor_type,
+            p_image_info: image_info,
+            p_buffer_info: buffer_info,
+            p_texel_buffer_view: ptr::null(),
+        };
+        let device = self.pool.device();
+        unsafe {(device.fns().vkUpdateDescriptorSets)(*device.handle(), 1, &write, 0, ptr::null())}
+    }
+
+    // For uniform and storage buffers, `range` may be VK_WHOLE_SIZE
+    pub fn write_buffer(&mut self, binding: u32, array_element: u32, buffer: &'r Buffer, offset: u64, range: u64)
+                        -> Result<(), Error> {
+        let descriptor_type = self.descriptor_type(binding, array_element, 1)?;
+        match descriptor_type {
+            VkDescriptorType::VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER |
+            VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER |
+            VkDescriptorType::VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC |
+            VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC => {},
+            _ => return Err(Error::from(ValidationError::DescriptorTypeMismatch{
+                binding: binding, descriptor_type: descriptor_type
+            })),
+        }
+        let buffer_info = VkDescriptorBufferInfo{buffer: *buffer.handle(), offset: offset, range: range};
+        self.write(binding, array_element, descriptor_type, ptr::null(), &buffer_info);
+        Ok(())
+    }
+
+    // For sampled and storage images and input attachments, or combined
+    // image samplers if `sampler` is given
+    pub fn write_image(&mut self, binding: u32, array_element: u32, view: &'r ImageView, layout: VkImageLayout,
+                       sampler: Option<&'r Sampler>) -> Result<(), Error> {
+        let descriptor_type = self.descriptor_type(binding, array_element, 1)?;
+        match (descriptor_type, sampler.is_some()) {
+            (VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE, false) |
+            (VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_IMAGE, false) |
+            (VkDescriptorType::VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT, false) |
+            (VkDescriptorType::VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER, true) => {},
+            _ => return Err(Error::from(ValidationError::DescriptorTypeMismatch{
+                binding: binding, descriptor_type: descriptor_type
+            })),
+        }
+        let image_info = VkDescriptorImageInfo {
+            sampler: sampler.map_or(VK_NULL_HANDLE, |s| *s.handle()),
+            image_view: *view.handle(),
+            image_layout: layout,
+        };
+        self.write(binding, array_element, descriptor_type, &image_info, ptr::null());
+        Ok(())
+    }
+
+    pub fn write_sampler(&mut self, binding: u32, array_element: u32, sampler: &'r Sampler) -> Result<(), Error> {
+        let descriptor_type = self.descriptor_type(binding, array_element, 1)?;
+        if descriptor_type != VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER {
+            return Err(Error::from(ValidationError::DescriptorTypeMismatch{
+                binding: binding, descriptor_type: descriptor_type
+            }));
+        }
+        let image_info = VkDescriptorImageInfo {
+            sampler: *sampler.handle(),
+            image_view: VK_NULL_HANDLE,
+            image_layout: VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED,
+        };
+        self.write(binding, array_element, descriptor_type, &image_info, ptr::null());
+        Ok(())
+    }
+
+    // Copies `count` descriptors, which borrow resources for at least as long
+    pub fn copy<'b, Q>(&mut self, binding: u32, array_element: u32, src: &DescriptorSet<'b, 'r, Q>,
+                       src_binding: u32, src_array_element: u32, count: u32) -> Result<(), Error>
+        where Q: DescriptorPool<'b>
+    {
+        let descriptor_type = self.descriptor_type(binding, array_element, count)?;
+        let src_type = src.descriptor_type(src_binding, src_array_element, count)?;
+        if src_type != descriptor_type {
+            return Err(Error::from(ValidationError::DescriptorTypeMismatch{
+                binding: src_binding, descriptor_type: src_type
+            }));
+        }
+        let copy = VkCopyDescriptorSet {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_COPY_DESCRIPTOR_SET,
+            p_next: ptr::null(),
+            src_set: src.handle,
+            src_binding: src_binding,
+            src_array_element: src_array_element,
+            dst_set: self.handle,
+            dst_binding: binding,
+            dst_array_element: array_element,
+            descriptor_count: count,
+        };
+        let device = self.pool.device();
+        unsafe {(device.fns().vkUpdateDescriptorSets)(*device.handle(), 0, ptr::null(), 1, &copy)}
+        Ok(())
+    }
+}
+
+impl<'a, 'r, P: DescriptorPool<'a>> Drop for DescriptorSet<'a, 'r, P> {
+    fn drop(&mut self) {
+        if P::FREE_DESCRIPTOR_SET {
+            let device = self.pool.device();
+            unsafe {(device.fns().vkFreeDescriptorSets)(*device.handle(), *self.pool.handle(), 1, &self.handle)};
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority, SharingMode};
+    use buffer::Buffer;
+    use memory::{DeviceMemory, MemoryPropertyFlags};
+    use sampler::SamplerBuilder;
+    use error::{Error, ValidationError};
+    use sys::pipeline::VK_SHADER_STAGE_COMPUTE_BIT;
+    use sys::buffer::VK_BUFFER_USAGE_STORAGE_BUFFER_BIT;
+    use sys::memory::VK_WHOLE_SIZE;
+    use std::collections::HashMap;
+
+    use descriptor::*;
+
+    #[test]
+    fn create_descriptor_set_layout() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let binding = DescriptorSetLayoutBinding {
+            binding: 0,
+            descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,
+            descriptor_count: 1,
+            stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+        };
+        let layout = DescriptorSetLayout::new(&device, &[binding]).unwrap();
+        assert_eq!(layout.bindings(), &[binding]);
+        assert_eq!(DescriptorSetLayout::new(&device, &[binding, binding]).err(),
+                   Some(Error::from(ValidationError::DuplicateBinding(0))));
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn allocate_descriptor_sets() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let layout = DescriptorSetLayout::new(&device, &[DescriptorSetLayoutBinding {
+            binding: 0,
+            descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,
+            descriptor_count: 1,
+            stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+        }]).unwrap();
+        let pool_size = DescriptorPoolSize{descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,
+                                           descriptor_count: 2};
+
+        let split_pool = SplitDescriptorPool::new(&device, 2, &[pool_size]).unwrap();
+        for _ in 0..4 {
+            // Free'd on drop, so the pool never runs out
+            assert_eq!(DescriptorSet::allocate(&split_pool, &[&layout, &layout]).unwrap().len(), 2);
+        }
+
+        let mut unified_pool = UnifiedDescriptorPool::new(&device, 2, &[pool_size]).unwrap();
+        for _ in 0..4 {
+            assert_eq!(DescriptorSet::allocate(&unified_pool, &[&layout, &layout]).unwrap().len(), 2);
+            unified_pool.reset().unwrap();
+        }
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn update_descriptor_sets() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let memory;
+        let mut buffer = Buffer::new(&device, 1024, VK_BUFFER_USAGE_STORAGE_BUFFER_BIT, &SharingMode::Exclusive).unwrap();
+        let requirements = buffer.memory_requirements();
+        memory = DeviceMemory::allocate(&device, requirements.size, requirements.memory_type_bits,
+                                        MemoryPropertyFlags::empty(), MemoryPropertyFlags::empty()).unwrap();
+        buffer.bind_memory(&memory, 0).unwrap();
+        let sampler = SamplerBuilder::new().build(&device).unwrap();
+
+        let layout = DescriptorSetLayout::new(&device, &[
+            DescriptorSetLayoutBinding {
+                binding: 0,
+                descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,
+                descriptor_count: 2,
+                stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+            },
+            DescriptorSetLayoutBinding {
+                binding: 1,
+                descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER,
+                descriptor_count: 1,
+                stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+            },
+        ]).unwrap();
+        let pool = SplitDescriptorPool::new(&device, 2, &[
+            DescriptorPoolSize{descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER, descriptor_count: 4},
+            DescriptorPoolSize{descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER, descriptor_count: 2},
+        ]).unwrap();
+        let mut sets = DescriptorSet::allocate(&pool, &[&layout, &layout]).unwrap();
+        let mut dst = sets.pop().unwrap();
+        let mut src = sets.pop().unwrap();
+
+        src.write_buffer(0, 0, &buffer, 0, 512).unwrap();
+        src.write_buffer(0, 1, &buffer, 512, VK_WHOLE_SIZE).unwrap();
+        src.write_sampler(1, 0, &sampler).unwrap();
+        dst.copy(0, 0, &src, 0, 0, 2).unwrap();
+        dst.copy(1, 0, &src, 1, 0, 1).unwrap();
+
+        assert_eq!(src.write_buffer(0, 2, &buffer, 0, 512),
+                   Err(Error::from(ValidationError::InvalidDescriptor{binding: 0, array_element: 2})));
+        assert_eq!(src.write_buffer(1, 0, &buffer, 0, 512),
+                   Err(Error::from(ValidationError::DescriptorTypeMismatch{
+                       binding: 1, descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER
+                   })));
+        assert_eq!(dst.copy(0, 0, &src, 1, 0, 1),
+                   Err(Error::from(ValidationError::DescriptorTypeMismatch{
+                       binding: 1, descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER
+                   })));
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
@@ -1,11 +1,46 @@
-use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
+use sys::common::{VkBool32, VkResult, VkStructureType, VkPipelineStageFlags, VkSharingMode, VK_NULL_HANDLE};
 use sys::device::*;
-use sys::instance::VK_QUEUE_GRAPHICS_BIT;
-use instance::PhysicalDevice;
+use sys::instance::{PFNvkVoidFunction, VkPhysicalDeviceLimits, VkQueueFamilyProperties, VkQueueFlags};
+use sys::queue::*;
+use sys::memory::VkPhysicalDeviceMemoryProperties;
+use sys::loader::DeviceFns;
+use instance::{PhysicalDevice, PhysicalDeviceProperties};
+use command_pool::CommandPool;
+use command_buffer::{PrimaryCommandBuffer, Executable};
+use semaphore::Semaphore;
+use fence::Fence;
+use error::{Error, ValidationError, VkError};
 use std::marker::PhantomData;
 use std::collections::HashMap;
+use std::ffi::CString;
+use std::mem::transmute;
 use std::ptr;
 
+pub type PipelineStageFlags = VkPipelineStageFlags;
+pub type QueueFlags = VkQueueFlags;
+
+// Concurrent resources can be used by the listed queue families without
+// ownership transfers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SharingMode {
+    Exclusive,
+    Concurrent(Vec<u32>),
+}
+
+impl SharingMode {
+    pub(crate) fn raw(&self, device: &Device) -> Result<(VkSharingMode, &[u32]), Error> {
+        match *self {
+            SharingMode::Exclusive => Ok((VkSharingMode::VK_SHARING_MODE_EXCLUSIVE, &[])),
+            SharingMode::Concurrent(ref families) => {
+                if let Some(&family) = families.iter().find(|family| !device.queue_counts().contains_key(family)) {
+                    return Err(Error::from(ValidationError::InvalidQueueFamily(family)));
+                }
+                Ok((VkSharingMode::VK_SHARING_MODE_CONCURRENT, families))
+            }
+        }
+    }
+}
+
 pub struct QueuePriority(f32);
 
 impl QueuePriority {
@@ -18,22 +53,164 @@ impl QueuePriority {
     }
 
     pub fn from_float_clamped(priority: f32) -> QueuePriority {
-        QueuePriority(priority.min(0.0).max(1.0))
+        QueuePriority(priority.max(0.0).min(1.0))
     }
 }
 
-pub struct Device<'a> {
-    handle: VkDevice,
-    nqueues: HashMap<u32, u32>,
-    physical_device: PhantomData<&'a PhysicalDevice<'a>>
+// Names of the features that are requested, but not supported
+fn unsupported_features(requested: &VkPhysicalDeviceFeatures, supported: &VkPhysicalDeviceFeatures)
+                        -> Vec<&'static str> {
+    macro_rules! features {
+        ($($feature:ident,)*) => {
+            vec!($((stringify!($feature), requested.$feature, supported.$feature),)*)
+        }
+    }
+    features!(
+        robust_buffer_access,
+        full_draw_index_uint32,
+        image_cube_array,
+        independent_blend,
+        geometry_shader,
+        tessellation_shader,
+        sample_rate_shading,
+        dual_src_blend,
+        logic_op,
+        multi_draw_indirect,
+        draw_indirect_first_instance,
+        depth_clamp,
+        depth_bias_clamp,
+        fill_mode_non_solid,
+        depth_bounds,
+        wide_lines,
+        large_points,
+        alpha_to_one,
+        multi_viewport,
+        sampler_anisotropy,
+        texture_compression_etc2,
+        texture_compression_astc_ldr,
+        texture_compression_bc,
+        occlusion_query_precise,
+        pipeline_statistics_query,
+        vertex_pipeline_stores_and_atomics,
+        fragment_stores_and_atomics,
+        shader_tessellation_and_geometry_point_size,
+        shader_image_gather_extended,
+        shader_storage_image_extended_formats,
+        shader_storage_image_multisample,
+        shader_storage_image_read_without_format,
+        shader_storage_image_write_without_format,
+        shader_uniform_buffer_array_dynamic_indexing,
+        shader_sampled_image_array_dynamic_indexing,
+        shader_storage_buffer_array_dynamic_indexing,
+        shader_storage_image_array_dynamic_indexing,
+        shader_clip_distance,
+        shader_cull_distance,
+        shader_float64,
+        shader_int64,
+        shader_int16,
+        shader_resource_residency,
+        shader_resource_min_lod,
+        sparse_binding,
+        sparse_residency_buffer,
+        sparse_residency_image2_d,
+        sparse_residency_image3_d,
+        sparse_residency2_samples,
+        sparse_residency4_samples,
+        sparse_residency8_samples,
+        sparse_residency16_samples,
+        sparse_residency_aliased,
+        variable_multisample_rate,
+        inherited_queries,
+    ).into_iter()
+        .filter(|&(_, requested, supported)| requested == VkBool32::True && supported != VkBool32::True)
+        .map(|(name, _, _)| name)
+        .collect()
 }
 
-impl<'a> Device<'a> {
-    pub fn new(physical_device: &PhysicalDevice<'a>,
-               queue_priorities: HashMap<u32, Vec<QueuePriority>>)
-               -> Result<Self, VkResult> {
+pub struct DeviceBuilder {
+    queue_priorities: HashMap<u32, Vec<QueuePriority>>,
+    layers: Vec<CString>,
+    extensions: Vec<CString>,
+    features: VkPhysicalDeviceFeatures,
+}
+
+impl DeviceBuilder {
+    pub fn new() -> Self {
+        DeviceBuilder {
+            queue_priorities: HashMap::new(),
+            layers: Vec::new(),
+            extensions: Vec::new(),
+            features: VkPhysicalDeviceFeatures::default(),
+        }
+    }
+
+    // One queue is created per priority, families without any are skipped
+    pub fn queues(mut self, family: u32, priorities: Vec<QueuePriority>) -> Self {
+        self.queue_priorities.insert(family, priorities);
+        self
+    }
+
+    // Device layers are deprecated, but required by some older implementations
+    pub fn layer(mut self, name: CString) -> Self {
+        self.layers.push(name);
+        self
+    }
+
+    pub fn extension(mut self, name: CString) -> Self {
+        self.extensions.push(name);
+        self
+    }
+
+    pub fn features(mut self, features: VkPhysicalDeviceFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    pub fn build<'a>(&self, physical_device: &PhysicalDevice<'a>) -> Result<Device<'a>, Error> {
+        let queue_priorities = self.queue_priorities.iter()
+            .filter(|&(_, priorities)| !priorities.is_empty())
+            .collect::<Vec<_>>();
+        let queue_family_properties = physical_device.queue_family_properties();
+        for &(&family, priorities) in &queue_priorities {
+            let count = match queue_family_properties.get(family as usize) {
+                Some(properties) => properties.queue_count,
+                None => return Err(Error::from(ValidationError::InvalidQueueFamily(family))),
+            };
+            if priorities.len() > count as usize {
+                return Err(Error::from(ValidationError::TooManyQueues{
+                    family: family, requested: priorities.len() as u32, available: count
+                }));
+            }
+        }
+
+        let available_layers = physical_device.layer_properties()?;
+        for layer in &self.layers {
+            if !available_layers.iter().any(|l| l.name.as_bytes() == layer.as_bytes()) {
+                let name = layer.to_string_lossy().into_owned();
+                return Err(Error::from(ValidationError::UnavailableLayer(name)));
+            }
+        }
+
+        // Extensions may be provided by the implementation or any enabled layer
+        let sources = Some(None).into_iter().chain(self.layers.iter().map(|l| Some(l.as_c_str())));
+        let mut available_extensions = Vec::new();
+        for layer in sources {
+            available_extensions.extend(physical_device.extension_properties(layer)?);
+        }
+        for extension in &self.extensions {
+            if !available_extensions.iter().any(|e| e.name.as_bytes() == extension.as_bytes()) {
+                let name = extension.to_string_lossy().into_owned();
+                return Err(Error::from(ValidationError::UnavailableExtension(name)));
+            }
+        }
+
+        let unsupported = unsupported_features(&self.features, &physical_device.features());
+        if !unsupported.is_empty() {
+            return Err(Error::from(ValidationError::UnsupportedFeatures(unsupported)));
+        }
+
         let queue_create_infos = queue_priorities.iter()
-            .map(|(family, priorities)| {
+            .map(|&(family, priorities)| {
                 VkDeviceQueueCreateInfo {
                     s_type: VkStructureType::VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO,
                     p_next: ptr::null(),
@@ -43,69 +220,165 @@ impl<'a> Device<'a> {
                     p_queue_priorities: priorities.as_ptr() as *const f32,
                 }})
             .collect::<Vec<_>>();
+        let layers = self.layers.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
+        let extensions = self.extensions.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let create_info = VkDeviceCreateInfo {
             s_type: VkStructureType::VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO,
             p_next: ptr::null(),
             flags: VkDeviceCreateFlags::Reserved,
             queue_create_info_count: queue_create_infos.len() as u32,
             p_queue_create_infos: queue_create_infos.as_ptr(),
-            enabled_layer_count: 0,
-            pp_enabled_layer_names: ptr::null(),
-            enabled_extension_count: 0,
-            pp_enabled_extension_names: ptr::null(),
-            p_enabled_features: &VkPhysicalDeviceFeatures::default(),
+            enabled_layer_count: layers.len() as u32,
+            pp_enabled_layer_names: layers.as_ptr(),
+            enabled_extension_count: extensions.len() as u32,
+            pp_enabled_extension_names: extensions.as_ptr(),
+            p_enabled_features: &self.features,
         };
 
-        let queue_family_properties = physical_device.queue_family_properties();
-        if queue_priorities.iter().any(|(family, priorities)| {
-            let count = queue_family_properties[*family as usize].queue_count as usize;
-            let flags = queue_family_properties[*family as usize].queue_flags;
-            count < priorities.len() && !flags.contains(VK_QUEUE_GRAPHICS_BIT)
-        }) {
-            return Err(VkResult::VK_ERROR_VALIDATION_FAILED_EXT);
-        }
-
         let nqueues = queue_priorities.iter()
-            .map(|(family, priorities)| {(*family, priorities.len() as u32)})
+            .map(|&(family, priorities)| {(*family, priorities.len() as u32)})
             .collect::<HashMap<_, _>>();
 
+        let instance_fns = physical_device.instance().fns();
         let mut device = VK_NULL_HANDLE;
-        unsafe {
-            match vkCreateDevice(*physical_device.handle(),
-                                 &create_info, ptr::null(), &mut device) {
-                VkResult::VK_SUCCESS => Ok(Device{handle: device,
-                                                  physical_device: PhantomData,
-                                                  nqueues: nqueues}),
-                x => Err(x)
+        match unsafe {(instance_fns.vkCreateDevice)(*physical_device.handle(),
+                                                     &create_info, ptr::null(), &mut device)} {
+            VkResult::VK_SUCCESS => {},
+            x => return Err(Error::from(x))
+        }
+
+        // Skips the loader trampoline for device-level functions
+        let get_proc_addr = |name| unsafe {(instance_fns.vkGetDeviceProcAddr)(device, name)};
+        match unsafe {DeviceFns::load(get_proc_addr)} {
+            Some(fns) => Ok(Device{handle: device, nqueues: nqueues, extensions: self.extensions.clone(),
+                                   features: self.features, properties: physical_device.properties(),
+                                   memory_properties: physical_device.memory_properties(),
+                                   queue_family_properties: queue_family_properties,
+                                   fns: fns, physical_device: PhantomData}),
+            None => {
+                let destroy_name = CString::new("vkDestroyDevice").unwrap();
+                if let Some(destroy) = get_proc_addr(destroy_name.as_ptr()) {
+                    unsafe {transmute::<PFNvkVoidFunction, PFNvkDestroyDevice>(destroy)(device, ptr::null())};
+                }
+                Err(Error::Vulkan(VkError::InitializationFailed))
             }
         }
     }
+}
+
+impl Default for DeviceBuilder {
+    fn default() -> Self {
+        DeviceBuilder::new()
+    }
+}
+
+pub struct Device<'a> {
+    handle: VkDevice,
+    nqueues: HashMap<u32, u32>,
+    extensions: Vec<CString>,
+    features: VkPhysicalDeviceFeatures,
+    properties: PhysicalDeviceProperties,
+    memory_properties: VkPhysicalDeviceMemoryProperties,
+    queue_family_properties: Vec<VkQueueFamilyProperties>,
+    fns: DeviceFns,
+    physical_device: PhantomData<&'a PhysicalDevice<'a>>
+}
+
+impl<'a> Device<'a> {
+    pub fn new(physical_device: &PhysicalDevice<'a>,
+               queue_priorities: HashMap<u32, Vec<QueuePriority>>)
+               -> Result<Self, Error> {
+        Device::with_features(physical_device, queue_priorities, &VkPhysicalDeviceFeatures::default())
+    }
 
-    pub fn get_queue(&self, family: u32, index: u32) -> Option<Queue<'a>> {
+    pub fn with_features(physical_device: &PhysicalDevice<'a>,
+                         queue_priorities: HashMap<u32, Vec<QueuePriority>>,
+                         features: &VkPhysicalDeviceFeatures)
+                         -> Result<Self, Error> {
+        DeviceBuilder{queue_priorities: queue_priorities, ..DeviceBuilder::new()}
+            .features(*features)
+            .build(physical_device)
+    }
+
+    pub fn get_queue(&self, family: u32, index: u32) -> Option<Queue> {
         match self.nqueues.get(&family) {
             Some(nqueues) if index < *nqueues => {
                 let mut queue = VK_NULL_HANDLE;
-                unsafe {vkGetDeviceQueue(self.handle, family, index, &mut queue);}
-                Some(Queue{queue: queue, device: PhantomData})
+                unsafe {(self.fns().vkGetDeviceQueue)(self.handle, family, index, &mut queue);}
+                Some(Queue{handle: queue, device: self})
             }
             Some(_) | None => None
         }
     }
 
     pub fn handle(&self) -> &VkDevice {&self.handle}
+    // Number of queues created in each family
+    pub fn queue_counts(&self) -> &HashMap<u32, u32> {&self.nqueues}
+    pub fn extensions(&self) -> &[CString] {&self.extensions}
+    pub fn features(&self) -> &VkPhysicalDeviceFeatures {&self.features}
+    // Of the physical device it was created from
+    pub fn properties(&self) -> &PhysicalDeviceProperties {&self.properties}
+    pub fn limits(&self) -> &VkPhysicalDeviceLimits {&self.properties.limits}
+    pub fn memory_properties(&self) -> &VkPhysicalDeviceMemoryProperties {&self.memory_properties}
+    // Of every family, including those without any queues created
+    pub fn queue_family_properties(&self) -> &[VkQueueFamilyProperties] {&self.queue_family_properties}
+    pub fn fns(&self) -> &DeviceFns {&self.fns}
 }
 
 impl<'a> Drop for Device<'a> {
     fn drop(&mut self) {
         unsafe {
-            vkDestroyDevice(self.handle, ptr::null())
+            (self.fns().vkDestroyDevice)(self.handle, ptr::null())
         }
     }
 }
 
 pub struct Queue<'a> {
-    queue: VkQueue,
-    device: PhantomData<&'a Device<'a>>
+    handle: VkQueue,
+    device: &'a Device<'a>,
+}
+
+impl<'a> Queue<'a> {
+    // Submits a single batch. Each wait semaphore blocks the given stages of
+    // the command buffers, the fence is signaled once they have completed.
+    pub fn submit<'b, P>(&mut self, command_buffers: &[&PrimaryCommandBuffer<'b, P, Executable>],
+                         wait_semaphores: &[(&Semaphore, PipelineStageFlags)],
+                         signal_semaphores: &[&Semaphore], fence: Option<&mut Fence>)
+                         -> Result<(), Error>
+        where P: CommandPool<'b>
+    {
+        let command_buffers = command_buffers.iter().map(|b| *b.handle()).collect::<Vec<_>>();
+        let wait_stages = wait_semaphores.iter().map(|&(_, stages)| stages).collect::<Vec<_>>();
+        let wait_semaphores = wait_semaphores.iter().map(|&(s, _)| *s.handle()).collect::<Vec<_>>();
+        let signal_semaphores = signal_semaphores.iter().map(|s| *s.handle()).collect::<Vec<_>>();
+        let submit_info = VkSubmitInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_SUBMIT_INFO,
+            p_next: ptr::null(),
+            wait_semaphore_count: wait_semaphores.len() as u32,
+            p_wait_semaphores: wait_semaphores.as_ptr(),
+            p_wait_dst_stage_mask: wait_stages.as_ptr(),
+            command_buffer_count: command_buffers.len() as u32,
+            p_command_buffers: command_buffers.as_ptr(),
+            signal_semaphore_count: signal_semaphores.len() as u32,
+            p_signal_semaphores: signal_semaphores.as_ptr(),
+        };
+        let fence = fence.map_or(VK_NULL_HANDLE, |f| *f.handle());
+
+        match unsafe {(self.device.fns().vkQueueSubmit)(self.handle, 1, &submit_info, fence)} {
+            VkResult::VK_SUCCESS => Ok(()),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn wait_idle(&mut self) -> Result<(), Error> {
+        match unsafe {(self.device.fns().vkQueueWaitIdle)(self.handle)} {
+            VkResult::VK_SUCCESS => Ok(()),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkQueue {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
 }
 
 #[cfg(test)]
@@ -115,7 +388,11 @@ mod tests {
     use std::sync::atomic::Ordering;
 
     use device::*;
+    use error::{Error, ValidationError};
     use std::collections::HashMap;
+    use std::ffi::CString;
+    use sys::common::VK_PIPELINE_STAGE_ALL_COMMANDS_BIT;
+    use command_pool::SplitCommandPool;
 
     #[test]
     fn create_device() {
@@ -133,6 +410,96 @@ mod tests {
         assert!(!errs.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn enable_features() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+
+        let physical_device = &instance.devices().unwrap()[0];
+        let features = physical_device.features();
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+            Device::with_features(physical_device, priorities, &features).unwrap()
+        };
+        assert_eq!(device.features().robust_buffer_access, features.robust_buffer_access);
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn unsupported() {
+        let supported = VkPhysicalDeviceFeatures{sampler_anisotropy: VkBool32::True, ..Default::default()};
+        let requested = VkPhysicalDeviceFeatures{
+            geometry_shader: VkBool32::True, sampler_anisotropy: VkBool32::True, shader_float64: VkBool32::True,
+            ..Default::default()
+        };
+        assert_eq!(unsupported_features(&requested, &supported), vec!("geometry_shader", "shader_float64"));
+        assert!(unsupported_features(&supported, &supported).is_empty());
+    }
+
+    #[test]
+    fn builder() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+
+        let physical_device = &instance.devices().unwrap()[0];
+        let device = DeviceBuilder::new()
+            .queues(0, vec!(QueuePriority::from_float_clamped(1.0)))
+            .queues(1, Vec::new())
+            .features(physical_device.features())
+            .build(physical_device).unwrap();
+        assert_eq!(device.queue_counts(), &vec!((0, 1)).into_iter().collect::<HashMap<u32, u32>>());
+        assert!(device.extensions().is_empty());
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn invalid_queues() {
+        let instance = debug_instance();
+        let physical_device = &instance.devices().unwrap()[0];
+        let nfamilies = physical_device.queue_family_properties().len() as u32;
+        let available = physical_device.queue_family_properties()[0].queue_count;
+
+        let invalid = DeviceBuilder::new()
+            .queues(nfamilies, vec!(QueuePriority::from_float_clamped(1.0)))
+            .build(physical_device);
+        match invalid {
+            Err(Error::Validation(ValidationError::InvalidQueueFamily(family))) => assert_eq!(family, nfamilies),
+            _ => panic!("Expected an invalid queue family"),
+        }
+
+        let priorities = (0..available + 1).map(|_| QueuePriority::from_float_clamped(1.0)).collect();
+        let too_many = DeviceBuilder::new().queues(0, priorities).build(physical_device);
+        match too_many {
+            Err(Error::Validation(ValidationError::TooManyQueues{family: 0, requested, available: a})) => {
+                assert_eq!((requested, a), (available + 1, available));
+            }
+            _ => panic!("Expected too many queues"),
+        }
+    }
+
+    #[test]
+    fn unavailable_extension() {
+        let instance = debug_instance();
+        let physical_device = &instance.devices().unwrap()[0];
+        let device = DeviceBuilder::new()
+            .queues(0, vec!(QueuePriority::from_float_clamped(1.0)))
+            .extension(CString::new("VK_vkrs_missing").unwrap())
+            .build(physical_device);
+        match device {
+            Err(Error::Validation(ValidationError::UnavailableExtension(name))) => assert_eq!(name, "VK_vkrs_missing"),
+            _ => panic!("Expected an unavailable extension"),
+        }
+    }
+
+    #[test]
+    fn clamped_priority() {
+        assert_eq!(QueuePriority::from_float_clamped(2.0).0, 1.0);
+        assert_eq!(QueuePriority::from_float_clamped(-1.0).0, 0.0);
+        assert_eq!(QueuePriority::from_float_clamped(0.5).0, 0.5);
+    }
+
     #[test]
     fn get_queue() {
         let instance = debug_instance();
@@ -147,6 +514,28 @@ mod tests {
         assert!(!errs.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn submit() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let semaphore = Semaphore::new(&device).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        let no_buffers: &[&PrimaryCommandBuffer<SplitCommandPool, Executable>] = &[];
+
+        queue.submit(no_buffers, &[], &[&semaphore], None).unwrap();
+        queue.submit(no_buffers, &[(&semaphore, VK_PIPELINE_STAGE_ALL_COMMANDS_BIT)], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+        queue.wait_idle().unwrap();
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
     #[test]
     fn get_invalid_queue() {
         let instance = debug_instance();
@@ -0,0 +1,202 @@
+extern crate libc;
+use self::libc::{c_void, c_char, RTLD_NOW, RTLD_LOCAL};
+use sys::common::{VkResult, VK_NULL_HANDLE};
+use sys::instance::{VkInstance, VkLayerProperties, VkExtensionProperties};
+use sys::instance::{PFNvkGetInstanceProcAddr, PFNvkVoidFunction};
+use sys::loader::EntryFns;
+use error::{Error, VkError};
+
+use std::ffi::{CStr, CString, OsStr};
+use std::os::unix::ffi::OsStrExt;
+use std::mem::transmute;
+use std::ptr;
+use std::sync::Arc;
+
+#[cfg(not(target_os = "macos"))]
+const LIBRARY: &'static str = "libvulkan.so.1";
+#[cfg(target_os = "macos")]
+const LIBRARY: &'static str = "libvulkan.1.dylib";
+
+struct Library {
+    handle: *mut c_void,
+}
+
+// dlopen handles may be used from any thread
+unsafe impl Send for Library {}
+unsafe impl Sync for Library {}
+
+fn dlerror() -> String {
+    let msg = unsafe {libc::dlerror()};
+    if msg.is_null() {
+        "unknown error".to_string()
+    } else {
+        unsafe {CStr::from_ptr(msg)}.to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        unsafe {libc::dlclose(self.handle)};
+    }
+}
+
+// Calls a vkEnumerate* style function, first for the count and then for the
+// values. Values may be added in between calls, so retry on VK_INCOMPLETE.
+pub(crate) fn enumerate<T, F>(mut f: F) -> Result<Vec<T>, Error>
+    where F: FnMut(*mut u32, *mut T) -> VkResult
+{
+    loop {
+        let mut count = 0;
+        match f(&mut count, ptr::null_mut()) {
+            VkResult::VK_SUCCESS => {},
+            x => return Err(Error::from(x)),
+        }
+        let mut values = Vec::with_capacity(count as usize);
+        match f(&mut count, values.as_mut_ptr()) {
+            VkResult::VK_SUCCESS => {
+                unsafe {values.set_len(count as usize)};
+                return Ok(values)
+            },
+            VkResult::VK_INCOMPLETE => continue,
+            x => return Err(Error::from(x)),
+        }
+    }
+}
+
+fn string(chars: &[c_char]) -> String {
+    unsafe {CStr::from_ptr(chars.as_ptr())}.to_string_lossy().into_owned()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerProperties {
+    pub name: String,
+    pub spec_version: u32,
+    pub implementation_version: u32,
+    pub description: String,
+}
+
+impl<'a> From<&'a VkLayerProperties> for LayerProperties {
+    fn from(properties: &'a VkLayerProperties) -> Self {
+        LayerProperties {
+            name: string(&properties.layer_name),
+            spec_version: properties.spec_version,
+            implementation_version: properties.implementation_version,
+            description: string(&properties.description),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionProperties {
+    pub name: String,
+    pub spec_version: u32,
+}
+
+impl<'a> From<&'a VkExtensionProperties> for ExtensionProperties {
+    fn from(properties: &'a VkExtensionProperties) -> Self {
+        ExtensionProperties {
+            name: string(&properties.extension_name),
+            spec_version: properties.spec_version,
+        }
+    }
+}
+
+// The Vulkan loader, opened at runtime so a missing libvulkan is an error and
+// not a failure to start. Cheap to clone, the library stays open as long as
+// any clone (or instance created from one) is alive.
+#[derive(Clone)]
+pub struct Entry {
+    #[allow(dead_code)] // keeps the library loaded
+    library: Arc<Library>,
+    get_instance_proc_addr: PFNvkGetInstanceProcAddr,
+    fns: EntryFns,
+}
+
+impl Entry {
+    pub fn new() -> Result<Self, Error> {
+        Entry::from_path(LIBRARY)
+    }
+
+    // Load a specific loader or ICD, e.g. a mock driver for testing.
+    pub fn from_path<P: AsRef<OsStr>>(path: P) -> Result<Self, Error> {
+        let path = match CString::new(path.as_ref().as_bytes()) {
+            Ok(path) => path,
+            Err(_) => return Err(Error::Library("path contains a nul byte".to_string())),
+        };
+        let handle = unsafe {libc::dlopen(path.as_ptr(), RTLD_NOW | RTLD_LOCAL)};
+        if handle.is_null() {
+            return Err(Error::Library(dlerror()));
+        }
+        let library = Library{handle: handle};
+
+        let symbol = unsafe {libc::dlsym(library.handle, b"vkGetInstanceProcAddr\0".as_ptr() as *const c_char)};
+        if symbol.is_null() {
+            return Err(Error::Library(dlerror()));
+        }
+        let get_instance_proc_addr = unsafe {transmute::<*mut c_void, PFNvkGetInstanceProcAddr>(symbol)};
+
+        let fns = match unsafe {EntryFns::load(|name| get_instance_proc_addr(VK_NULL_HANDLE, name))} {
+            Some(fns) => fns,
+            None => return Err(Error::Vulkan(VkError::InitializationFailed)),
+        };
+
+        Ok(Entry{library: Arc::new(library), get_instance_proc_addr: get_instance_proc_addr, fns: fns})
+    }
+
+    pub fn get_instance_proc_addr(&self, instance: VkInstance, name: &CStr) -> Option<PFNvkVoidFunction> {
+        unsafe {(self.get_instance_proc_addr)(instance, name.as_ptr())}
+    }
+
+    pub fn layer_properties(&self) -> Result<Vec<LayerProperties>, Error> {
+        enumerate(|count, properties| unsafe {
+            (self.fns.vkEnumerateInstanceLayerProperties)(count, properties)
+        }).map(|properties| properties.iter().map(LayerProperties::from).collect())
+    }
+
+    // Extensions provided by the implementation, or by `layer` if given
+    pub fn extension_properties(&self, layer: Option<&CStr>) -> Result<Vec<ExtensionProperties>, Error> {
+        let layer = layer.map_or(ptr::null(), |l| l.as_ptr());
+        enumerate(|count, properties| unsafe {
+            (self.fns.vkEnumerateInstanceExtensionProperties)(layer, count, properties)
+        }).map(|properties| properties.iter().map(ExtensionProperties::from).collect())
+    }
+
+    pub fn fns(&self) -> &EntryFns {&self.fns}
+}
+
+#[cfg(test)]
+mod tests {
+    use entry::*;
+    use error::Error;
+    use std::ffi::CString;
+
+    #[test]
+    fn load_entry() {
+        assert!(Entry::new().is_ok());
+    }
+
+    #[test]
+    fn layer_properties() {
+        let layers = Entry::new().unwrap().layer_properties().unwrap();
+        assert!(layers.iter().all(|l| !l.name.is_empty()));
+    }
+
+    #[test]
+    fn extension_properties() {
+        let entry = Entry::new().unwrap();
+        let extensions = entry.extension_properties(None).unwrap();
+        assert!(extensions.iter().any(|e| e.name == "VK_EXT_debug_report"));
+        for layer in entry.layer_properties().unwrap() {
+            let name = CString::new(layer.name).unwrap();
+            assert!(entry.extension_properties(Some(&name)).is_ok());
+        }
+    }
+
+    #[test]
+    fn missing_library() {
+        match Entry::from_path("libvulkan_does_not_exist.so") {
+            Err(Error::Library(msg)) => assert!(msg.contains("libvulkan_does_not_exist.so")),
+            _ => panic!("Expected a library error"),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use sys::common::VkResult;
+use sys::descriptor::VkDescriptorType;
+use sys::query::VkQueryType;
+use std::error;
+use std::fmt;
+use std::io;
+
+// Error codes returned by Vulkan. Success codes (VK_TIMEOUT, VK_INCOMPLETE,
+// ...) are handled by the wrappers, e.g. `Fence::wait` returns `Ok(false)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkError {
+    OutOfHostMemory,
+    OutOfDeviceMemory,
+    InitializationFailed,
+    DeviceLost,
+    MemoryMapFailed,
+    LayerNotPresent,
+    ExtensionNotPresent,
+    FeatureNotPresent,
+    IncompatibleDriver,
+    TooManyObjects,
+    FormatNotSupported,
+    FragmentedPool,
+    SurfaceLostKHR,
+    NativeWindowInUseKHR,
+    OutOfDateKHR,
+    IncompatibleDisplayKHR,
+    ValidationFailedEXT,
+    InvalidShaderNV,
+    OutOfPoolMemoryKHR,
+    // A negative code vkrs doesn't know about, e.g. from a newer driver
+    Unknown(i32),
+}
+
+impl fmt::Display for VkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            VkError::OutOfHostMemory => "out of host memory",
+            VkError::OutOfDeviceMemory => "out of device memory",
+            VkError::InitializationFailed => "initialization failed",
+            VkError::DeviceLost => "device lost",
+            VkError::MemoryMapFailed => "memory map failed",
+            VkError::LayerNotPresent => "layer not present",
+            VkError::ExtensionNotPresent => "extension not present",
+            VkError::FeatureNotPresent => "feature not present",
+            VkError::IncompatibleDriver => "incompatible driver",
+            VkError::TooManyObjects => "too many objects",
+            VkError::FormatNotSupported => "format not supported",
+            VkError::FragmentedPool => "fragmented pool",
+            VkError::SurfaceLostKHR => "surface lost",
+            VkError::NativeWindowInUseKHR => "native window in use",
+            VkError::OutOfDateKHR => "swapchain out of date",
+            VkError::IncompatibleDisplayKHR => "incompatible display",
+            VkError::ValidationFailedEXT => "validation failed",
+            VkError::InvalidShaderNV => "invalid shader",
+            VkError::OutOfPoolMemoryKHR => "out of pool memory",
+            VkError::Unknown(code) => return write!(f, "unknown error code {}", code),
+        })
+    }
+}
+
+// Invalid usage caught by vkrs, before calling into Vulkan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    InvalidQueueFamily(u32),
+    TooManyQueues{family: u32, requested: u32, available: u32},
+    ExtensionNotEnabled(&'static str),
+    FeatureNotEnabled(&'static str),
+    UnsupportedFeatures(Vec<&'static str>),
+    UnavailableLayer(String),
+    UnavailableExtension(String),
+    NoSuitableMemoryType,
+    MemoryNotHostVisible,
+    MemoryRangeOutOfBounds{offset: u64, size: u64, allocation_size: u64},
+    MisalignedMemoryOffset{offset: u64, alignment: u64},
+    MisalignedMappedRange{offset: u64, size: u64, atom_size: u64},
+    RangeNotMapped{offset: u64, size: u64, map_offset: u64, map_size: u64},
+    IncompatibleMemoryType(u32),
+    MemoryAlreadyBound,
+    MemoryNotBound,
+    InvalidMipLevel{level: u32, mip_levels: u32},
+    InvalidArrayLayers{base_layer: u32, layer_count: u32, array_layers: u32},
+    InvalidLodRange,
+    InvalidSpirvLength(usize),
+    InvalidSpirvMagic(u32),
+    DuplicateBinding(u32),
+    InvalidPushConstantRange{offset: u32, size: u32},
+    InvalidDescriptor{binding: u32, array_element: u32},
+    DescriptorTypeMismatch{binding: u32, descriptor_type: VkDescriptorType},
+    InvalidAttachmentReference(u32),
+    ResolveAttachmentCount{color: u32, resolve: u32},
+    InvalidSubpassDependency{src_subpass: u32, dst_subpass: u32},
+    AttachmentCountMismatch{expected: u32, actual: u32},
+    InvalidFramebufferExtent{width: u32, height: u32, layers: u32},
+    InvalidSubpass(u32),
+    InvalidRenderArea{x: i32, y: i32, width: u32, height: u32},
+    RenderPassNotFinished{subpass: u32, subpass_count: u32},
+    NoInheritedRenderPass,
+    QueryOutOfRange{first: u32, count: u32, query_count: u32},
+    QueryTypeMismatch(VkQueryType),
+    TimestampsNotSupported(u32),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValidationError::InvalidQueueFamily(family) =>
+                write!(f, "queue family {} does not exist", family),
+            ValidationError::TooManyQueues{family, requested, available} =>
+                write!(f, "requested {} queues from family {}, which has {}", requested, family, available),
+            ValidationError::ExtensionNotEnabled(name) =>
+                write!(f, "extension {} is not enabled", name),
+            ValidationError::FeatureNotEnabled(name) =>
+                write!(f, "feature {} is not enabled", name),
+            ValidationError::UnsupportedFeatures(ref names) =>
+                write!(f, "features not supported by the device: {}", names.join(", ")),
+            ValidationError::UnavailableLayer(ref name) =>
+                write!(f, "layer {} is not available", name),
+            ValidationError::UnavailableExtension(ref name) =>
+                write!(f, "extension {} is not available", name),
+            ValidationError::NoSuitableMemoryType =>
+                write!(f, "no memory type has the required properties"),
+            ValidationError::MemoryNotHostVisible =>
+                write!(f, "memory is not host visible"),
+            ValidationError::MemoryRangeOutOfBounds{offset, size, allocation_size} =>
+                write!(f, "range of {} bytes at {} exceeds allocation of {} bytes", size, offset, allocation_size),
+            ValidationError::MisalignedMemoryOffset{offset, alignment} =>
+                write!(f, "memory offset {} is not a multiple of {}", offset, alignment),
+            ValidationError::MisalignedMappedRange{offset, size, atom_size} =>
+                write!(f, "mapped range of {} bytes at {} is not aligned to {}", size, offset, atom_size),
+            ValidationError::RangeNotMapped{offset, size, map_offset, map_size} =>
+                write!(f, "range of {} bytes at {} is outside the {} bytes mapped at {}", size, offset, map_size,
+                       map_offset),
+            ValidationError::IncompatibleMemoryType(memory_type) =>
+                write!(f, "memory type {} is not allowed by the resource", memory_type),
+            ValidationError::MemoryAlreadyBound =>
+                write!(f, "memory is already bound"),
+            ValidationError::MemoryNotBound =>
+                write!(f, "no memory is bound"),
+            ValidationError::InvalidMipLevel{level, mip_levels} =>
+                write!(f, "image has no mip level {}, it has {}", level, mip_levels),
+            ValidationError::InvalidArrayLayers{base_layer, layer_count, array_layers} =>
+                write!(f, "{} layers from layer {} exceed the image's {} array layers", layer_count, base_layer,
+                       array_layers),
+            ValidationError::InvalidLodRange =>
+                write!(f, "minimum LOD is greater than maximum LOD"),
+            ValidationError::InvalidSpirvLength(len) =>
+                write!(f, "SPIR-V of {} bytes is not a whole number of words", len),
+            ValidationError::InvalidSpirvMagic(magic) =>
+                write!(f, "SPIR-V has invalid magic number {:#010x}", magic),
+            ValidationError::DuplicateBinding(binding) =>
+                write!(f, "binding {} is declared more than once", binding),
+            ValidationError::InvalidPushConstantRange{offset, size} =>
+                write!(f, "invalid push constant range of {} bytes at {}", size, offset),
+            ValidationError::InvalidDescriptor{binding, array_element} =>
+                write!(f, "descriptor set has no element {} in binding {}", array_element, binding),
+            ValidationError::DescriptorTypeMismatch{binding, descriptor_type} =>
+                write!(f, "binding {} has descriptor type {:?}", binding, descriptor_type),
+            ValidationError::InvalidAttachmentReference(attachment) =>
+                write!(f, "render pass has no attachment {}", attachment),
+            ValidationError::ResolveAttachmentCount{color, resolve} =>
+                write!(f, "subpass has {} resolve attachments for {} color attachments", resolve, color),
+            ValidationError::InvalidSubpassDependency{src_subpass, dst_subpass} =>
+                write!(f, "invalid dependency from subpass {} to subpass {}", src_subpass, dst_subpass),
+            ValidationError::AttachmentCountMismatch{expected, actual} =>
+                write!(f, "expected {} attachments, got {}", expected, actual),
+            ValidationError::InvalidFramebufferExtent{width, height, layers} =>
+                write!(f, "invalid framebuffer extent {}x{} with {} layers", width, height, layers),
+            ValidationError::InvalidSubpass(subpass) =>
+                write!(f, "render pass has no subpass {}", subpass),
+            ValidationError::InvalidRenderArea{x, y, width, height} =>
+                write!(f, "render area of {}x{} at ({}, {}) exceeds the framebuffer", width, height, x, y),
+            ValidationError::RenderPassNotFinished{subpass, subpass_count} =>
+                write!(f, "render pass ended in subpass {} of {}", subpass, subpass_count),
+            ValidationError::NoInheritedRenderPass =>
+                write!(f, "no render pass to inherit"),
+            ValidationError::QueryOutOfRange{first, count, query_count} =>
+                write!(f, "{} queries from {} exceed pool of {} queries", count, first, query_count),
+            ValidationError::QueryTypeMismatch(query_type) =>
+                write!(f, "query pool has type {:?}", query_type),
+            ValidationError::TimestampsNotSupported(family) =>
+                write!(f, "queue family {} does not support timestamps", family),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Vulkan(VkError),
+    // A success code the wrapper does not handle
+    UnexpectedResult(VkResult),
+    Validation(ValidationError),
+    // The Vulkan loader could not be opened
+    Library(String),
+    // Reading or writing a file, e.g. a pipeline cache
+    Io(String),
+}
+
+impl From<VkResult> for Error {
+    fn from(result: VkResult) -> Self {
+        Error::Vulkan(match result {
+            VkResult::VK_ERROR_OUT_OF_HOST_MEMORY => VkError::OutOfHostMemory,
+            VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY => VkError::OutOfDeviceMemory,
+            VkResult::VK_ERROR_INITIALIZATION_FAILED => VkError::InitializationFailed,
+            VkResult::VK_ERROR_DEVICE_LOST => VkError::DeviceLost,
+            VkResult::VK_ERROR_MEMORY_MAP_FAILED => VkError::MemoryMapFailed,
+            VkResult::VK_ERROR_LAYER_NOT_PRESENT => VkError::LayerNotPresent,
+            VkResult::VK_ERROR_EXTENSION_NOT_PRESENT => VkError::ExtensionNotPresent,
+            VkResult::VK_ERROR_FEATURE_NOT_PRESENT => VkError::FeatureNotPresent,
+            VkResult::VK_ERROR_INCOMPATIBLE_DRIVER => VkError::IncompatibleDriver,
+            VkResult::VK_ERROR_TOO_MANY_OBJECTS => VkError::TooManyObjects,
+            VkResult::VK_ERROR_FORMAT_NOT_SUPPORTED => VkError::FormatNotSupported,
+            VkResult::VK_ERROR_FRAGMENTED_POOL => VkError::FragmentedPool,
+            VkResult::VK_ERROR_SURFACE_LOST_KHR => VkError::SurfaceLostKHR,
+            VkResult::VK_ERROR_NATIVE_WINDOW_IN_USE_KHR => VkError::NativeWindowInUseKHR,
+            VkResult::VK_ERROR_OUT_OF_DATE_KHR => VkError::OutOfDateKHR,
+            VkResult::VK_ERROR_INCOMPATIBLE_DISPLAY_KHR => VkError::IncompatibleDisplayKHR,
+            VkResult::VK_ERROR_VALIDATION_FAILED_EXT => VkError::ValidationFailedEXT,
+            VkResult::VK_ERROR_INVALID_SHADER_NV => VkError::InvalidShaderNV,
+            VkResult::VK_ERROR_OUT_OF_POOL_MEMORY_KHR => VkError::OutOfPoolMemoryKHR,
+            VkResult(code) if code < 0 => VkError::Unknown(code),
+            x => return Error::UnexpectedResult(x),
+        })
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err.to_string())
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        Error::Validation(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Vulkan(ref err) => write!(f, "Vulkan error: {}", err),
+            Error::UnexpectedResult(ref result) => write!(f, "unexpected result: {:?}", result),
+            Error::Validation(ref err) => write!(f, "invalid usage: {}", err),
+            Error::Library(ref msg) => write!(f, "could not load Vulkan: {}", msg),
+            Error::Io(ref msg) => write!(f, "I/O error: {}", msg),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use error::*;
+    use sys::common::VkResult;
+
+    #[test]
+    fn from_result() {
+        assert_eq!(Error::from(VkResult::VK_ERROR_DEVICE_LOST), Error::Vulkan(VkError::DeviceLost));
+        assert_eq!(Error::from(VkResult::VK_INCOMPLETE), Error::UnexpectedResult(VkResult::VK_INCOMPLETE));
+        assert_eq!(Error::from(VkResult(-1000069000)), Error::Vulkan(VkError::OutOfPoolMemoryKHR));
+    }
+
+    #[test]
+    fn unknown_result() {
+        assert_eq!(Error::from(VkResult(-1000072003)), Error::Vulkan(VkError::Unknown(-1000072003)));
+        assert_eq!(Error::from(VkResult(1000268000)), Error::UnexpectedResult(VkResult(1000268000)));
+        assert_eq!(format!("{}", Error::from(VkResult(-1000072003))), "Vulkan error: unknown error code -1000072003");
+        assert_eq!(format!("{}", Error::from(VkResult(1000268000))), "unexpected result: VkResult(1000268000)");
+        assert_eq!(format!("{:?}", VkResult::VK_TIMEOUT), "VK_TIMEOUT");
+    }
+
+    #[test]
+    fn display() {
+        let err = Error::from(ValidationError::InvalidQueueFamily(3));
+        assert_eq!(format!("{}", err), "invalid usage: queue family 3 does not exist");
+    }
+}
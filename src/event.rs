@@ -2,6 +2,7 @@ use sys::event::*;
 use device::Device;
 use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
 use std::ptr;
+use error::Error;
 
 pub struct Event<'a> {
     handle: VkEvent,
@@ -9,46 +10,46 @@ pub struct Event<'a> {
 }
 
 impl<'a> Event<'a> {
-    pub fn new(device: &'a Device) -> Result<Self, VkResult> {
+    pub fn new(device: &'a Device) -> Result<Self, Error> {
         let create_info = VkEventCreateInfo {
             s_type: VkStructureType::VK_STRUCTURE_TYPE_EVENT_CREATE_INFO,
             p_next: ptr::null(),
             flags: VkEventCreateFlags::Reserved,
         };
         let mut semaphore = VK_NULL_HANDLE;
-        match unsafe{vkCreateEvent(*device.handle(), &create_info, ptr::null(), &mut semaphore)} {
+        match unsafe{(device.fns().vkCreateEvent)(*device.handle(), &create_info, ptr::null(), &mut semaphore)} {
             VkResult::VK_SUCCESS => Ok(Event{handle: semaphore, device: device}),
-            x => Err(x),
+            x => Err(Error::from(x)),
         }
     }
 
-    pub fn signaled(&self) -> Result<bool, VkResult> {
-        match unsafe {vkGetEventStatus(*self.device.handle(), self.handle)} {
+    pub fn signaled(&self) -> Result<bool, Error> {
+        match unsafe {(self.device.fns().vkGetEventStatus)(*self.device.handle(), self.handle)} {
             VkResult::VK_EVENT_SET => Ok(true),
             VkResult::VK_EVENT_RESET => Ok(false),
-            x => Err(x)
+            x => Err(Error::from(x))
         }
     }
 
     // Use &mut here, because it must be externally synchronized
-    pub fn set(&mut self) -> Result<(), VkResult> {
-        match unsafe {vkSetEvent(*self.device.handle(), self.handle)}{
+    pub fn set(&mut self) -> Result<(), Error> {
+        match unsafe {(self.device.fns().vkSetEvent)(*self.device.handle(), self.handle)}{
             VkResult::VK_SUCCESS => Ok(()),
-            x => Err(x)
+            x => Err(Error::from(x))
         }
     }
 
-    pub fn reset(&mut self) -> Result<(), VkResult> {
-        match unsafe {vkResetEvent(*self.device.handle(), self.handle)}{
+    pub fn reset(&mut self) -> Result<(), Error> {
+        match unsafe {(self.device.fns().vkResetEvent)(*self.device.handle(), self.handle)}{
             VkResult::VK_SUCCESS => Ok(()),
-            x => Err(x)
+            x => Err(Error::from(x))
         }
     }
 }
 
 impl<'a> Drop for Event<'a> {
     fn drop(&mut self) {
-        unsafe{vkDestroyEvent(*self.device.handle(), self.handle, ptr::null())}
+        unsafe{(self.device.fns().vkDestroyEvent)(*self.device.handle(), self.handle, ptr::null())}
     }
 }
 
@@ -2,6 +2,7 @@ use sys::fence::*;
 use device::Device;
 use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE, VkBool32};
 use std::ptr;
+use error::Error;
 
 pub struct Fence<'a> {
     handle: VkFence,
@@ -9,7 +10,7 @@ pub struct Fence<'a> {
 }
 
 impl<'a> Fence<'a> {
-    pub fn new(device: &'a Device, signaled: bool) -> Result<Self, VkResult> {
+    pub fn new(device: &'a Device, signaled: bool) -> Result<Self, Error> {
         let create_info = VkFenceCreateInfo {
             s_type: VkStructureType::VK_STRUCTURE_TYPE_FENCE_CREATE_INFO,
             p_next: ptr::null(),
@@ -21,39 +22,41 @@ impl<'a> Fence<'a> {
         };
 
         let mut fence = VK_NULL_HANDLE;
-        match unsafe{vkCreateFence(*device.handle(), &create_info, ptr::null(), &mut fence)} {
+        match unsafe{(device.fns().vkCreateFence)(*device.handle(), &create_info, ptr::null(), &mut fence)} {
             VkResult::VK_SUCCESS => Ok(Fence{handle: fence, device: device}),
-            x => Err(x),
+            x => Err(Error::from(x)),
         }
     }
 
-    pub fn signaled(&self) -> Result<bool, VkResult> {
-        match unsafe {vkGetFenceStatus(*self.device.handle(), self.handle)} {
+    pub fn handle(&self) -> &VkFence {&self.handle}
+
+    pub fn signaled(&self) -> Result<bool, Error> {
+        match unsafe {(self.device.fns().vkGetFenceStatus)(*self.device.handle(), self.handle)} {
             VkResult::VK_SUCCESS => Ok(true),
             VkResult::VK_NOT_READY => Ok(false),
-            x => Err(x)
+            x => Err(Error::from(x))
         }
     }
 
-    pub fn reset(&mut self) -> Result<(), VkResult> {
-        match unsafe{vkResetFences(*self.device.handle(), 1, &self.handle)} {
+    pub fn reset(&mut self) -> Result<(), Error> {
+        match unsafe{(self.device.fns().vkResetFences)(*self.device.handle(), 1, &self.handle)} {
             VkResult::VK_SUCCESS => Ok(()),
-            x => Err(x)
+            x => Err(Error::from(x))
         }
     }
 
-    pub fn wait(&self, timeout: u64) -> Result<bool, VkResult> {
-        match unsafe{vkWaitForFences(*self.device.handle(), 1, &self.handle, VkBool32::True, timeout)} {
+    pub fn wait(&self, timeout: u64) -> Result<bool, Error> {
+        match unsafe{(self.device.fns().vkWaitForFences)(*self.device.handle(), 1, &self.handle, VkBool32::True, timeout)} {
             VkResult::VK_SUCCESS => Ok(true),
             VkResult::VK_TIMEOUT => Ok(false),
-            x => Err(x),
+            x => Err(Error::from(x)),
         }
     }
 }
 
 impl<'a> Drop for Fence<'a> {
     fn drop(&mut self) {
-        unsafe {vkDestroyFence(*self.device.handle(), self.handle, ptr::null())}
+        unsafe {(self.device.fns().vkDestroyFence)(*self.device.handle(), self.handle, ptr::null())}
     }
 }
 
@@ -0,0 +1,330 @@
+use sys::image::*;
+use sys::memory::VkMemoryRequirements;
+use sys::common::{VkResult, VkStructureType, VkExtent3D, VkSampleCountFlags, VK_SAMPLE_COUNT_1_BIT, VK_NULL_HANDLE};
+use device::{Device, SharingMode};
+use memory::{DeviceMemory, check_binding};
+use error::{Error, ValidationError};
+use std::marker::PhantomData;
+use std::cmp;
+use std::mem;
+use std::ptr;
+
+pub type ImageUsageFlags = VkImageUsageFlags;
+pub type ImageCreateFlags = VkImageCreateFlags;
+pub type ImageAspectFlags = VkImageAspectFlags;
+pub type ImageSubresourceRange = VkImageSubresourceRange;
+pub type ComponentMapping = VkComponentMapping;
+
+pub struct ImageBuilder {
+    flags: ImageCreateFlags,
+    image_type: VkImageType,
+    format: VkFormat,
+    extent: VkExtent3D,
+    mip_levels: u32,
+    array_layers: u32,
+    samples: VkSampleCountFlags,
+    tiling: VkImageTiling,
+    usage: ImageUsageFlags,
+    sharing_mode: SharingMode,
+    initial_layout: VkImageLayout,
+}
+
+impl ImageBuilder {
+    // A single sample, optimally tiled image with one mip level and layer
+    pub fn new(image_type: VkImageType, format: VkFormat, extent: VkExtent3D) -> Self {
+        ImageBuilder {
+            flags: ImageCreateFlags::empty(),
+            image_type: image_type,
+            format: format,
+            extent: extent,
+            mip_levels: 1,
+            array_layers: 1,
+            samples: VK_SAMPLE_COUNT_1_BIT,
+            tiling: VkImageTiling::VK_IMAGE_TILING_OPTIMAL,
+            usage: ImageUsageFlags::empty(),
+            sharing_mode: SharingMode::Exclusive,
+            initial_layout: VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED,
+        }
+    }
+
+    pub fn flags(mut self, flags: ImageCreateFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn mip_levels(mut self, mip_levels: u32) -> Self {
+        self.mip_levels = mip_levels;
+        self
+    }
+
+    pub fn array_layers(mut self, array_layers: u32) -> Self {
+        self.array_layers = array_layers;
+        self
+    }
+
+    pub fn samples(mut self, samples: VkSampleCountFlags) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn tiling(mut self, tiling: VkImageTiling) -> Self {
+        self.tiling = tiling;
+        self
+    }
+
+    pub fn usage(mut self, usage: ImageUsageFlags) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    pub fn sharing_mode(mut self, sharing_mode: SharingMode) -> Self {
+        self.sharing_mode = sharing_mode;
+        self
+    }
+
+    // Either VK_IMAGE_LAYOUT_UNDEFINED or VK_IMAGE_LAYOUT_PREINITIALIZED
+    pub fn initial_layout(mut self, initial_layout: VkImageLayout) -> Self {
+        self.initial_layout = initial_layout;
+        self
+    }
+
+    pub fn build<'a, 'm>(&self, device: &'a Device) -> Result<Image<'a, 'm>, Error> {
+        let (sharing_mode, families) = self.sharing_mode.raw(device)?;
+        let create_info = VkImageCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: self.flags,
+            image_type: self.image_type,
+            format: self.format,
+            extent: self.extent,
+            mip_levels: self.mip_levels,
+            array_layers: self.array_layers,
+            samples: self.samples,
+            tiling: self.tiling,
+            usage: self.usage,
+            sharing_mode: sharing_mode,
+            queue_family_index_count: families.len() as u32,
+            p_queue_family_indices: families.as_ptr(),
+            initial_layout: self.initial_layout,
+        };
+        let mut image = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateImage)(*device.handle(), &create_info, ptr::null(), &mut image)} {
+            VkResult::VK_SUCCESS => Ok(Image{handle: image, device: device, format: self.format, extent: self.extent,
+                                             mip_levels: self.mip_levels, array_layers: self.array_layers,
+                                             usage: self.usage, memory: None}),
+            x => Err(Error::from(x)),
+        }
+    }
+}
+
+pub struct Image<'a: 'm, 'm> {
+    handle: VkImage,
+    device: &'a Device<'a>,
+    format: VkFormat,
+    extent: VkExtent3D,
+    mip_levels: u32,
+    array_layers: u32,
+    usage: ImageUsageFlags,
+    memory: Option<&'m DeviceMemory<'a>>,
+}
+
+impl<'a, 'm> Image<'a, 'm> {
+    pub fn memory_requirements(&self) -> VkMemoryRequirements {
+        let mut requirements = unsafe {mem::zeroed()};
+        unsafe {(self.device.fns().vkGetImageMemoryRequirements)(*self.device.handle(), self.handle, &mut requirements)};
+        requirements
+    }
+
+    // An image can only be bound once, and the memory must outlive it
+    pub fn bind_memory(&mut self, memory: &'m DeviceMemory<'a>, offset: u64) -> Result<(), Error> {
+        if self.memory.is_some() {
+            return Err(Error::from(ValidationError::MemoryAlreadyBound));
+        }
+        check_binding(memory, offset, &self.memory_requirements())?;
+        match unsafe {(self.device.fns().vkBindImageMemory)(*self.device.handle(), self.handle,
+                                                            *memory.handle(), offset)} {
+            VkResult::VK_SUCCESS => {
+                self.memory = Some(memory);
+                Ok(())
+            }
+            x => Err(Error::from(x)),
+        }
+    }
+
+    // Every mip level and array layer of the image
+    pub fn full_range(&self, aspect_mask: ImageAspectFlags) -> ImageSubresourceRange {
+        VkImageSubresourceRange {
+            aspect_mask: aspect_mask,
+            base_mip_level: 0,
+            level_count: self.mip_levels,
+            base_array_layer: 0,
+            layer_count: self.array_layers,
+        }
+    }
+
+    pub fn handle(&self) -> &VkImage {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn format(&self) -> VkFormat {self.format}
+    pub fn extent(&self) -> VkExtent3D {self.extent}
+    pub fn mip_levels(&self) -> u32 {self.mip_levels}
+    pub fn array_layers(&self) -> u32 {self.array_layers}
+    pub fn usage(&self) -> ImageUsageFlags {self.usage}
+    pub fn memory(&self) -> Option<&'m DeviceMemory<'a>> {self.memory}
+}
+
+impl<'a, 'm> Drop for Image<'a, 'm> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyImage)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+pub struct ImageView<'a: 'i, 'i> {
+    handle: VkImageView,
+    device: &'a Device<'a>,
+    // Of the view's base mip level
+    extent: VkExtent3D,
+    layer_count: u32,
+    image: PhantomData<&'i VkImage>,
+}
+
+impl<'a, 'i> ImageView<'a, 'i> {
+    pub fn new<'m>(image: &'i Image<'a, 'm>, view_type: VkImageViewType, format: VkFormat,
+                   components: ComponentMapping, subresource_range: ImageSubresourceRange) -> Result<Self, Error> {
+        if image.memory.is_none() {
+            return Err(Error::from(ValidationError::MemoryNotBound));
+        }
+        if subresource_range.base_mip_level >= image.mip_levels {
+            return Err(Error::from(ValidationError::InvalidMipLevel{
+                level: subresource_range.base_mip_level, mip_levels: image.mip_levels
+            }));
+        }
+        let base_layer = subresource_range.base_array_layer;
+        let layer_count = match subresource_range.layer_count {
+            VK_REMAINING_ARRAY_LAYERS => image.array_layers.saturating_sub(base_layer),
+            count => count,
+        };
+        if base_layer >= image.array_layers || layer_count == 0 || layer_count > image.array_layers - base_layer {
+            return Err(Error::from(ValidationError::InvalidArrayLayers{
+                base_layer: base_layer, layer_count: layer_count, array_layers: image.array_layers
+            }));
+        }
+        let create_info = VkImageViewCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_IMAGE_VIEW_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkImageViewCreateFlags::Reserved,
+            image: image.handle,
+            view_type: view_type,
+            format: format,
+            components: components,
+            subresource_range: subresource_range,
+        };
+        let level = subresource_range.base_mip_level;
+        let extent = VkExtent3D {
+            width: cmp::max(1, image.extent.width >> level),
+            height: cmp::max(1, image.extent.height >> level),
+            depth: cmp::max(1, image.extent.depth >> level),
+        };
+
+        let device = image.device;
+        let mut view = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateImageView)(*device.handle(), &create_info, ptr::null(), &mut view)} {
+            VkResult::VK_SUCCESS => Ok(ImageView{handle: view, device: device, extent: extent,
+                                                 layer_count: layer_count, image: PhantomData}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkImageView {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn extent(&self) -> VkExtent3D {self.extent}
+    pub fn layer_count(&self) -> u32 {self.layer_count}
+}
+
+impl<'a, 'i> Drop for ImageView<'a, 'i> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyImageView)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority};
+    use memory::{DeviceMemory, MemoryPropertyFlags};
+    use error::{Error, ValidationError};
+    use sys::common::VkExtent3D;
+    use std::collections::HashMap;
+
+    use image::*;
+
+    #[test]
+    fn create_image() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let extent = VkExtent3D{width: 64, height: 64, depth: 1};
+        let image = ImageBuilder::new(VkImageType::VK_IMAGE_TYPE_2D, VkFormat::VK_FORMAT_R8G8B8A8_UNORM, extent)
+            .mip_levels(7)
+            .usage(VK_IMAGE_USAGE_SAMPLED_BIT)
+            .build(&device).unwrap();
+        assert!(image.memory_requirements().size >= 64 * 64 * 4);
+        assert_eq!(image.full_range(VK_IMAGE_ASPECT_COLOR_BIT).level_count, 7);
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn create_image_view() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let format = VkFormat::VK_FORMAT_R8G8B8A8_UNORM;
+        let extent = VkExtent3D{width: 64, height: 64, depth: 1};
+        // Declared first, so it outlives the image
+        let memory;
+        let mut image = ImageBuilder::new(VkImageType::VK_IMAGE_TYPE_2D, format, extent)
+            .usage(VK_IMAGE_USAGE_SAMPLED_BIT)
+            .build(&device).unwrap();
+        let range = image.full_range(VK_IMAGE_ASPECT_COLOR_BIT);
+        match ImageView::new(&image, VkImageViewType::VK_IMAGE_VIEW_TYPE_2D, format, ComponentMapping::default(), range) {
+            Err(Error::Validation(ValidationError::MemoryNotBound)) => {},
+            _ => panic!("Expected a memory not bound error"),
+        }
+
+        let requirements = image.memory_requirements();
+        memory = DeviceMemory::allocate(&device, requirements.size, requirements.memory_type_bits,
+                                        MemoryPropertyFlags::empty(), MemoryPropertyFlags::empty()).unwrap();
+        image.bind_memory(&memory, 0).unwrap();
+        let view = ImageView::new(&image, VkImageViewType::VK_IMAGE_VIEW_TYPE_2D, format,
+                                  ComponentMapping::default(), range).unwrap();
+        assert_eq!((view.extent().width, view.layer_count()), (64, 1));
+        let mut range = range;
+        range.base_mip_level = 32;
+        assert_eq!(ImageView::new(&image, VkImageViewType::VK_IMAGE_VIEW_TYPE_2D, format,
+                                  ComponentMapping::default(), range).err(),
+                   Some(Error::from(ValidationError::InvalidMipLevel{level: 32, mip_levels: 1})));
+        let mut range = image.full_range(VK_IMAGE_ASPECT_COLOR_BIT);
+        range.base_array_layer = 1;
+        assert_eq!(ImageView::new(&image, VkImageViewType::VK_IMAGE_VIEW_TYPE_2D, format,
+                                  ComponentMapping::default(), range).err(),
+                   Some(Error::from(ValidationError::InvalidArrayLayers{base_layer: 1, layer_count: 0, array_layers: 1})));
+        range.base_array_layer = 0;
+        range.layer_count = 2;
+        assert_eq!(ImageView::new(&image, VkImageViewType::VK_IMAGE_VIEW_TYPE_2D, format,
+                                  ComponentMapping::default(), range).err(),
+                   Some(Error::from(ValidationError::InvalidArrayLayers{base_layer: 0, layer_count: 2, array_layers: 1})));
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
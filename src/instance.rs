@@ -1,16 +1,37 @@
-use sys::common::{VkStructureType, VkResult, VK_NULL_HANDLE};
+extern crate libc;
+use self::libc::c_void;
+use sys::common::{VkStructureType, VkResult, VK_NULL_HANDLE, VK_API_VERSION_1_0};
 use sys::instance::*;
+use sys::device::VkPhysicalDeviceFeatures;
+use sys::memory::VkPhysicalDeviceMemoryProperties;
+use sys::image::{VkFormat, VkFormatProperties, VkImageFormatProperties, VkImageType, VkImageTiling, VkImageUsageFlags,
+                 VkImageCreateFlags};
+use sys::loader::{InstanceFns, DebugReportFns};
+use entry::{Entry, LayerProperties, ExtensionProperties, enumerate};
+use error::{Error, ValidationError, VkError};
 
 use std::ptr;
+use std::mem;
+use std::mem::transmute;
 use std::marker::PhantomData;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 
 pub struct Instance {
     handle: VkInstance,
+    entry: Entry,
+    fns: InstanceFns,
+    // Extension functions, only loaded if the extension was enabled
+    debug_report_fns: Option<DebugReportFns>,
 }
 
 impl Instance {
-    pub fn new<'a, L, E>(layers: L, extensions: E) -> Result<Self, VkResult>
+    pub fn new<'a, L, E>(layers: L, extensions: E) -> Result<Self, Error>
+        where L: IntoIterator<Item=&'a CString>, E: IntoIterator<Item=&'a CString>
+    {
+        Instance::with_entry(&Entry::new()?, layers, extensions)
+    }
+
+    pub fn with_entry<'a, L, E>(entry: &Entry, layers: L, extensions: E) -> Result<Self, Error>
         where L: IntoIterator<Item=&'a CString>, E: IntoIterator<Item=&'a CString>
     {
         let layers = layers.into_iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
@@ -26,75 +47,310 @@ impl Instance {
             enabled_extension_count: extensions.len() as u32,
             pp_enabled_extension_names: extensions.as_ptr(),
         };
+        Instance::create(entry, &create_info)
+    }
+
+    fn create(entry: &Entry, create_info: &VkInstanceCreateInfo) -> Result<Self, Error> {
+        let extension_names = (0..create_info.enabled_extension_count as isize)
+            .map(|i| unsafe {CStr::from_ptr(*create_info.pp_enabled_extension_names.offset(i))})
+            .collect::<Vec<_>>();
 
         let mut instance = VK_NULL_HANDLE;
-        match unsafe {vkCreateInstance(&create_info, ptr::null(), &mut instance)} {
-            VkResult::VK_SUCCESS => Ok(Instance{handle: instance}),
-            x => Err(x),
+        match unsafe {(entry.fns().vkCreateInstance)(create_info, ptr::null(), &mut instance)} {
+            VkResult::VK_SUCCESS => {},
+            x => return Err(Error::from(x)),
         }
-    }
 
-    pub fn devices(&self) -> Result<Vec<PhysicalDevice>, VkResult> {
-        let mut ndevices = 0;
-        match unsafe {vkEnumeratePhysicalDevices(self.handle, &mut ndevices,
-                                                 ptr::null_mut())} {
-            VkResult::VK_SUCCESS => {}
-            x => return Err(x)
+        let get_proc_addr = |name| unsafe {entry.get_instance_proc_addr(instance, CStr::from_ptr(name))};
+        let enabled = |name: &str| extension_names.iter().any(|e| e.to_bytes() == name.as_bytes());
+        let fns = unsafe {InstanceFns::load(get_proc_addr)};
+        let debug_report_fns = if enabled("VK_EXT_debug_report") {
+            unsafe {DebugReportFns::load(get_proc_addr)}
+        } else {
+            None
         };
-        let mut devices = Vec::<VkPhysicalDevice>::with_capacity(ndevices as usize);
-        match unsafe {vkEnumeratePhysicalDevices(self.handle,
-                                                 &mut (devices.capacity() as u32),
-                                                 devices.as_mut_ptr())} {
-            VkResult::VK_SUCCESS => {
-                unsafe{devices.set_len(ndevices as usize)};
-                Ok(devices.into_iter().map(|dev| {
-                    PhysicalDevice{handle: dev, instance: PhantomData}
-                }).collect())
+
+        match fns {
+            Some(fns) => Ok(Instance{handle: instance, entry: entry.clone(), fns: fns,
+                                     debug_report_fns: debug_report_fns}),
+            None => {
+                // Can't hand out an instance we can't use, but clean up if possible
+                let destroy_name = CString::new("vkDestroyInstance").unwrap();
+                if let Some(destroy) = entry.get_instance_proc_addr(instance, &destroy_name) {
+                    unsafe {transmute::<PFNvkVoidFunction, PFNvkDestroyInstance>(destroy)(instance, ptr::null())};
+                }
+                Err(Error::Vulkan(VkError::InitializationFailed))
             }
-            x => Err(x)
         }
     }
 
+    pub fn devices(&self) -> Result<Vec<PhysicalDevice>, Error> {
+        enumerate(|ndevices, devices| unsafe {
+            (self.fns.vkEnumeratePhysicalDevices)(self.handle, ndevices, devices)
+        }).map(|devices| devices.into_iter().map(|dev| {
+            PhysicalDevice{handle: dev, instance: self}
+        }).collect())
+    }
+
     pub fn handle(&self) -> &VkInstance {&self.handle}
+    pub fn entry(&self) -> &Entry {&self.entry}
+    pub fn fns(&self) -> &InstanceFns {&self.fns}
+    pub fn debug_report_fns(&self) -> Option<&DebugReportFns> {self.debug_report_fns.as_ref()}
 }
 
 impl Drop for Instance {
     fn drop(&mut self) {
-        unsafe {vkDestroyInstance(self.handle, ptr::null())}
+        unsafe {(self.fns.vkDestroyInstance)(self.handle, ptr::null())}
     }
 }
 
+pub struct InstanceBuilder<'a> {
+    application_name: Option<CString>,
+    application_version: u32,
+    engine_name: Option<CString>,
+    engine_version: u32,
+    api_version: u32,
+    layers: Vec<CString>,
+    extensions: Vec<CString>,
+    next: *const c_void,
+    lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a> InstanceBuilder<'a> {
+    pub fn new() -> Self {
+        InstanceBuilder {
+            application_name: None,
+            application_version: 0,
+            engine_name: None,
+            engine_version: 0,
+            api_version: VK_API_VERSION_1_0,
+            layers: Vec::new(),
+            extensions: Vec::new(),
+            next: ptr::null(),
+            lifetime: PhantomData,
+        }
+    }
+
+    pub fn application(mut self, name: CString, version: u32) -> Self {
+        self.application_name = Some(name);
+        self.application_version = version;
+        self
+    }
+
+    pub fn engine(mut self, name: CString, version: u32) -> Self {
+        self.engine_name = Some(name);
+        self.engine_version = version;
+        self
+    }
+
+    // See `vk_make_version`, defaults to VK_API_VERSION_1_0
+    pub fn api_version(mut self, version: u32) -> Self {
+        self.api_version = version;
+        self
+    }
+
+    pub fn layer(mut self, name: CString) -> Self {
+        self.layers.push(name);
+        self
+    }
+
+    pub fn extension(mut self, name: CString) -> Self {
+        self.extensions.push(name);
+        self
+    }
+
+    // Extends VkInstanceCreateInfo. Unsafe because the structure is passed
+    // straight to Vulkan, it must be a valid chain of Vulkan structures.
+    pub unsafe fn next<T>(mut self, next: &'a T) -> Self {
+        self.next = next as *const T as *const c_void;
+        self
+    }
+
+    pub fn build(&self) -> Result<Instance, Error> {
+        self.build_with_entry(&Entry::new()?)
+    }
+
+    pub fn build_with_entry(&self, entry: &Entry) -> Result<Instance, Error> {
+        let available_layers = entry.layer_properties()?;
+        for layer in &self.layers {
+            if !available_layers.iter().any(|l| l.name.as_bytes() == layer.as_bytes()) {
+                let name = layer.to_string_lossy().into_owned();
+                return Err(Error::from(ValidationError::UnavailableLayer(name)));
+            }
+        }
+
+        // Extensions may be provided by the implementation or any enabled layer
+        let sources = Some(None).into_iter().chain(self.layers.iter().map(|l| Some(l.as_c_str())));
+        let mut available_extensions = Vec::new();
+        for layer in sources {
+            available_extensions.extend(entry.extension_properties(layer)?);
+        }
+        for extension in &self.extensions {
+            if !available_extensions.iter().any(|e| e.name.as_bytes() == extension.as_bytes()) {
+                let name = extension.to_string_lossy().into_owned();
+                return Err(Error::from(ValidationError::UnavailableExtension(name)));
+            }
+        }
+
+        let application_info = VkApplicationInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_APPLICATION_INFO,
+            p_next: ptr::null(),
+            p_application_name: self.application_name.as_ref().map_or(ptr::null(), |n| n.as_ptr()),
+            application_version: self.application_version,
+            p_engine_name: self.engine_name.as_ref().map_or(ptr::null(), |n| n.as_ptr()),
+            engine_version: self.engine_version,
+            api_version: self.api_version,
+        };
+        let layers = self.layers.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
+        let extensions = self.extensions.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
+        let create_info = VkInstanceCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO,
+            p_next: self.next,
+            flags: VkInstanceCreateFlags::Reserved,
+            p_application_info: &application_info,
+            enabled_layer_count: layers.len() as u32,
+            pp_enabled_layer_names: layers.as_ptr(),
+            enabled_extension_count: extensions.len() as u32,
+            pp_enabled_extension_names: extensions.as_ptr(),
+        };
+        Instance::create(entry, &create_info)
+    }
+}
+
+impl<'a> Default for InstanceBuilder<'a> {
+    fn default() -> Self {
+        InstanceBuilder::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PhysicalDeviceProperties {
+    pub api_version: u32,
+    pub driver_version: u32,
+    pub vendor_id: u32,
+    pub device_id: u32,
+    pub device_type: VkPhysicalDeviceType,
+    pub device_name: String,
+    pub pipeline_cache_uuid: [u8; VK_UUID_SIZE as usize],
+    pub limits: VkPhysicalDeviceLimits,
+    pub sparse_properties: VkPhysicalDeviceSparseProperties,
+}
+
 pub struct PhysicalDevice<'a> {
     handle: VkPhysicalDevice,
-    instance: PhantomData<&'a Instance>
+    instance: &'a Instance,
 }
 
 impl<'a> PhysicalDevice<'a> {
     pub fn handle(&self) -> &VkPhysicalDevice {&self.handle}
+    pub fn instance(&self) -> &'a Instance {self.instance}
+
+    pub fn properties(&self) -> PhysicalDeviceProperties {
+        let properties = unsafe {
+            let mut properties = mem::zeroed::<VkPhysicalDeviceProperties>();
+            (self.instance.fns.vkGetPhysicalDeviceProperties)(self.handle, &mut properties);
+            properties
+        };
+        let name = unsafe {CStr::from_ptr(properties.device_name.as_ptr())};
+        PhysicalDeviceProperties {
+            api_version: properties.api_version,
+            driver_version: properties.driver_version,
+            vendor_id: properties.vendor_id,
+            device_id: properties.device_id,
+            device_type: properties.device_type,
+            device_name: name.to_string_lossy().into_owned(),
+            pipeline_cache_uuid: properties.pipeline_cache_uuid,
+            limits: properties.limits,
+            sparse_properties: properties.sparse_properties,
+        }
+    }
+
+    pub fn features(&self) -> VkPhysicalDeviceFeatures {
+        let mut features = VkPhysicalDeviceFeatures::default();
+        unsafe {(self.instance.fns.vkGetPhysicalDeviceFeatures)(self.handle, &mut features)};
+        features
+    }
+
+    // Device layers are deprecated, but still reported by older implementations
+    pub fn layer_properties(&self) -> Result<Vec<LayerProperties>, Error> {
+        enumerate(|count, properties| unsafe {
+            (self.instance.fns.vkEnumerateDeviceLayerProperties)(self.handle, count, properties)
+        }).map(|properties| properties.iter().map(LayerProperties::from).collect())
+    }
+
+    pub fn extension_properties(&self, layer: Option<&CStr>) -> Result<Vec<ExtensionProperties>, Error> {
+        let layer = layer.map_or(ptr::null(), |l| l.as_ptr());
+        enumerate(|count, properties| unsafe {
+            (self.instance.fns.vkEnumerateDeviceExtensionProperties)(self.handle, layer, count, properties)
+        }).map(|properties| properties.iter().map(ExtensionProperties::from).collect())
+    }
+
+    pub fn memory_properties(&self) -> VkPhysicalDeviceMemoryProperties {
+        unsafe {
+            let mut properties = mem::zeroed::<VkPhysicalDeviceMemoryProperties>();
+            (self.instance.fns.vkGetPhysicalDeviceMemoryProperties)(self.handle, &mut properties);
+            properties
+        }
+    }
+
+    pub fn format_properties(&self, format: VkFormat) -> VkFormatProperties {
+        unsafe {
+            let mut properties = mem::zeroed::<VkFormatProperties>();
+            (self.instance.fns.vkGetPhysicalDeviceFormatProperties)(self.handle, format, &mut properties);
+            properties
+        }
+    }
+
+    // Limits for images created with these parameters, or None if the
+    // combination isn't supported at all
+    pub fn image_format_properties(&self, format: VkFormat, image_type: VkImageType, tiling: VkImageTiling,
+                                   usage: VkImageUsageFlags, flags: VkImageCreateFlags)
+                                   -> Result<Option<VkImageFormatProperties>, Error> {
+        let mut properties = unsafe {mem::zeroed::<VkImageFormatProperties>()};
+        match unsafe {(self.instance.fns.vkGetPhysicalDeviceImageFormatProperties)(self.handle, format, image_type,
+                                                                                   tiling, usage, flags,
+                                                                                   &mut properties)} {
+            VkResult::VK_SUCCESS => Ok(Some(properties)),
+            VkResult::VK_ERROR_FORMAT_NOT_SUPPORTED => Ok(None),
+            x => Err(Error::from(x)),
+        }
+    }
 
     pub fn queue_family_properties(&self) -> Vec<VkQueueFamilyProperties> {
+        let get_properties = self.instance.fns.vkGetPhysicalDeviceQueueFamilyProperties;
         let mut nqueues = 0;
-        unsafe {vkGetPhysicalDeviceQueueFamilyProperties(self.handle, &mut nqueues, ptr::null_mut())};
+        unsafe {get_properties(self.handle, &mut nqueues, ptr::null_mut())};
         let mut properties = Vec::<VkQueueFamilyProperties>::with_capacity(nqueues as usize);
         unsafe {
-            vkGetPhysicalDeviceQueueFamilyProperties(self.handle,
-                                                     &mut (properties.capacity() as u32),
-                                                     properties.as_mut_ptr());
+            get_properties(self.handle, &mut (properties.capacity() as u32),
+                           properties.as_mut_ptr());
             properties.set_len(nqueues as usize);
         };
         properties
     }
 }
 
+// The validation layer, if installed. Older SDKs only have the LunarG one.
+pub fn validation_layer(entry: &Entry) -> Option<CString> {
+    let available = entry.layer_properties().unwrap_or_default();
+    ["VK_LAYER_KHRONOS_validation", "VK_LAYER_LUNARG_standard_validation"].iter()
+        .find(|name| available.iter().any(|l| l.name == **name))
+        .map(|name| CString::new(*name).unwrap())
+}
+
 pub fn debug_instance() -> Instance {
+    let entry = Entry::new().unwrap();
     let exts = vec!(CString::new("VK_EXT_debug_report").unwrap());
-    let layers = vec!(CString::new("VK_LAYER_LUNARG_standard_validation").unwrap());
-    Instance::new(layers.iter(), exts.iter()).unwrap()
+    let layers = validation_layer(&entry);
+    Instance::with_entry(&entry, layers.iter(), exts.iter()).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use instance::*;
+    use entry::Entry;
+    use error::{Error, ValidationError};
+    use sys::common::*;
     use std::ffi::CString;
     use std::sync::atomic::Ordering;
 
@@ -107,8 +363,52 @@ mod tests {
 
     #[test]
     fn create_layers() {
-        let layers = vec!(CString::new("VK_LAYER_LUNARG_standard_validation").unwrap());
-        assert!(Instance::new(layers.iter(), None).is_ok());
+        let entry = Entry::new().unwrap();
+        let layers = validation_layer(&entry);
+        assert!(layers.is_some());
+        assert!(Instance::with_entry(&entry, layers.iter(), None).is_ok());
+    }
+
+    #[test]
+    fn create_with_entry() {
+        let entry = Entry::new().unwrap();
+        assert!(Instance::with_entry(&entry, None, None).is_ok());
+    }
+
+    #[test]
+    fn builder() {
+        let entry = Entry::new().unwrap();
+        let mut builder = InstanceBuilder::new()
+            .application(CString::new("vkrs test").unwrap(), vk_make_version(0, 1, 0))
+            .engine(CString::new("vkrs").unwrap(), vk_make_version(0, 1, 0))
+            .api_version(VK_API_VERSION_1_0)
+            .extension(CString::new("VK_EXT_debug_report").unwrap());
+        if let Some(layer) = validation_layer(&entry) {
+            builder = builder.layer(layer);
+        }
+        let instance = builder.build_with_entry(&entry).unwrap();
+        assert!(instance.debug_report_fns().is_some());
+    }
+
+    #[test]
+    fn builder_unavailable() {
+        let layer = InstanceBuilder::new().layer(CString::new("VK_LAYER_vkrs_missing").unwrap()).build();
+        match layer {
+            Err(Error::Validation(ValidationError::UnavailableLayer(name))) => assert_eq!(name, "VK_LAYER_vkrs_missing"),
+            _ => panic!("Expected an unavailable layer"),
+        }
+        let extension = InstanceBuilder::new().extension(CString::new("VK_vkrs_missing").unwrap()).build();
+        match extension {
+            Err(Error::Validation(ValidationError::UnavailableExtension(name))) => assert_eq!(name, "VK_vkrs_missing"),
+            _ => panic!("Expected an unavailable extension"),
+        }
+    }
+
+    #[test]
+    fn versions() {
+        let version = vk_make_version(1, 2, 3);
+        assert_eq!(vk_make_version(1, 0, 0), VK_API_VERSION_1_0);
+        assert_eq!((vk_version_major(version), vk_version_minor(version), vk_version_patch(version)), (1, 2, 3));
     }
 
     #[test]
@@ -117,6 +417,12 @@ mod tests {
         assert!(Instance::new(None, exts.iter()).is_ok());
     }
 
+    #[test]
+    fn extension_fns() {
+        assert!(Instance::new(None, None).unwrap().debug_report_fns().is_none());
+        assert!(debug_instance().debug_report_fns().is_some());
+    }
+
     #[test]
     fn enumerate_devices() {
         let instance = debug_instance();
@@ -126,6 +432,64 @@ mod tests {
         assert!(!errs.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn properties() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let properties = instance.devices().unwrap()[0].properties();
+        assert!(!properties.device_name.is_empty());
+        assert!(properties.api_version > 0);
+        // Minimum required by the spec
+        assert!(properties.limits.max_image_dimension2_d >= 4096);
+        assert!(properties.limits.max_bound_descriptor_sets >= 4);
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn memory_properties() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let properties = instance.devices().unwrap()[0].memory_properties();
+        assert!(properties.memory_type_count > 0);
+        assert!(properties.memory_heap_count > 0);
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn format_properties() {
+        use sys::image::*;
+
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = &instance.devices().unwrap()[0];
+        // Required to be supported by every implementation
+        let format = VkFormat::VK_FORMAT_R8G8B8A8_UNORM;
+        assert!(device.format_properties(format).optimal_tiling_features.contains(VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT));
+        let properties = device.image_format_properties(format, VkImageType::VK_IMAGE_TYPE_2D,
+                                                        VkImageTiling::VK_IMAGE_TILING_OPTIMAL,
+                                                        VK_IMAGE_USAGE_SAMPLED_BIT, VkImageCreateFlags::empty());
+        assert!(properties.unwrap().unwrap().max_extent.width >= 4096);
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn device_extensions() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = &instance.devices().unwrap()[0];
+        assert!(device.extension_properties(None).unwrap().iter().all(|e| !e.name.is_empty()));
+        assert!(device.layer_properties().is_ok());
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
     #[test]
     fn queue_family_properties() {
         let instance = debug_instance();
@@ -7,6 +7,8 @@
 // TODO: Track bitflags#20 for namespaced flags
 extern crate bitflags;
 
+pub mod error;
+pub mod entry;
 pub mod instance;
 pub mod device;
 pub mod sys;
@@ -16,3 +18,15 @@ pub mod command_buffer;
 pub mod fence;
 pub mod semaphore;
 pub mod event;
+pub mod memory;
+pub mod buffer;
+pub mod image;
+pub mod sampler;
+pub mod shader_module;
+pub mod descriptor;
+pub mod pipeline;
+pub mod render_pass;
+pub mod query;
+pub mod barrier;
+
+pub use error::Error;
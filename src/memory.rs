@@ -0,0 +1,313 @@
+extern crate libc;
+use self::libc::c_void;
+use sys::memory::*;
+use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
+use device::Device;
+use error::{Error, ValidationError};
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+
+pub type MemoryPropertyFlags = VkMemoryPropertyFlags;
+
+// Index of a memory type allowed by `type_bits` (e.g. from a buffer's memory
+// requirements) with all of the `required` properties, preferring one which
+// also has the `preferred` ones.
+pub fn find_memory_type(properties: &VkPhysicalDeviceMemoryProperties, type_bits: u32,
+                        required: MemoryPropertyFlags, preferred: MemoryPropertyFlags) -> Option<u32> {
+    let types = (0..properties.memory_type_count)
+        .filter(|&i| type_bits & (1 << i) != 0)
+        .filter(|&i| properties.memory_types[i as usize].property_flags.contains(required))
+        .collect::<Vec<_>>();
+    types.iter().cloned()
+        .find(|&i| properties.memory_types[i as usize].property_flags.contains(preferred))
+        .or_else(|| types.first().cloned())
+}
+
+pub struct DeviceMemory<'a> {
+    handle: VkDeviceMemory,
+    device: &'a Device<'a>,
+    size: u64,
+    memory_type: u32,
+}
+
+impl<'a> DeviceMemory<'a> {
+    pub fn allocate(device: &'a Device, size: u64, type_bits: u32,
+                    required: MemoryPropertyFlags, preferred: MemoryPropertyFlags) -> Result<Self, Error> {
+        let memory_type = match find_memory_type(device.memory_properties(), type_bits, required, preferred) {
+            Some(memory_type) => memory_type,
+            None => return Err(Error::from(ValidationError::NoSuitableMemoryType)),
+        };
+        let allocate_info = VkMemoryAllocateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO,
+            p_next: ptr::null(),
+            allocation_size: size,
+            memory_type_index: memory_type,
+        };
+
+        let mut memory = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkAllocateMemory)(*device.handle(), &allocate_info, ptr::null(), &mut memory)} {
+            VkResult::VK_SUCCESS => Ok(DeviceMemory{handle: memory, device: device, size: size,
+                                                    memory_type: memory_type}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkDeviceMemory {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn size(&self) -> u64 {self.size}
+    pub fn memory_type(&self) -> u32 {self.memory_type}
+
+    pub fn properties(&self) -> MemoryPropertyFlags {
+        self.device.memory_properties().memory_types[self.memory_type as usize].property_flags
+    }
+
+    // Maps `size` bytes (or VK_WHOLE_SIZE) from `offset`, until the returned
+    // slice is dropped. Use &mut here, because memory can only be mapped once.
+    pub fn map<'m>(&'m mut self, offset: u64, size: u64) -> Result<MappedMemory<'m, 'a>, Error> {
+        if !self.properties().contains(VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT) {
+            return Err(Error::from(ValidationError::MemoryNotHostVisible));
+        }
+        let len = if size == VK_WHOLE_SIZE {self.size.saturating_sub(offset)} else {size};
+        if offset >= self.size || len == 0 || len > self.size - offset {
+            return Err(Error::from(ValidationError::MemoryRangeOutOfBounds{
+                offset: offset, size: len, allocation_size: self.size
+            }));
+        }
+
+        let mut data = ptr::null_mut::<c_void>();
+        match unsafe {(self.device.fns().vkMapMemory)(*self.device.handle(), self.handle, offset, size,
+                                                      VkMemoryMapFlags::Reserved, &mut data)} {
+            VkResult::VK_SUCCESS => Ok(MappedMemory{memory: self, data: data as *mut u8, offset: offset,
+                                                    len: len as usize}),
+            x => Err(Error::from(x)),
+        }
+    }
+}
+
+// Checks that a resource with `requirements` can be bound to `memory` at `offset`
+pub(crate) fn check_binding(memory: &DeviceMemory, offset: u64, requirements: &VkMemoryRequirements)
+                            -> Result<(), Error> {
+    if requirements.memory_type_bits & (1 << memory.memory_type) == 0 {
+        return Err(Error::from(ValidationError::IncompatibleMemoryType(memory.memory_type)));
+    }
+    if requirements.alignment != 0 && offset % requirements.alignment != 0 {
+        return Err(Error::from(ValidationError::MisalignedMemoryOffset{
+            offset: offset, alignment: requirements.alignment
+        }));
+    }
+    if offset >= memory.size || requirements.size > memory.size - offset {
+        return Err(Error::from(ValidationError::MemoryRangeOutOfBounds{
+            offset: offset, size: requirements.size, allocation_size: memory.size
+        }));
+    }
+    Ok(())
+}
+
+// Ranges to flush or invalidate must lie within the mapped range, start at a
+// multiple of `atom_size`, and span a multiple of it unless they run to the
+// end of the allocation. VK_WHOLE_SIZE runs to the end of the mapping.
+fn check_mapped_range(offset: u64, size: u64, map_offset: u64, map_size: u64, allocation_size: u64,
+                      atom_size: u64) -> Result<(), Error> {
+    let map_end = map_offset + map_size;
+    let len = if size == VK_WHOLE_SIZE {map_end.saturating_sub(offset)} else {size};
+    if offset < map_offset || offset >= map_end || len == 0 || len > map_end - offset {
+        return Err(Error::from(ValidationError::RangeNotMapped{
+            offset: offset, size: len, map_offset: map_offset, map_size: map_size
+        }));
+    }
+    if atom_size != 0 && (offset % atom_size != 0 || (len % atom_size != 0 && offset + len != allocation_size)) {
+        return Err(Error::from(ValidationError::MisalignedMappedRange{
+            offset: offset, size: len, atom_size: atom_size
+        }));
+    }
+    Ok(())
+}
+
+impl<'a> Drop for DeviceMemory<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkFreeMemory)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+pub struct MappedMemory<'m, 'a: 'm> {
+    memory: &'m mut DeviceMemory<'a>,
+    data: *mut u8,
+    // The mapped range, from the start of the memory object
+    offset: u64,
+    len: usize,
+}
+
+impl<'m, 'a> MappedMemory<'m, 'a> {
+    fn ranges(&self, ranges: &[(u64, u64)]) -> Result<Vec<VkMappedMemoryRange>, Error> {
+        let atom_size = self.memory.device.limits().non_coherent_atom_size;
+        ranges.iter().map(|&(offset, size)| {
+            check_mapped_range(offset, size, self.offset, self.len as u64, self.memory.size, atom_size)?;
+            Ok(VkMappedMemoryRange {
+                s_type: VkStructureType::VK_STRUCTURE_TYPE_MAPPED_MEMORY_RANGE,
+                p_next: ptr::null(),
+                memory: self.memory.handle,
+                offset: offset,
+                size: size,
+            })
+        }).collect()
+    }
+
+    // Only needed for memory without VK_MEMORY_PROPERTY_HOST_COHERENT_BIT.
+    // Ranges are (offset, size) from the start of the memory object, not the
+    // mapping, but must lie within the mapping (VK_WHOLE_SIZE runs to its
+    // end) and be aligned to `non_coherent_atom_size`.
+    pub fn flush_mapped_ranges(&self, ranges: &[(u64, u64)]) -> Result<(), Error> {
+        let ranges = self.ranges(ranges)?;
+        let device = self.memory.device;
+        match unsafe {(device.fns().vkFlushMappedMemoryRanges)(*device.handle(), ranges.len() as u32,
+                                                               ranges.as_ptr())} {
+            VkResult::VK_SUCCESS => Ok(()),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn invalidate_mapped_ranges(&mut self, ranges: &[(u64, u64)]) -> Result<(), Error> {
+        let ranges = self.ranges(ranges)?;
+        let device = self.memory.device;
+        match unsafe {(device.fns().vkInvalidateMappedMemoryRanges)(*device.handle(), ranges.len() as u32,
+                                                                    ranges.as_ptr())} {
+            VkResult::VK_SUCCESS => Ok(()),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn unmap(self) {}
+}
+
+impl<'m, 'a> Deref for MappedMemory<'m, 'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe {slice::from_raw_parts(self.data, self.len)}
+    }
+}
+
+impl<'m, 'a> DerefMut for MappedMemory<'m, 'a> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe {slice::from_raw_parts_mut(self.data, self.len)}
+    }
+}
+
+impl<'m, 'a> Drop for MappedMemory<'m, 'a> {
+    fn drop(&mut self) {
+        unsafe {(self.memory.device.fns().vkUnmapMemory)(*self.memory.device.handle(), self.memory.handle)}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority};
+    use error::{Error, ValidationError};
+    use std::collections::HashMap;
+    use std::mem;
+
+    use memory::*;
+
+    #[test]
+    fn choose_memory_type() {
+        let mut properties = unsafe {mem::zeroed::<VkPhysicalDeviceMemoryProperties>()};
+        properties.memory_type_count = 3;
+        properties.memory_types[0].property_flags = VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT;
+        properties.memory_types[1].property_flags = VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT | VK_MEMORY_PROPERTY_HOST_COHERENT_BIT;
+        properties.memory_types[2].property_flags = VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT | VK_MEMORY_PROPERTY_HOST_CACHED_BIT;
+
+        let none = MemoryPropertyFlags::empty();
+        assert_eq!(find_memory_type(&properties, !0, VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT, none), Some(1));
+        assert_eq!(find_memory_type(&properties, !0, VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT,
+                                    VK_MEMORY_PROPERTY_HOST_CACHED_BIT), Some(2));
+        assert_eq!(find_memory_type(&properties, 0b001, VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT, none), None);
+        assert_eq!(find_memory_type(&properties, 0b110, none, VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT), Some(1));
+    }
+
+    #[test]
+    fn mapped_range_validation() {
+        assert_eq!(check_mapped_range(0, VK_WHOLE_SIZE, 0, 1000, 1000, 64), Ok(()));
+        assert_eq!(check_mapped_range(64, 128, 0, 1000, 1000, 64), Ok(()));
+        // Ranges running to the end of the allocation needn't be whole atoms
+        assert_eq!(check_mapped_range(960, 40, 0, 1000, 1000, 64), Ok(()));
+        assert_eq!(check_mapped_range(960, 64, 0, 1000, 1000, 64),
+                   Err(Error::from(ValidationError::RangeNotMapped{offset: 960, size: 64, map_offset: 0, map_size: 1000})));
+        assert_eq!(check_mapped_range(1000, VK_WHOLE_SIZE, 0, 1000, 1000, 64),
+                   Err(Error::from(ValidationError::RangeNotMapped{offset: 1000, size: 0, map_offset: 0, map_size: 1000})));
+        assert_eq!(check_mapped_range(32, 64, 0, 1000, 1000, 64),
+                   Err(Error::from(ValidationError::MisalignedMappedRange{offset: 32, size: 64, atom_size: 64})));
+        assert_eq!(check_mapped_range(0, 100, 0, 1000, 1000, 64),
+                   Err(Error::from(ValidationError::MisalignedMappedRange{offset: 0, size: 100, atom_size: 64})));
+
+        // VK_WHOLE_SIZE runs to the end of the mapping, not the allocation
+        assert_eq!(check_mapped_range(128, VK_WHOLE_SIZE, 128, 256, 1000, 64), Ok(()));
+        assert_eq!(check_mapped_range(128, VK_WHOLE_SIZE, 128, 100, 1000, 64),
+                   Err(Error::from(ValidationError::MisalignedMappedRange{offset: 128, size: 100, atom_size: 64})));
+        assert_eq!(check_mapped_range(64, 64, 128, 256, 1000, 64),
+                   Err(Error::from(ValidationError::RangeNotMapped{offset: 64, size: 64, map_offset: 128, map_size: 256})));
+        assert_eq!(check_mapped_range(320, 128, 128, 256, 1000, 64),
+                   Err(Error::from(ValidationError::RangeNotMapped{offset: 320, size: 128, map_offset: 128, map_size: 256})));
+    }
+
+    #[test]
+    fn allocate_memory() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let memory = DeviceMemory::allocate(&device, 1024, !0, MemoryPropertyFlags::empty(),
+                                            VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT).unwrap();
+        assert_eq!(memory.size(), 1024);
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn map_memory() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let mut memory = DeviceMemory::allocate(&device, 1024, !0, VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT,
+                                                MemoryPropertyFlags::empty()).unwrap();
+        {
+            let mut mapped = memory.map(0, VK_WHOLE_SIZE).unwrap();
+            assert_eq!(mapped.len(), 1024);
+            mapped[..4].copy_from_slice(&[1, 2, 3, 4]);
+            mapped.flush_mapped_ranges(&[(0, VK_WHOLE_SIZE)]).unwrap();
+        }
+        let mut mapped = memory.map(0, VK_WHOLE_SIZE).unwrap();
+        mapped.invalidate_mapped_ranges(&[(0, VK_WHOLE_SIZE)]).unwrap();
+        assert_eq!(&mapped[..4], &[1, 2, 3, 4]);
+        assert_eq!(mapped.flush_mapped_ranges(&[(0, 2048)]),
+                   Err(Error::from(ValidationError::RangeNotMapped{offset: 0, size: 2048, map_offset: 0, map_size: 1024})));
+        mapped.unmap();
+
+        // Atoms are at most 256 bytes, so these ranges are aligned
+        let mapped = memory.map(256, 256).unwrap();
+        mapped.flush_mapped_ranges(&[(256, VK_WHOLE_SIZE)]).unwrap();
+        assert_eq!(mapped.flush_mapped_ranges(&[(512, 256)]),
+                   Err(Error::from(ValidationError::RangeNotMapped{offset: 512, size: 256, map_offset: 256, map_size: 256})));
+        assert_eq!(mapped.flush_mapped_ranges(&[(0, VK_WHOLE_SIZE)]),
+                   Err(Error::from(ValidationError::RangeNotMapped{offset: 0, size: 512, map_offset: 256, map_size: 256})));
+        mapped.unmap();
+
+        match memory.map(1000, 100) {
+            Err(Error::Validation(ValidationError::MemoryRangeOutOfBounds{offset: 1000, size: 100, allocation_size: 1024})) => {},
+            _ => panic!("Expected an out of bounds error"),
+        }
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,1072 @@
+extern crate libc;
+use self::libc::c_void;
+use sys::pipeline::*;
+use sys::common::{VkBool32, VkResult, VkStructureType, VkCompareOp, VkRect2D, VkSampleCountFlags,
+                  VK_SAMPLE_COUNT_1_BIT, VK_NULL_HANDLE};
+use sys::device::VkPhysicalDeviceFeatures;
+use sys::instance::VK_UUID_SIZE;
+use device::Device;
+use instance::PhysicalDeviceProperties;
+use descriptor::DescriptorSetLayout;
+use shader_module::ShaderModule;
+use render_pass::RenderPass;
+use error::{Error, ValidationError};
+use std::ffi::CStr;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::ptr;
+
+pub type ShaderStageFlags = VkShaderStageFlags;
+pub type PushConstantRange = VkPushConstantRange;
+pub type VertexInputBinding = VkVertexInputBindingDescription;
+pub type VertexInputAttribute = VkVertexInputAttributeDescription;
+pub type Viewport = VkViewport;
+pub type CullModeFlags = VkCullModeFlags;
+pub type StencilOpState = VkStencilOpState;
+pub type ColorBlendAttachment = VkPipelineColorBlendAttachmentState;
+
+pub trait Pipeline {
+    const BIND_POINT: VkPipelineBindPoint;
+
+    fn handle(&self) -> &VkPipeline;
+}
+
+pub struct PipelineLayout<'a> {
+    handle: VkPipelineLayout,
+    device: &'a Device<'a>,
+    push_constant_ranges: Vec<PushConstantRange>,
+}
+
+impl<'a> PipelineLayout<'a> {
+    pub fn new(device: &'a Device, set_layouts: &[&DescriptorSetLayout<'a>],
+               push_constant_ranges: &[PushConstantRange]) -> Result<Self, Error> {
+        let max_size = device.limits().max_push_constants_size;
+        for range in push_constant_ranges {
+            if range.offset % 4 != 0 || range.size == 0 || range.size % 4 != 0 ||
+                range.offset >= max_size || range.size > max_size - range.offset {
+                return Err(Error::from(ValidationError::InvalidPushConstantRange{
+                    offset: range.offset, size: range.size
+                }));
+            }
+        }
+        let set_layouts = set_layouts.iter().map(|l| *l.handle()).collect::<Vec<_>>();
+        let create_info = VkPipelineLayoutCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_LAYOUT_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineLayoutCreateFlags::Reserved,
+            set_layout_count: set_layouts.len() as u32,
+            p_set_layouts: set_layouts.as_ptr(),
+            push_constant_range_count: push_constant_ranges.len() as u32,
+            p_push_constant_ranges: push_constant_ranges.as_ptr(),
+        };
+        let mut layout = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreatePipelineLayout)(*device.handle(), &create_info, ptr::null(), &mut layout)} {
+            VkResult::VK_SUCCESS => Ok(PipelineLayout{handle: layout, device: device,
+                                                      push_constant_ranges: push_constant_ranges.to_vec()}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkPipelineLayout {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn push_constant_ranges(&self) -> &[PushConstantRange] {&self.push_constant_ranges}
+}
+
+// Checks an update of `size` bytes of push constants at `offset` for
+// `stages`. Each byte must be in a range for every one of the stages, and
+// every range containing it must be updated for all of its stages.
+pub(crate) fn check_push_constants(ranges: &[PushConstantRange], stages: ShaderStageFlags, offset: u32, size: u32)
+                                   -> Result<(), Error> {
+    let invalid = Error::from(ValidationError::InvalidPushConstantRange{offset: offset, size: size});
+    if stages.is_empty() || offset % 4 != 0 || size == 0 || size % 4 != 0 {
+        return Err(invalid);
+    }
+    let end = offset as u64 + size as u64;
+    for byte in (offset as u64 / 4..end / 4).map(|word| word * 4) {
+        let containing = ranges.iter()
+            .filter(|r| r.offset as u64 <= byte && byte < r.offset as u64 + r.size as u64)
+            .collect::<Vec<_>>();
+        let declared = containing.iter().fold(ShaderStageFlags::empty(), |acc, r| acc | r.stage_flags);
+        if !declared.contains(stages) || containing.iter().any(|r| !stages.contains(r.stage_flags)) {
+            return Err(invalid);
+        }
+    }
+    Ok(())
+}
+
+impl<'a> Drop for PipelineLayout<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyPipelineLayout)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+// Values for a shader's specialization constants, by constant_id
+#[derive(Debug, Clone, Default)]
+pub struct Specialization {
+    entries: Vec<VkSpecializationMapEntry>,
+    data: Vec<u8>,
+}
+
+impl Specialization {
+    pub fn new() -> Self {
+        Specialization::default()
+    }
+
+    // `value` must match the constant's type, e.g. 4 bytes for a bool
+    pub fn constant(mut self, constant_id: u32, value: &[u8]) -> Self {
+        self.entries.push(VkSpecializationMapEntry {
+            constant_id: constant_id,
+            offset: self.data.len() as u32,
+            size: value.len(),
+        });
+        self.data.extend_from_slice(value);
+        self
+    }
+
+    pub(crate) fn info(&self) -> VkSpecializationInfo {
+        VkSpecializationInfo {
+            map_entry_count: self.entries.len() as u32,
+            p_map_entries: self.entries.as_ptr(),
+            data_size: self.data.len(),
+            p_data: self.data.as_ptr() as *const c_void,
+        }
+    }
+}
+
+// Length, version, vendor ID, device ID and pipelineCacheUUID
+const PIPELINE_CACHE_HEADER_SIZE: usize = 16 + VK_UUID_SIZE as usize;
+
+// Whether cache data was written by the same kind of device and driver. The
+// header is always little endian.
+fn cache_header_matches(data: &[u8], properties: &PhysicalDeviceProperties) -> bool {
+    if data.len() < PIPELINE_CACHE_HEADER_SIZE {
+        return false;
+    }
+    let word = |i: usize| {
+        let b = &data[i * 4..i * 4 + 4];
+        (b[0] as u32) | (b[1] as u32) << 8 | (b[2] as u32) << 16 | (b[3] as u32) << 24
+    };
+    let header_length = word(0) as usize;
+    header_length >= PIPELINE_CACHE_HEADER_SIZE && header_length <= data.len() &&
+        word(1) == VkPipelineCacheHeaderVersion::VK_PIPELINE_CACHE_HEADER_VERSION_ONE.0 as u32 &&
+        word(2) == properties.vendor_id && word(3) == properties.device_id &&
+        data[16..PIPELINE_CACHE_HEADER_SIZE] == properties.pipeline_cache_uuid[..]
+}
+
+pub struct PipelineCache<'a> {
+    handle: VkPipelineCache,
+    device: &'a Device<'a>,
+}
+
+impl<'a> PipelineCache<'a> {
+    // `initial_data` is handed to the driver as is, see `load` for data which
+    // may be stale
+    pub fn new(device: &'a Device, initial_data: &[u8]) -> Result<Self, Error> {
+        let create_info = VkPipelineCacheCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_CACHE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineCacheCreateFlags::Reserved,
+            initial_data_size: initial_data.len(),
+            p_initial_data: initial_data.as_ptr() as *const c_void,
+        };
+        let mut cache = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreatePipelineCache)(*device.handle(), &create_info, ptr::null(), &mut cache)} {
+            VkResult::VK_SUCCESS => Ok(PipelineCache{handle: cache, device: device}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    // Loads a cache saved with `save`. Starts with an empty cache if the file
+    // doesn't exist, or was written for another device or driver version.
+    pub fn load<P: AsRef<Path>>(device: &'a Device, path: P) -> Result<Self, Error> {
+        let mut data = Vec::new();
+        match fs::File::open(path) {
+            Ok(mut file) => {file.read_to_end(&mut data)?;},
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => {},
+            Err(err) => return Err(Error::from(err)),
+        }
+        if !cache_header_matches(&data, device.properties()) {
+            data.clear();
+        }
+        PipelineCache::new(device, &data)
+    }
+
+    // Writes to a temporary file first, so a crash can't leave a truncated cache
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let data = self.data()?;
+        let path = path.as_ref();
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        {
+            let mut file = fs::File::create(&tmp_path)?;
+            file.write_all(&data)?;
+        }
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    pub fn data(&self) -> Result<Vec<u8>, Error> {
+        let fns = self.device.fns();
+        // The cache can grow between the two calls
+        loop {
+            let mut size = 0;
+            match unsafe {(fns.vkGetPipelineCacheData)(*self.device.handle(), self.handle, &mut size, ptr::null_mut())} {
+                VkResult::VK_SUCCESS => {},
+                x => return Err(Error::from(x)),
+            }
+            let mut data = Vec::<u8>::with_capacity(size);
+            match unsafe {(fns.vkGetPipelineCacheData)(*self.device.handle(), self.handle, &mut size,
+                                                       data.as_mut_ptr() as *mut c_void)} {
+                VkResult::VK_SUCCESS => {
+                    unsafe {data.set_len(size)};
+                    return Ok(data);
+                }
+                VkResult::VK_INCOMPLETE => continue,
+                x => return Err(Error::from(x)),
+            }
+        }
+    }
+
+    // Adds the pipelines of `sources` to this cache
+    pub fn merge(&mut self, sources: &[&PipelineCache<'a>]) -> Result<(), Error> {
+        let sources = sources.iter().map(|c| c.handle).collect::<Vec<_>>();
+        match unsafe {(self.device.fns().vkMergePipelineCaches)(*self.device.handle(), self.handle,
+                                                                sources.len() as u32, sources.as_ptr())} {
+            VkResult::VK_SUCCESS => Ok(()),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkPipelineCache {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+}
+
+impl<'a> Drop for PipelineCache<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyPipelineCache)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+pub struct ComputePipeline<'a> {
+    handle: VkPipeline,
+    device: &'a Device<'a>,
+}
+
+impl<'a> ComputePipeline<'a> {
+    pub fn new(device: &'a Device, layout: &PipelineLayout<'a>, module: &ShaderModule<'a>, entry_point: &CStr,
+               specialization: Option<&Specialization>, cache: Option<&PipelineCache<'a>>) -> Result<Self, Error> {
+        let specialization_info = specialization.map(Specialization::info);
+        let create_info = VkComputePipelineCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_COMPUTE_PIPELINE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineCreateFlags::empty(),
+            stage: VkPipelineShaderStageCreateInfo {
+                s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO,
+                p_next: ptr::null(),
+                flags: VkPipelineShaderStageCreateFlags::Reserved,
+                stage: VK_SHADER_STAGE_COMPUTE_BIT,
+                module: *module.handle(),
+                p_name: entry_point.as_ptr(),
+                p_specialization_info: specialization_info.as_ref().map_or(ptr::null(), |info| info),
+            },
+            layout: *layout.handle(),
+            base_pipeline_handle: VK_NULL_HANDLE,
+            base_pipeline_index: -1,
+        };
+        let mut pipeline = VK_NULL_HANDLE;
+        let cache = cache.map_or(VK_NULL_HANDLE, |c| c.handle);
+        match unsafe {(device.fns().vkCreateComputePipelines)(*device.handle(), cache, 1, &create_info,
+                                                              ptr::null(), &mut pipeline)} {
+            VkResult::VK_SUCCESS => Ok(ComputePipeline{handle: pipeline, device: device}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+}
+
+impl<'a> Pipeline for ComputePipeline<'a> {
+    const BIND_POINT: VkPipelineBindPoint = VkPipelineBindPoint::VK_PIPELINE_BIND_POINT_COMPUTE;
+
+    fn handle(&self) -> &VkPipeline {&self.handle}
+}
+
+impl<'a> Drop for ComputePipeline<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyPipeline)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+enum Viewports {
+    Static(Vec<(Viewport, VkRect2D)>),
+    Dynamic(u32),
+}
+
+struct ShaderStage<'a: 's, 's> {
+    stage: ShaderStageFlags,
+    module: &'s ShaderModule<'a>,
+    entry_point: &'s CStr,
+    specialization: Option<&'s Specialization>,
+}
+
+// Everything but the layout and render pass of a graphics pipeline. By
+// default: triangle lists, one dynamic viewport and scissor, filled polygons
+// without culling, a single sample, no depth or stencil test, and colour
+// attachments written without blending. Only shader stages need adding.
+pub struct GraphicsPipelineBuilder<'a: 's, 's> {
+    stages: Vec<ShaderStage<'a, 's>>,
+    vertex_bindings: Vec<VertexInputBinding>,
+    vertex_attributes: Vec<VertexInputAttribute>,
+    topology: VkPrimitiveTopology,
+    primitive_restart: bool,
+    patch_control_points: u32,
+    viewports: Viewports,
+    rasterizer_discard: bool,
+    polygon_mode: VkPolygonMode,
+    cull_mode: CullModeFlags,
+    front_face: VkFrontFace,
+    line_width: f32,
+    depth_clamp: bool,
+    // Constant factor, clamp and slope factor
+    depth_bias: Option<(f32, f32, f32)>,
+    samples: VkSampleCountFlags,
+    min_sample_shading: Option<f32>,
+    alpha_to_coverage: bool,
+    alpha_to_one: bool,
+    depth_test: Option<(VkCompareOp, bool)>,
+    depth_bounds: Option<(f32, f32)>,
+    stencil_test: Option<(StencilOpState, StencilOpState)>,
+    logic_op: Option<VkLogicOp>,
+    // One per colour attachment of the subpass, defaults if None
+    blend_attachments: Option<Vec<ColorBlendAttachment>>,
+    blend_constants: [f32; 4],
+    dynamic_states: Vec<VkDynamicState>,
+    cache: Option<&'s PipelineCache<'a>>,
+}
+
+impl<'a, 's> GraphicsPipelineBuilder<'a, 's> {
+    pub fn new() -> Self {
+        GraphicsPipelineBuilder {
+            stages: Vec::new(),
+            vertex_bindings: Vec::new(),
+            vertex_attributes: Vec::new(),
+            topology: VkPrimitiveTopology::VK_PRIMITIVE_TOPOLOGY_TRIANGLE_LIST,
+            primitive_restart: false,
+            patch_control_points: 0,
+            viewports: Viewports::Dynamic(1),
+            rasterizer_discard: false,
+            polygon_mode: VkPolygonMode::VK_POLYGON_MODE_FILL,
+            cull_mode: VK_CULL_MODE_NONE,
+            front_face: VkFrontFace::VK_FRONT_FACE_COUNTER_CLOCKWISE,
+            line_width: 1.0,
+            depth_clamp: false,
+            depth_bias: None,
+            samples: VK_SAMPLE_COUNT_1_BIT,
+            min_sample_shading: None,
+            alpha_to_coverage: false,
+            alpha_to_one: false,
+            depth_test: None,
+            depth_bounds: None,
+            stencil_test: None,
+            logic_op: None,
+            blend_attachments: None,
+            blend_constants: [0.0; 4],
+            dynamic_states: Vec::new(),
+            cache: None,
+        }
+    }
+
+    pub fn stage(mut self, stage: ShaderStageFlags, module: &'s ShaderModule<'a>, entry_point: &'s CStr,
+                 specialization: Option<&'s Specialization>) -> Self {
+        self.stages.push(ShaderStage{stage: stage, module: module, entry_point: entry_point,
+                                     specialization: specialization});
+        self
+    }
+
+    pub fn vertex_input(mut self, bindings: &[VertexInputBinding], attributes: &[VertexInputAttribute]) -> Self {
+        self.vertex_bindings = bindings.to_vec();
+        self.vertex_attributes = attributes.to_vec();
+        self
+    }
+
+    pub fn input_assembly(mut self, topology: VkPrimitiveTopology, primitive_restart: bool) -> Self {
+        self.topology = topology;
+        self.primitive_restart = primitive_restart;
+        self
+    }
+
+    // Only used with tessellation shaders, which need a patch list topology
+    pub fn tessellation(mut self, patch_control_points: u32) -> Self {
+        self.patch_control_points = patch_control_points;
+        self
+    }
+
+    // Adds a static viewport and its scissor, replacing any dynamic ones
+    pub fn viewport(mut self, viewport: Viewport, scissor: VkRect2D) -> Self {
+        match self.viewports {
+            Viewports::Static(ref mut viewports) => viewports.push((viewport, scissor)),
+            Viewports::Dynamic(_) => self.viewports = Viewports::Static(vec!((viewport, scissor))),
+        }
+        self
+    }
+
+    // Viewports and scissors set while recording, with `set_viewports` and `set_scissors`
+    pub fn dynamic_viewports(mut self, count: u32) -> Self {
+        self.viewports = Viewports::Dynamic(count);
+        self
+    }
+
+    pub fn rasterizer_discard(mut self, rasterizer_discard: bool) -> Self {
+        self.rasterizer_discard = rasterizer_discard;
+        self
+    }
+
+    pub fn polygon_mode(mut self, polygon_mode: VkPolygonMode) -> Self {
+        self.polygon_mode = polygon_mode;
+        self
+    }
+
+    pub fn cull_mode(mut self, cull_mode: CullModeFlags, front_face: VkFrontFace) -> Self {
+        self.cull_mode = cull_mode;
+        self.front_face = front_face;
+        self
+    }
+
+    pub fn line_width(mut self, line_width: f32) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn depth_clamp(mut self, depth_clamp: bool) -> Self {
+        self.depth_clamp = depth_clamp;
+        self
+    }
+
+    pub fn depth_bias(mut self, constant_factor: f32, clamp: f32, slope_factor: f32) -> Self {
+        self.depth_bias = Some((constant_factor, clamp, slope_factor));
+        self
+    }
+
+    // Must match the sample count of the subpass's attachments
+    pub fn samples(mut self, samples: VkSampleCountFlags) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn sample_shading(mut self, min_sample_shading: f32) -> Self {
+        self.min_sample_shading = Some(min_sample_shading);
+        self
+    }
+
+    pub fn alpha_to_coverage(mut self, alpha_to_coverage: bool) -> Self {
+        self.alpha_to_coverage = alpha_to_coverage;
+        self
+    }
+
+    pub fn alpha_to_one(mut self, alpha_to_one: bool) -> Self {
+        self.alpha_to_one = alpha_to_one;
+        self
+    }
+
+    pub fn depth_test(mut self, compare_op: VkCompareOp, write: bool) -> Self {
+        self.depth_test = Some((compare_op, write));
+        self
+    }
+
+    pub fn depth_bounds(mut self, min: f32, max: f32) -> Self {
+        self.depth_bounds = Some((min, max));
+        self
+    }
+
+    pub fn stencil_test(mut self, front: StencilOpState, back: StencilOpState) -> Self {
+        self.stencil_test = Some((front, back));
+        self
+    }
+
+    // Replaces blending for all colour attachments
+    pub fn logic_op(mut self, logic_op: VkLogicOp) -> Self {
+        self.logic_op = Some(logic_op);
+        self
+    }
+
+    // One for each colour attachment of the subpass
+    pub fn blend_attachments(mut self, attachments: &[ColorBlendAttachment]) -> Self {
+        self.blend_attachments = Some(attachments.to_vec());
+        self
+    }
+
+    pub fn blend_constants(mut self, blend_constants: [f32; 4]) -> Self {
+        self.blend_constants = blend_constants;
+        self
+    }
+
+    // Dynamic viewports and scissors are added by `dynamic_viewports`
+    pub fn dynamic_state(mut self, state: VkDynamicState) -> Self {
+        if !self.dynamic_states.contains(&state) {
+            self.dynamic_states.push(state);
+        }
+        self
+    }
+
+    pub fn pipeline_cache(mut self, cache: &'s PipelineCache<'a>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    fn viewport_count(&self) -> u32 {
+        match self.viewports {
+            Viewports::Static(ref viewports) => viewports.len() as u32,
+            Viewports::Dynamic(count) => count,
+        }
+    }
+
+    fn dynamic_states(&self) -> Vec<VkDynamicState> {
+        let mut dynamic_states = self.dynamic_states.clone();
+        if let Viewports::Dynamic(_) = self.viewports {
+            for state in &[VkDynamicState::VK_DYNAMIC_STATE_VIEWPORT, VkDynamicState::VK_DYNAMIC_STATE_SCISSOR] {
+                if !dynamic_states.contains(state) {
+                    dynamic_states.push(*state);
+                }
+            }
+        }
+        dynamic_states
+    }
+
+    fn check(&self, features: &VkPhysicalDeviceFeatures, color_attachment_count: u32) -> Result<(), Error> {
+        if let Some(ref attachments) = self.blend_attachments {
+            if attachments.len() as u32 != color_attachment_count {
+                return Err(Error::from(ValidationError::AttachmentCountMismatch{
+                    expected: color_attachment_count, actual: attachments.len() as u32
+                }));
+            }
+        }
+        let stages = self.stages.iter().fold(ShaderStageFlags::empty(), |s, stage| s | stage.stage);
+        let viewport_count = self.viewport_count();
+        let blend_attachments = self.blend_attachments.as_ref().map_or(&[][..], |a| &a[..]);
+        let dual_source = |f: VkBlendFactor| f.0 >= VkBlendFactor::VK_BLEND_FACTOR_SRC1_COLOR.0;
+        let required = [
+            ("geometry_shader", stages.contains(VK_SHADER_STAGE_GEOMETRY_BIT), features.geometry_shader),
+            ("tessellation_shader", stages.intersects(VK_SHADER_STAGE_TESSELLATION_CONTROL_BIT |
+                                                      VK_SHADER_STAGE_TESSELLATION_EVALUATION_BIT),
+             features.tessellation_shader),
+            ("multi_viewport", viewport_count > 1, features.multi_viewport),
+            ("fill_mode_non_solid", self.polygon_mode != VkPolygonMode::VK_POLYGON_MODE_FILL,
+             features.fill_mode_non_solid),
+            ("wide_lines", self.line_width != 1.0 &&
+             !self.dynamic_states.contains(&VkDynamicState::VK_DYNAMIC_STATE_LINE_WIDTH), features.wide_lines),
+            ("depth_clamp", self.depth_clamp, features.depth_clamp),
+            ("depth_bias_clamp", self.depth_bias.map_or(false, |(_, clamp, _)| clamp != 0.0), features.depth_bias_clamp),
+            ("sample_rate_shading", self.min_sample_shading.is_some(), features.sample_rate_shading),
+            ("alpha_to_one", self.alpha_to_one, features.alpha_to_one),
+            ("depth_bounds", self.depth_bounds.is_some(), features.depth_bounds),
+            ("logic_op", self.logic_op.is_some(), features.logic_op),
+            ("independent_blend", blend_attachments.windows(2).any(|w| w[0] != w[1]), features.independent_blend),
+            ("dual_src_blend", blend_attachments.iter().any(|a| {
+                dual_source(a.src_color_blend_factor) || dual_source(a.dst_color_blend_factor) ||
+                    dual_source(a.src_alpha_blend_factor) || dual_source(a.dst_alpha_blend_factor)
+            }), features.dual_src_blend),
+        ];
+        for &(name, needed, enabled) in &required {
+            if needed && enabled != VkBool32::True {
+                return Err(Error::from(ValidationError::FeatureNotEnabled(name)));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn build(&self, layout: &PipelineLayout<'a>, render_pass: &RenderPass<'a>, subpass: u32)
+                 -> Result<GraphicsPipeline<'a>, Error> {
+        let color_attachment_count = match render_pass.subpasses().get(subpass as usize) {
+            Some(s) => s.color_attachments.len() as u32,
+            None => return Err(Error::from(ValidationError::InvalidSubpass(subpass))),
+        };
+        let device = layout.device();
+        self.check(device.features(), color_attachment_count)?;
+        let bool32 = |b| if b {VkBool32::True} else {VkBool32::False};
+
+        let specialization_infos = self.stages.iter()
+            .map(|s| s.specialization.map(Specialization::info))
+            .collect::<Vec<_>>();
+        let stages = self.stages.iter().zip(&specialization_infos).map(|(s, info)| VkPipelineShaderStageCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineShaderStageCreateFlags::Reserved,
+            stage: s.stage,
+            module: *s.module.handle(),
+            p_name: s.entry_point.as_ptr(),
+            p_specialization_info: info.as_ref().map_or(ptr::null(), |info| info),
+        }).collect::<Vec<_>>();
+        let vertex_input = VkPipelineVertexInputStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineVertexInputStateCreateFlags::Reserved,
+            vertex_binding_description_count: self.vertex_bindings.len() as u32,
+            p_vertex_binding_descriptions: self.vertex_bindings.as_ptr(),
+            vertex_attribute_description_count: self.vertex_attributes.len() as u32,
+            p_vertex_attribute_descriptions: self.vertex_attributes.as_ptr(),
+        };
+        let input_assembly = VkPipelineInputAssemblyStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineInputAssemblyStateCreateFlags::Reserved,
+            topology: self.topology,
+            primitive_restart_enable: bool32(self.primitive_restart),
+        };
+        let tessellation = VkPipelineTessellationStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_TESSELLATION_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineTessellationStateCreateFlags::Reserved,
+            patch_control_points: self.patch_control_points,
+        };
+
+        let dynamic_states = self.dynamic_states();
+        let (viewports, scissors): (Vec<_>, Vec<_>) = match self.viewports {
+            Viewports::Static(ref viewports) => viewports.iter().cloned().unzip(),
+            Viewports::Dynamic(_) => (Vec::new(), Vec::new()),
+        };
+        let viewport_count = self.viewport_count();
+        let viewport = VkPipelineViewportStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineViewportStateCreateFlags::Reserved,
+            viewport_count: viewport_count,
+            p_viewports: if viewports.is_empty() {ptr::null()} else {viewports.as_ptr()},
+            scissor_count: viewport_count,
+            p_scissors: if scissors.is_empty() {ptr::null()} else {scissors.as_ptr()},
+        };
+        let (depth_bias_constant, depth_bias_clamp, depth_bias_slope) = self.depth_bias.unwrap_or((0.0, 0.0, 0.0));
+        let rasterization = VkPipelineRasterizationStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineRasterizationStateCreateFlags::Reserved,
+            depth_clamp_enable: bool32(self.depth_clamp),
+            rasterizer_discard_enable: bool32(self.rasterizer_discard),
+            polygon_mode: self.polygon_mode,
+            cull_mode: self.cull_mode,
+            front_face: self.front_face,
+            depth_bias_enable: bool32(self.depth_bias.is_some()),
+            depth_bias_constant_factor: depth_bias_constant,
+            depth_bias_clamp: depth_bias_clamp,
+            depth_bias_slope_factor: depth_bias_slope,
+            line_width: self.line_width,
+        };
+        let multisample = VkPipelineMultisampleStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineMultisampleStateCreateFlags::Reserved,
+            rasterization_samples: self.samples,
+            sample_shading_enable: bool32(self.min_sample_shading.is_some()),
+            min_sample_shading: self.min_sample_shading.unwrap_or(0.0),
+            p_sample_mask: ptr::null(),
+            alpha_to_coverage_enable: bool32(self.alpha_to_coverage),
+            alpha_to_one_enable: bool32(self.alpha_to_one),
+        };
+        let keep = VkStencilOpState {
+            fail_op: VkStencilOp::VK_STENCIL_OP_KEEP,
+            pass_op: VkStencilOp::VK_STENCIL_OP_KEEP,
+            depth_fail_op: VkStencilOp::VK_STENCIL_OP_KEEP,
+            compare_op: VkCompareOp::VK_COMPARE_OP_ALWAYS,
+            compare_mask: 0,
+            write_mask: 0,
+            reference: 0,
+        };
+        let (depth_compare_op, depth_write) = self.depth_test.unwrap_or((VkCompareOp::VK_COMPARE_OP_ALWAYS, false));
+        let (min_depth_bounds, max_depth_bounds) = self.depth_bounds.unwrap_or((0.0, 1.0));
+        let (front, back) = self.stencil_test.unwrap_or((keep, keep));
+        let depth_stencil = VkPipelineDepthStencilStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineDepthStencilStateCreateFlags::Reserved,
+            depth_test_enable: bool32(self.depth_test.is_some()),
+            depth_write_enable: bool32(depth_write),
+            depth_compare_op: depth_compare_op,
+            depth_bounds_test_enable: bool32(self.depth_bounds.is_some()),
+            stencil_test_enable: bool32(self.stencil_test.is_some()),
+            front: front,
+            back: back,
+            min_depth_bounds: min_depth_bounds,
+            max_depth_bounds: max_depth_bounds,
+        };
+        let blend_attachments = match self.blend_attachments {
+            Some(ref attachments) => attachments.clone(),
+            None => vec!(ColorBlendAttachment::default(); color_attachment_count as usize),
+        };
+        let color_blend = VkPipelineColorBlendStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_COLOR_BLEND_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineColorBlendStateCreateFlags::Reserved,
+            logic_op_enable: bool32(self.logic_op.is_some()),
+            logic_op: self.logic_op.unwrap_or(VkLogicOp::VK_LOGIC_OP_COPY),
+            attachment_count: blend_attachments.len() as u32,
+            p_attachments: blend_attachments.as_ptr(),
+            blend_constants: self.blend_constants,
+        };
+        let dynamic = VkPipelineDynamicStateCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_PIPELINE_DYNAMIC_STATE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineDynamicStateCreateFlags::Reserved,
+            dynamic_state_count: dynamic_states.len() as u32,
+            p_dynamic_states: dynamic_states.as_ptr(),
+        };
+
+        let create_info = VkGraphicsPipelineCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_GRAPHICS_PIPELINE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkPipelineCreateFlags::empty(),
+            stage_count: stages.len() as u32,
+            p_stages: stages.as_ptr(),
+            p_vertex_input_state: &vertex_input,
+            p_input_assembly_state: &input_assembly,
+            p_tessellation_state: if self.patch_control_points == 0 {ptr::null()} else {&tessellation},
+            p_viewport_state: &viewport,
+            p_rasterization_state: &rasterization,
+            p_multisample_state: &multisample,
+            p_depth_stencil_state: &depth_stencil,
+            p_color_blend_state: &color_blend,
+            p_dynamic_state: if dynamic_states.is_empty() {ptr::null()} else {&dynamic},
+            layout: *layout.handle(),
+            render_pass: *render_pass.handle(),
+            subpass: subpass,
+            base_pipeline_handle: VK_NULL_HANDLE,
+            base_pipeline_index: -1,
+        };
+        let cache = self.cache.map_or(VK_NULL_HANDLE, |c| c.handle);
+        let mut pipeline = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateGraphicsPipelines)(*device.handle(), cache, 1, &create_info,
+                                                               ptr::null(), &mut pipeline)} {
+            VkResult::VK_SUCCESS => Ok(GraphicsPipeline{handle: pipeline, device: device}),
+            x => Err(Error::from(x)),
+        }
+    }
+}
+
+impl<'a, 's> Default for GraphicsPipelineBuilder<'a, 's> {
+    fn default() -> Self {
+        GraphicsPipelineBuilder::new()
+    }
+}
+
+pub struct GraphicsPipeline<'a> {
+    handle: VkPipeline,
+    device: &'a Device<'a>,
+}
+
+impl<'a> GraphicsPipeline<'a> {
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+}
+
+impl<'a> Pipeline for GraphicsPipeline<'a> {
+    const BIND_POINT: VkPipelineBindPoint = VkPipelineBindPoint::VK_PIPELINE_BIND_POINT_GRAPHICS;
+
+    fn handle(&self) -> &VkPipeline {&self.handle}
+}
+
+impl<'a> Drop for GraphicsPipeline<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyPipeline)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority, SharingMode};
+    use descriptor::{DescriptorSetLayout, DescriptorSetLayoutBinding, DescriptorPool, UnifiedDescriptorPool,
+                     DescriptorPoolSize, DescriptorSet};
+    use shader_module::ShaderModule;
+    use shader_module::test::{COMPUTE_SHADER, VERTEX_SHADER, FRAGMENT_SHADER};
+    use command_pool::{SplitCommandPool, CommandPool};
+    use command_buffer::{PrimaryCommandBuffer, CommandBuffer, RecordingCommandBuffer, OutsideRenderPass,
+                         InsideRenderPass};
+    use render_pass::{RenderPass, Subpass, Framebuffer};
+    use sys::render_pass::VkSubpassContents;
+    use sys::device::VkPhysicalDeviceFeatures;
+    use sys::common::{VkBool32, VkCompareOp};
+    use sys::instance::VkPhysicalDeviceType;
+    use instance::PhysicalDeviceProperties;
+    use sys::command_buffer::{VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT, VK_STENCIL_FRONT_AND_BACK};
+    use buffer::Buffer;
+    use memory::DeviceMemory;
+    use fence::Fence;
+    use error::{Error, ValidationError};
+    use sys::descriptor::VkDescriptorType;
+    use sys::buffer::{VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT, VK_BUFFER_USAGE_STORAGE_BUFFER_BIT};
+    use sys::memory::{VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT, VK_MEMORY_PROPERTY_HOST_COHERENT_BIT};
+    use std::collections::HashMap;
+    use std::ffi::CString;
+    use std::env;
+    use std::fs;
+    use std::mem;
+    use std::process;
+
+    use pipeline::*;
+
+    #[test]
+    fn create_pipeline_layout() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let set_layout = DescriptorSetLayout::new(&device, &[DescriptorSetLayoutBinding {
+            binding: 0,
+            descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,
+            descriptor_count: 1,
+            stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+        }]).unwrap();
+        let range = PushConstantRange{stage_flags: VK_SHADER_STAGE_COMPUTE_BIT, offset: 0, size: 16};
+        let layout = PipelineLayout::new(&device, &[&set_layout], &[range]).unwrap();
+        assert_eq!(layout.push_constant_ranges(), &[range]);
+
+        let misaligned = PushConstantRange{stage_flags: VK_SHADER_STAGE_COMPUTE_BIT, offset: 2, size: 16};
+        assert_eq!(PipelineLayout::new(&device, &[], &[misaligned]).err(),
+                   Some(Error::from(ValidationError::InvalidPushConstantRange{offset: 2, size: 16})));
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn push_constant_validation() {
+        let vertex_fragment = VK_SHADER_STAGE_VERTEX_BIT | VK_SHADER_STAGE_FRAGMENT_BIT;
+        let ranges = [PushConstantRange{stage_flags: VK_SHADER_STAGE_VERTEX_BIT, offset: 0, size: 16},
+                      PushConstantRange{stage_flags: vertex_fragment, offset: 16, size: 16}];
+        let invalid = |offset, size| Err(Error::from(ValidationError::InvalidPushConstantRange{
+            offset: offset, size: size
+        }));
+        assert_eq!(check_push_constants(&ranges, VK_SHADER_STAGE_VERTEX_BIT, 0, 16), Ok(()));
+        assert_eq!(check_push_constants(&ranges, vertex_fragment, 16, 16), Ok(()));
+        assert_eq!(check_push_constants(&ranges, VK_SHADER_STAGE_VERTEX_BIT, 4, 8), Ok(()));
+        // Not declared for the stage, or past the end of the ranges
+        assert_eq!(check_push_constants(&ranges, VK_SHADER_STAGE_FRAGMENT_BIT, 0, 16), invalid(0, 16));
+        assert_eq!(check_push_constants(&ranges, vertex_fragment, 16, 20), invalid(16, 20));
+        // The second range is declared for both stages, so they're updated together
+        assert_eq!(check_push_constants(&ranges, VK_SHADER_STAGE_VERTEX_BIT, 16, 16), invalid(16, 16));
+        assert_eq!(check_push_constants(&ranges, VK_SHADER_STAGE_VERTEX_BIT, 0, 32), invalid(0, 32));
+        assert_eq!(check_push_constants(&ranges, VK_SHADER_STAGE_VERTEX_BIT, 2, 8), invalid(2, 8));
+        assert_eq!(check_push_constants(&ranges, VK_SHADER_STAGE_VERTEX_BIT, 0, 0), invalid(0, 0));
+    }
+
+    #[test]
+    fn dispatch() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let set_layout = DescriptorSetLayout::new(&device, &[DescriptorSetLayoutBinding {
+            binding: 0,
+            descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,
+            descriptor_count: 1,
+            stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+        }]).unwrap();
+        let push_range = PushConstantRange{stage_flags: VK_SHADER_STAGE_COMPUTE_BIT, offset: 0, size: 16};
+        let layout = PipelineLayout::new(&device, &[&set_layout], &[push_range]).unwrap();
+        let module = ShaderModule::new(&device, &COMPUTE_SHADER).unwrap();
+        let entry_point = CString::new("main").unwrap();
+        let specialization = Specialization::new().constant(0, &[0; 4]);
+        let pipeline = ComputePipeline::new(&device, &layout, &module, &entry_point, Some(&specialization),
+                                            None).unwrap();
+
+        // VkDispatchIndirectCommand of 1x1x1 groups
+        let mut memory = DeviceMemory::allocate(&device, 256, !0, VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT,
+                                                VK_MEMORY_PROPERTY_HOST_COHERENT_BIT).unwrap();
+        memory.map(0, 12).unwrap().copy_from_slice(&[1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+        let mut indirect = Buffer::new(&device, 12, VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT | VK_BUFFER_USAGE_STORAGE_BUFFER_BIT,
+                                       &SharingMode::Exclusive).unwrap();
+        indirect.bind_memory(&memory, 0).unwrap();
+
+        let pool_size = DescriptorPoolSize{descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_STORAGE_BUFFER,
+                                           descriptor_count: 1};
+        let descriptor_pool = UnifiedDescriptorPool::new(&device, 1, &[pool_size]).unwrap();
+        let mut set = DescriptorSet::allocate(&descriptor_pool, &[&set_layout]).unwrap().pop().unwrap();
+        set.write_buffer(0, 0, &indirect, 0, 12).unwrap();
+
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+        let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+        let mut cmd_buf = cmd_buf.begin(VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap();
+        cmd_buf.bind_pipeline(&pipeline);
+        cmd_buf.bind_descriptor_sets(ComputePipeline::BIND_POINT, &layout, 0, &[&set], &[]);
+        cmd_buf.push_constants(&layout, VK_SHADER_STAGE_COMPUTE_BIT, 0, &[0; 16]).unwrap();
+        assert_eq!(cmd_buf.push_constants(&layout, VK_SHADER_STAGE_COMPUTE_BIT, 8, &[0; 16]),
+                   Err(Error::from(ValidationError::InvalidPushConstantRange{offset: 8, size: 16})));
+        cmd_buf.dispatch(1, 1, 1);
+        cmd_buf.dispatch_indirect(&indirect, 0);
+        let cmd_buf = cmd_buf.end().unwrap();
+        queue.submit(&[&cmd_buf], &[], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn graphics_pipeline_features() {
+        let features = VkPhysicalDeviceFeatures::default();
+        assert_eq!(GraphicsPipelineBuilder::new().check(&features, 1), Ok(()));
+        assert_eq!(GraphicsPipelineBuilder::new().polygon_mode(VkPolygonMode::VK_POLYGON_MODE_LINE).check(&features, 1),
+                   Err(Error::from(ValidationError::FeatureNotEnabled("fill_mode_non_solid"))));
+        assert_eq!(GraphicsPipelineBuilder::new().line_width(2.0).check(&features, 1),
+                   Err(Error::from(ValidationError::FeatureNotEnabled("wide_lines"))));
+        assert_eq!(GraphicsPipelineBuilder::new().line_width(2.0)
+                   .dynamic_state(VkDynamicState::VK_DYNAMIC_STATE_LINE_WIDTH).check(&features, 1), Ok(()));
+        assert_eq!(GraphicsPipelineBuilder::new().logic_op(VkLogicOp::VK_LOGIC_OP_XOR).check(&features, 1),
+                   Err(Error::from(ValidationError::FeatureNotEnabled("logic_op"))));
+        assert_eq!(GraphicsPipelineBuilder::new().dynamic_viewports(2).check(&features, 1),
+                   Err(Error::from(ValidationError::FeatureNotEnabled("multi_viewport"))));
+
+        let opaque = ColorBlendAttachment::default();
+        let blended = ColorBlendAttachment{blend_enable: VkBool32::True, ..opaque};
+        assert_eq!(GraphicsPipelineBuilder::new().blend_attachments(&[opaque, opaque]).check(&features, 2), Ok(()));
+        assert_eq!(GraphicsPipelineBuilder::new().blend_attachments(&[opaque, blended]).check(&features, 2),
+                   Err(Error::from(ValidationError::FeatureNotEnabled("independent_blend"))));
+        assert_eq!(GraphicsPipelineBuilder::new().blend_attachments(&[opaque]).check(&features, 2),
+                   Err(Error::from(ValidationError::AttachmentCountMismatch{expected: 2, actual: 1})));
+
+        let features = VkPhysicalDeviceFeatures{logic_op: VkBool32::True, ..Default::default()};
+        assert_eq!(GraphicsPipelineBuilder::new().logic_op(VkLogicOp::VK_LOGIC_OP_XOR).check(&features, 1), Ok(()));
+    }
+
+    #[test]
+    fn graphics_pipeline_dynamic_states() {
+        let viewport = VkDynamicState::VK_DYNAMIC_STATE_VIEWPORT;
+        let scissor = VkDynamicState::VK_DYNAMIC_STATE_SCISSOR;
+        assert_eq!(GraphicsPipelineBuilder::new().dynamic_states(), vec!(viewport, scissor));
+        assert_eq!(GraphicsPipelineBuilder::new().dynamic_state(scissor).dynamic_viewports(1).dynamic_states(),
+                   vec!(scissor, viewport));
+        assert_eq!(GraphicsPipelineBuilder::new().dynamic_state(viewport).dynamic_state(scissor).dynamic_viewports(1)
+                   .dynamic_states(), vec!(viewport, scissor));
+    }
+
+    #[test]
+    fn draw() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let layout = PipelineLayout::new(&device, &[], &[]).unwrap();
+        let render_pass = RenderPass::new(&device, &[], &[Subpass::default()], &[]).unwrap();
+        let framebuffer = Framebuffer::new(&render_pass, &[], 64, 64, 1).unwrap();
+        let vertex_shader = ShaderModule::new(&device, &VERTEX_SHADER).unwrap();
+        let fragment_shader = ShaderModule::new(&device, &FRAGMENT_SHADER).unwrap();
+        let entry_point = CString::new("main").unwrap();
+        let pipeline = GraphicsPipelineBuilder::new()
+            .stage(VK_SHADER_STAGE_VERTEX_BIT, &vertex_shader, &entry_point, None)
+            .stage(VK_SHADER_STAGE_FRAGMENT_BIT, &fragment_shader, &entry_point, None)
+            .depth_test(VkCompareOp::VK_COMPARE_OP_LESS, true)
+            .dynamic_state(VkDynamicState::VK_DYNAMIC_STATE_LINE_WIDTH)
+            .dynamic_state(VkDynamicState::VK_DYNAMIC_STATE_DEPTH_BIAS)
+            .dynamic_state(VkDynamicState::VK_DYNAMIC_STATE_BLEND_CONSTANTS)
+            .dynamic_state(VkDynamicState::VK_DYNAMIC_STATE_STENCIL_COMPARE_MASK)
+            .dynamic_state(VkDynamicState::VK_DYNAMIC_STATE_STENCIL_WRITE_MASK)
+            .dynamic_state(VkDynamicState::VK_DYNAMIC_STATE_STENCIL_REFERENCE)
+            .build(&layout, &render_pass, 0).unwrap();
+        match GraphicsPipelineBuilder::new().build(&layout, &render_pass, 1) {
+            Err(Error::Validation(ValidationError::InvalidSubpass(1))) => {},
+            _ => panic!("Expected an invalid subpass error"),
+        }
+
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+        let mut cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+            .begin(VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap()
+            .begin_render_pass(&framebuffer, framebuffer.area(), &[], VkSubpassContents::VK_SUBPASS_CONTENTS_INLINE)
+            .unwrap();
+        cmd_buf.bind_pipeline(&pipeline);
+        cmd_buf.set_viewports(0, &[Viewport{x: 0.0, y: 0.0, width: 64.0, height: 64.0, min_depth: 0.0, max_depth: 1.0}]);
+        cmd_buf.set_scissors(0, &[framebuffer.area()]);
+        cmd_buf.set_line_width(1.0).unwrap();
+        assert_eq!(cmd_buf.set_line_width(2.0), Err(Error::from(ValidationError::FeatureNotEnabled("wide_lines"))));
+        cmd_buf.set_depth_bias(1.0, 0.0, 1.0).unwrap();
+        cmd_buf.set_blend_constants([0.0; 4]);
+        cmd_buf.set_stencil_compare_mask(VK_STENCIL_FRONT_AND_BACK, !0);
+        cmd_buf.set_stencil_write_mask(VK_STENCIL_FRONT_AND_BACK, !0);
+        cmd_buf.set_stencil_reference(VK_STENCIL_FRONT_AND_BACK, 0);
+        cmd_buf.draw(3, 1, 0, 0);
+        let cmd_buf = cmd_buf.end_render_pass().unwrap().end().unwrap();
+        queue.submit(&[&cmd_buf], &[], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn pipeline_cache_header() {
+        let properties = PhysicalDeviceProperties {
+            api_version: 0,
+            driver_version: 0,
+            vendor_id: 0x1002,
+            device_id: 0x67df,
+            device_type: VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU,
+            device_name: String::new(),
+            pipeline_cache_uuid: [7; 16],
+            limits: unsafe {mem::zeroed()},
+            sparse_properties: unsafe {mem::zeroed()},
+        };
+        let mut data = vec!(32, 0, 0, 0, 1, 0, 0, 0, 0x02, 0x10, 0, 0, 0xdf, 0x67, 0, 0);
+        data.extend_from_slice(&[7; 16]);
+        data.extend_from_slice(&[0xff; 64]);
+        assert!(cache_header_matches(&data, &properties));
+        assert!(!cache_header_matches(&data[..20], &properties));
+
+        let mut stale = data.clone();
+        stale[31] = 8;
+        assert!(!cache_header_matches(&stale, &properties));
+        let mut other_device = data.clone();
+        other_device[12] = 0xe0;
+        assert!(!cache_header_matches(&other_device, &properties));
+        let mut bad_length = data.clone();
+        bad_length[0] = 0xff;
+        assert!(!cache_header_matches(&bad_length, &properties));
+    }
+
+    #[test]
+    fn pipeline_cache() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let layout = PipelineLayout::new(&device, &[], &[]).unwrap();
+        let module = ShaderModule::new(&device, &COMPUTE_SHADER).unwrap();
+        let entry_point = CString::new("main").unwrap();
+        let mut cache = PipelineCache::new(&device, &[]).unwrap();
+        let other = PipelineCache::new(&device, &[]).unwrap();
+        ComputePipeline::new(&device, &layout, &module, &entry_point, None, Some(&other)).unwrap();
+        cache.merge(&[&other]).unwrap();
+        let data = cache.data().unwrap();
+        assert!(cache_header_matches(&data, device.properties()));
+
+        let path = env::temp_dir().join(format!("vkrs-pipeline-cache-test-{}.bin", process::id()));
+        let sibling = path.with_extension("tmp");
+        fs::write(&sibling, b"untouched").unwrap();
+        cache.save(&path).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), data);
+        assert_eq!(fs::read(&sibling).unwrap(), b"untouched");
+        fs::remove_file(&sibling).unwrap();
+        let loaded = PipelineCache::load(&device, &path).unwrap();
+        ComputePipeline::new(&device, &layout, &module, &entry_point, None, Some(&loaded)).unwrap();
+
+        // Stale and missing files give empty caches
+        fs::write(&path, &[0; 64]).unwrap();
+        PipelineCache::load(&device, &path).unwrap();
+        fs::remove_file(&path).unwrap();
+        PipelineCache::load(&device, &path).unwrap();
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
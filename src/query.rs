@@ -0,0 +1,216 @@
+extern crate libc;
+use self::libc::c_void;
+use sys::query::*;
+use sys::common::{VkBool32, VkResult, VkStructureType, VK_NULL_HANDLE};
+use device::Device;
+use command_buffer::QueryPipelineStatisticFlags;
+use error::{Error, ValidationError};
+use std::mem;
+use std::ptr;
+
+pub type QueryType = VkQueryType;
+pub type QueryResultFlags = VkQueryResultFlags;
+
+// The width of the values read back by `QueryPool::results`
+pub trait QueryResult: Copy + Default {
+    fn flags() -> QueryResultFlags;
+}
+
+impl QueryResult for u32 {
+    fn flags() -> QueryResultFlags {QueryResultFlags::empty()}
+}
+
+impl QueryResult for u64 {
+    fn flags() -> QueryResultFlags {VK_QUERY_RESULT_64_BIT}
+}
+
+// Drops the bits a queue family doesn't write, then scales ticks of `period`
+// nanoseconds
+fn timestamp_ns(ticks: u64, valid_bits: u32, period: f32) -> u64 {
+    let ticks = if valid_bits < 64 {ticks & ((1 << valid_bits) - 1)} else {ticks};
+    (ticks as f64 * period as f64) as u64
+}
+
+pub struct QueryPool<'a> {
+    handle: VkQueryPool,
+    device: &'a Device<'a>,
+    query_type: QueryType,
+    query_count: u32,
+    pipeline_statistics: QueryPipelineStatisticFlags,
+}
+
+impl<'a> QueryPool<'a> {
+    // `pipeline_statistics` selects the counters of each query in a
+    // VK_QUERY_TYPE_PIPELINE_STATISTICS pool, and is ignored otherwise
+    pub fn new(device: &'a Device, query_type: QueryType, query_count: u32,
+               pipeline_statistics: QueryPipelineStatisticFlags) -> Result<Self, Error> {
+        let pipeline_statistics = match query_type {
+            VkQueryType::VK_QUERY_TYPE_PIPELINE_STATISTICS => {
+                if device.features().pipeline_statistics_query != VkBool32::True {
+                    return Err(Error::from(ValidationError::FeatureNotEnabled("pipeline_statistics_query")));
+                }
+                pipeline_statistics
+            }
+            _ => QueryPipelineStatisticFlags::empty(),
+        };
+        let create_info = VkQueryPoolCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_QUERY_POOL_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkQueryPoolCreateFlags::Reserved,
+            query_type: query_type,
+            query_count: query_count,
+            pipeline_statistics: pipeline_statistics,
+        };
+        let mut pool = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateQueryPool)(*device.handle(), &create_info, ptr::null(), &mut pool)} {
+            VkResult::VK_SUCCESS => Ok(QueryPool{handle: pool, device: device, query_type: query_type,
+                                                 query_count: query_count, pipeline_statistics: pipeline_statistics}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    // One for each enabled counter of a pipeline statistics query
+    pub fn values_per_query(&self) -> usize {
+        match self.query_type {
+            VkQueryType::VK_QUERY_TYPE_PIPELINE_STATISTICS => self.pipeline_statistics.bits().count_ones() as usize,
+            _ => 1,
+        }
+    }
+
+    fn check_range(&self, first_query: u32, query_count: u32) -> Result<(), Error> {
+        if first_query as u64 + query_count as u64 > self.query_count as u64 {
+            return Err(Error::from(ValidationError::QueryOutOfRange{
+                first: first_query, count: query_count, query_count: self.query_count
+            }));
+        }
+        Ok(())
+    }
+
+    // The `values_per_query()` values of each query, followed by its
+    // availability with VK_QUERY_RESULT_WITH_AVAILABILITY_BIT. VK_QUERY_RESULT_64_BIT
+    // is set by `T`. None if some queries aren't available yet, unless waiting
+    // or asking for availability or partial results.
+    pub fn results<T: QueryResult>(&self, first_query: u32, query_count: u32, flags: QueryResultFlags)
+                                   -> Result<Option<Vec<T>>, Error> {
+        self.check_range(first_query, query_count)?;
+        let flags = (flags - VK_QUERY_RESULT_64_BIT) | T::flags();
+        let values = self.values_per_query() + if flags.contains(VK_QUERY_RESULT_WITH_AVAILABILITY_BIT) {1} else {0};
+        let mut data = vec![T::default(); values * query_count as usize];
+        let stride = values * mem::size_of::<T>();
+        match unsafe {
+            (self.device.fns().vkGetQueryPoolResults)(*self.device.handle(), self.handle, first_query, query_count,
+                                                      data.len() * mem::size_of::<T>(),
+                                                      data.as_mut_ptr() as *mut c_void, stride as u64, flags)
+        } {
+            VkResult::VK_SUCCESS => Ok(Some(data)),
+            VkResult::VK_NOT_READY if flags.intersects(VK_QUERY_RESULT_WITH_AVAILABILITY_BIT |
+                                                       VK_QUERY_RESULT_PARTIAL_BIT) => Ok(Some(data)),
+            VkResult::VK_NOT_READY => Ok(None),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    // Timestamps in nanoseconds, written by a queue of `queue_family`. Only
+    // differences between them are meaningful.
+    pub fn timestamps(&self, first_query: u32, query_count: u32, queue_family: u32, wait: bool)
+                      -> Result<Option<Vec<u64>>, Error> {
+        if self.query_type != VkQueryType::VK_QUERY_TYPE_TIMESTAMP {
+            return Err(Error::from(ValidationError::QueryTypeMismatch(self.query_type)));
+        }
+        let valid_bits = match self.device.queue_family_properties().get(queue_family as usize) {
+            Some(properties) => properties.timestamp_valid_bits,
+            None => return Err(Error::from(ValidationError::InvalidQueueFamily(queue_family))),
+        };
+        if valid_bits == 0 {
+            return Err(Error::from(ValidationError::TimestampsNotSupported(queue_family)));
+        }
+        let flags = if wait {VK_QUERY_RESULT_WAIT_BIT} else {QueryResultFlags::empty()};
+        let period = self.device.limits().timestamp_period;
+        Ok(self.results::<u64>(first_query, query_count, flags)?
+            .map(|ticks| ticks.into_iter().map(|t| timestamp_ns(t, valid_bits, period)).collect()))
+    }
+
+    pub fn handle(&self) -> &VkQueryPool {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn query_type(&self) -> QueryType {self.query_type}
+    pub fn query_count(&self) -> u32 {self.query_count}
+}
+
+impl<'a> Drop for QueryPool<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyQueryPool)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority};
+    use command_pool::{SplitCommandPool, CommandPool};
+    use command_buffer::{CommandBuffer, PrimaryCommandBuffer, RecordingCommandBuffer, OutsideRenderPass,
+                         QueryPipelineStatisticFlags};
+    use sys::command_buffer::VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT;
+    use sys::common::{VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT, VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT};
+    use fence::Fence;
+    use error::{Error, ValidationError};
+    use std::collections::HashMap;
+
+    use query::*;
+
+    #[test]
+    fn timestamp_conversion() {
+        assert_eq!(timestamp_ns(1000, 64, 1.0), 1000);
+        assert_eq!(timestamp_ns(1000, 64, 2.5), 2500);
+        // Bits above timestamp_valid_bits are undefined
+        assert_eq!(timestamp_ns(0xffff_0000_0000_0010, 36, 1.0), 0x10);
+        assert_eq!(timestamp_ns(!0, 32, 1.0), 0xffff_ffff);
+    }
+
+    #[test]
+    fn query_pool() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let pool = QueryPool::new(&device, VkQueryType::VK_QUERY_TYPE_TIMESTAMP, 2,
+                                  QueryPipelineStatisticFlags::empty()).unwrap();
+        assert_eq!(pool.values_per_query(), 1);
+        assert_eq!(pool.results::<u64>(1, 2, QueryResultFlags::empty()).err(),
+                   Some(Error::from(ValidationError::QueryOutOfRange{first: 1, count: 2, query_count: 2})));
+        assert_eq!(QueryPool::new(&device, VkQueryType::VK_QUERY_TYPE_PIPELINE_STATISTICS, 1,
+                                  QueryPipelineStatisticFlags::all()).err(),
+                   Some(Error::from(ValidationError::FeatureNotEnabled("pipeline_statistics_query"))));
+        let occlusion = QueryPool::new(&device, VkQueryType::VK_QUERY_TYPE_OCCLUSION, 1,
+                                       QueryPipelineStatisticFlags::empty()).unwrap();
+        assert_eq!(occlusion.timestamps(0, 1, 0, true).err(),
+                   Some(Error::from(ValidationError::QueryTypeMismatch(VkQueryType::VK_QUERY_TYPE_OCCLUSION))));
+
+        if device.queue_family_properties()[0].timestamp_valid_bits > 0 {
+            let mut queue = device.get_queue(0, 0).unwrap();
+            let mut fence = Fence::new(&device, false).unwrap();
+            let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+            let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+            let mut cmd_buf = cmd_buf.begin(VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT).unwrap();
+            cmd_buf.reset_query_pool(&pool, 0, 2);
+            cmd_buf.write_timestamp(VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT, &pool, 0);
+            cmd_buf.write_timestamp(VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT, &pool, 1);
+            let cmd_buf = cmd_buf.end().unwrap();
+            queue.submit(&[&cmd_buf], &[], &[], Some(&mut fence)).unwrap();
+            assert!(fence.wait(1_000_000_000).unwrap());
+
+            let timestamps = pool.timestamps(0, 2, 0, false).unwrap().unwrap();
+            assert!(timestamps[0] <= timestamps[1]);
+            let available = pool.results::<u32>(0, 2, VK_QUERY_RESULT_WITH_AVAILABILITY_BIT).unwrap().unwrap();
+            assert_eq!(available.len(), 4);
+            assert!(available[1] != 0 && available[3] != 0);
+        }
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
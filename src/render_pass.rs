@@ -0,0 +1,418 @@
+use sys::render_pass::*;
+use sys::common::{VkResult, VkStructureType, VkRect2D, VkOffset2D, VkExtent2D, VK_NULL_HANDLE};
+use sys::pipeline::VkPipelineBindPoint;
+use device::Device;
+use image::ImageView;
+use error::{Error, ValidationError};
+use std::ptr;
+
+pub type AttachmentDescription = VkAttachmentDescription;
+pub type AttachmentReference = VkAttachmentReference;
+pub type SubpassDependency = VkSubpassDependency;
+pub type ClearValue = VkClearValue;
+
+pub fn clear_color(rgba: [f32; 4]) -> ClearValue {
+    VkClearValue{color: VkClearColorValue{float32: rgba}}
+}
+
+pub fn clear_depth_stencil(depth: f32, stencil: u32) -> ClearValue {
+    VkClearValue{depth_stencil: VkClearDepthStencilValue{depth: depth, stencil: stencil}}
+}
+
+// A graphics subpass. Attachments are referenced by their index in the render
+// pass, or VK_ATTACHMENT_UNUSED. `resolve_attachments` is either empty or has
+// one entry for each color attachment.
+#[derive(Debug, Clone, Default)]
+pub struct Subpass {
+    pub input_attachments: Vec<AttachmentReference>,
+    pub color_attachments: Vec<AttachmentReference>,
+    pub resolve_attachments: Vec<AttachmentReference>,
+    pub depth_stencil_attachment: Option<AttachmentReference>,
+    pub preserve_attachments: Vec<u32>,
+}
+
+fn check_render_pass(attachment_count: u32, subpasses: &[Subpass], dependencies: &[SubpassDependency])
+                     -> Result<(), Error> {
+    for subpass in subpasses {
+        let references = subpass.input_attachments.iter()
+            .chain(&subpass.color_attachments)
+            .chain(&subpass.resolve_attachments)
+            .chain(&subpass.depth_stencil_attachment)
+            .map(|r| r.attachment)
+            .chain(subpass.preserve_attachments.iter().cloned());
+        for attachment in references {
+            if attachment != VK_ATTACHMENT_UNUSED && attachment >= attachment_count {
+                return Err(Error::from(ValidationError::InvalidAttachmentReference(attachment)));
+            }
+        }
+        if !subpass.resolve_attachments.is_empty() &&
+            subpass.resolve_attachments.len() != subpass.color_attachments.len() {
+            return Err(Error::from(ValidationError::ResolveAttachmentCount{
+                color: subpass.color_attachments.len() as u32, resolve: subpass.resolve_attachments.len() as u32
+            }));
+        }
+    }
+    // Dependencies must go forwards, and at most one side can be external
+    let subpass_count = subpasses.len() as u32;
+    for dependency in dependencies {
+        let (src, dst) = (dependency.src_subpass, dependency.dst_subpass);
+        let valid = match (src, dst) {
+            (VK_SUBPASS_EXTERNAL, VK_SUBPASS_EXTERNAL) => false,
+            (VK_SUBPASS_EXTERNAL, dst) => dst < subpass_count,
+            (src, VK_SUBPASS_EXTERNAL) => src < subpass_count,
+            (src, dst) => src <= dst && dst < subpass_count,
+        };
+        if !valid {
+            return Err(Error::from(ValidationError::InvalidSubpassDependency{src_subpass: src, dst_subpass: dst}));
+        }
+    }
+    Ok(())
+}
+
+pub struct RenderPass<'a> {
+    handle: VkRenderPass,
+    device: &'a Device<'a>,
+    attachments: Vec<AttachmentDescription>,
+    subpasses: Vec<Subpass>,
+}
+
+impl<'a> RenderPass<'a> {
+    pub fn new(device: &'a Device, attachments: &[AttachmentDescription], subpasses: &[Subpass],
+               dependencies: &[SubpassDependency]) -> Result<Self, Error> {
+        check_render_pass(attachments.len() as u32, subpasses, dependencies)?;
+        let descriptions = subpasses.iter().map(|subpass| VkSubpassDescription {
+            flags: VkSubpassDescriptionFlags::Reserved,
+            pipeline_bind_point: VkPipelineBindPoint::VK_PIPELINE_BIND_POINT_GRAPHICS,
+            input_attachment_count: subpass.input_attachments.len() as u32,
+            p_input_attachments: subpass.input_attachments.as_ptr(),
+            color_attachment_count: subpass.color_attachments.len() as u32,
+            p_color_attachments: subpass.color_attachments.as_ptr(),
+            p_resolve_attachments: if subpass.resolve_attachments.is_empty() {
+                ptr::null()
+            } else {
+                subpass.resolve_attachments.as_ptr()
+            },
+            p_depth_stencil_attachment: subpass.depth_stencil_attachment.as_ref().map_or(ptr::null(), |r| r),
+            preserve_attachment_count: subpass.preserve_attachments.len() as u32,
+            p_preserve_attachments: subpass.preserve_attachments.as_ptr(),
+        }).collect::<Vec<_>>();
+        let create_info = VkRenderPassCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_RENDER_PASS_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkRenderPassCreateFlags::Reserved,
+            attachment_count: attachments.len() as u32,
+            p_attachments: attachments.as_ptr(),
+            subpass_count: descriptions.len() as u32,
+            p_subpasses: descriptions.as_ptr(),
+            dependency_count: dependencies.len() as u32,
+            p_dependencies: dependencies.as_ptr(),
+        };
+        let mut render_pass = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateRenderPass)(*device.handle(), &create_info, ptr::null(), &mut render_pass)} {
+            VkResult::VK_SUCCESS => Ok(RenderPass{handle: render_pass, device: device,
+                                                  attachments: attachments.to_vec(),
+                                                  subpasses: subpasses.to_vec()}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    pub fn handle(&self) -> &VkRenderPass {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn attachments(&self) -> &[AttachmentDescription] {&self.attachments}
+    pub fn subpasses(&self) -> &[Subpass] {&self.subpasses}
+    pub fn subpass_count(&self) -> u32 {self.subpasses.len() as u32}
+}
+
+impl<'a> Drop for RenderPass<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyRenderPass)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+// Borrows the render pass and image views, which must outlive it
+pub struct Framebuffer<'a: 'r, 'r> {
+    handle: VkFramebuffer,
+    device: &'a Device<'a>,
+    render_pass: &'r RenderPass<'a>,
+    extent: VkExtent2D,
+    layers: u32,
+}
+
+impl<'a, 'r> Framebuffer<'a, 'r> {
+    // One view for each of the render pass's attachments, in order, each at
+    // least as large as the framebuffer
+    pub fn new<'i: 'r>(render_pass: &'r RenderPass<'a>, attachments: &[&'r ImageView<'a, 'i>],
+                       width: u32, height: u32, layers: u32) -> Result<Self, Error> {
+        if attachments.len() != render_pass.attachments.len() {
+            return Err(Error::from(ValidationError::AttachmentCountMismatch{
+                expected: render_pass.attachments.len() as u32, actual: attachments.len() as u32
+            }));
+        }
+        let device = render_pass.device;
+        let limits = device.limits();
+        let too_small = |view: &ImageView| {
+            let extent = view.extent();
+            extent.width < width || extent.height < height || view.layer_count() < layers
+        };
+        if width == 0 || height == 0 || layers == 0 || width > limits.max_framebuffer_width ||
+            height > limits.max_framebuffer_height || layers > limits.max_framebuffer_layers ||
+            attachments.iter().any(|view| too_small(view)) {
+            return Err(Error::from(ValidationError::InvalidFramebufferExtent{
+                width: width, height: height, layers: layers
+            }));
+        }
+
+        let views = attachments.iter().map(|v| *v.handle()).collect::<Vec<_>>();
+        let create_info = VkFramebufferCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_FRAMEBUFFER_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkFramebufferCreateFlags::Reserved,
+            render_pass: render_pass.handle,
+            attachment_count: views.len() as u32,
+            p_attachments: views.as_ptr(),
+            width: width,
+            height: height,
+            layers: layers,
+        };
+        let mut framebuffer = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateFramebuffer)(*device.handle(), &create_info, ptr::null(), &mut framebuffer)} {
+            VkResult::VK_SUCCESS => Ok(Framebuffer{handle: framebuffer, device: device, render_pass: render_pass,
+                                                   extent: VkExtent2D{width: width, height: height},
+                                                   layers: layers}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    // The whole framebuffer, the usual render area
+    pub fn area(&self) -> VkRect2D {
+        VkRect2D{offset: VkOffset2D{x: 0, y: 0}, extent: self.extent}
+    }
+
+    pub fn handle(&self) -> &VkFramebuffer {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    pub fn render_pass(&self) -> &'r RenderPass<'a> {self.render_pass}
+    pub fn extent(&self) -> VkExtent2D {self.extent}
+    pub fn layers(&self) -> u32 {self.layers}
+}
+
+impl<'a, 'r> Drop for Framebuffer<'a, 'r> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyFramebuffer)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority};
+    use memory::{DeviceMemory, MemoryPropertyFlags};
+    use image::{ImageBuilder, ImageView, ComponentMapping};
+    use command_pool::{SplitCommandPool, CommandPool};
+    use command_buffer::{PrimaryCommandBuffer, SecondaryCommandBuffer, CommandBuffer, CommandBufferUsageFlags,
+                         Inheritance};
+    use fence::Fence;
+    use error::{Error, ValidationError};
+    use sys::common::{VkExtent3D, VkRect2D, VkOffset2D, VK_SAMPLE_COUNT_1_BIT, VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT,
+                      VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT, VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT,
+                      VK_ACCESS_INPUT_ATTACHMENT_READ_BIT, VK_DEPENDENCY_BY_REGION_BIT};
+    use sys::image::*;
+    use std::collections::HashMap;
+
+    use render_pass::*;
+
+    fn color_attachment() -> AttachmentDescription {
+        VkAttachmentDescription {
+            flags: VkAttachmentDescriptionFlags::empty(),
+            format: VkFormat::VK_FORMAT_R8G8B8A8_UNORM,
+            samples: VK_SAMPLE_COUNT_1_BIT,
+            load_op: VkAttachmentLoadOp::VK_ATTACHMENT_LOAD_OP_CLEAR,
+            store_op: VkAttachmentStoreOp::VK_ATTACHMENT_STORE_OP_STORE,
+            stencil_load_op: VkAttachmentLoadOp::VK_ATTACHMENT_LOAD_OP_DONT_CARE,
+            stencil_store_op: VkAttachmentStoreOp::VK_ATTACHMENT_STORE_OP_DONT_CARE,
+            initial_layout: VkImageLayout::VK_IMAGE_LAYOUT_UNDEFINED,
+            final_layout: VkImageLayout::VK_IMAGE_LAYOUT_GENERAL,
+        }
+    }
+
+    // Writes attachment 0 in the first subpass, and reads it as an input
+    // attachment in the second
+    fn subpasses() -> (Vec<Subpass>, Vec<SubpassDependency>) {
+        let subpasses = vec!(
+            Subpass {
+                color_attachments: vec!(AttachmentReference{
+                    attachment: 0, layout: VkImageLayout::VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL
+                }),
+                ..Subpass::default()
+            },
+            Subpass {
+                input_attachments: vec!(AttachmentReference{
+                    attachment: 0, layout: VkImageLayout::VK_IMAGE_LAYOUT_GENERAL
+                }),
+                ..Subpass::default()
+            },
+        );
+        let dependencies = vec!(VkSubpassDependency {
+            src_subpass: 0,
+            dst_subpass: 1,
+            src_stage_mask: VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT,
+            dst_stage_mask: VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT,
+            src_access_mask: VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT,
+            dst_access_mask: VK_ACCESS_INPUT_ATTACHMENT_READ_BIT,
+            dependency_flags: VK_DEPENDENCY_BY_REGION_BIT,
+        });
+        (subpasses, dependencies)
+    }
+
+    #[test]
+    fn validate_render_pass() {
+        let (mut subpasses, mut dependencies) = subpasses();
+        assert_eq!(check_render_pass(1, &subpasses, &dependencies), Ok(()));
+        assert_eq!(check_render_pass(0, &subpasses, &dependencies),
+                   Err(Error::from(ValidationError::InvalidAttachmentReference(0))));
+
+        dependencies[0].dst_subpass = VK_SUBPASS_EXTERNAL;
+        assert_eq!(check_render_pass(1, &subpasses, &dependencies), Ok(()));
+        dependencies[0].src_subpass = 1;
+        dependencies[0].dst_subpass = 0;
+        assert_eq!(check_render_pass(1, &subpasses, &dependencies),
+                   Err(Error::from(ValidationError::InvalidSubpassDependency{src_subpass: 1, dst_subpass: 0})));
+        dependencies[0].src_subpass = VK_SUBPASS_EXTERNAL;
+        dependencies[0].dst_subpass = 2;
+        assert!(check_render_pass(1, &subpasses, &dependencies).is_err());
+
+        let reference = subpasses[0].color_attachments[0];
+        subpasses[1].resolve_attachments.push(reference);
+        assert_eq!(check_render_pass(1, &subpasses, &[]),
+                   Err(Error::from(ValidationError::ResolveAttachmentCount{color: 0, resolve: 1})));
+    }
+
+    #[test]
+    fn create_framebuffer() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let format = VkFormat::VK_FORMAT_R8G8B8A8_UNORM;
+        let memory;
+        let mut image = ImageBuilder::new(VkImageType::VK_IMAGE_TYPE_2D, format, VkExtent3D{width: 64, height: 64, depth: 1})
+            .usage(VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT | VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT)
+            .build(&device).unwrap();
+        let requirements = image.memory_requirements();
+        memory = DeviceMemory::allocate(&device, requirements.size, requirements.memory_type_bits,
+                                        MemoryPropertyFlags::empty(), MemoryPropertyFlags::empty()).unwrap();
+        image.bind_memory(&memory, 0).unwrap();
+        let range = image.full_range(VK_IMAGE_ASPECT_COLOR_BIT);
+        let view = ImageView::new(&image, VkImageViewType::VK_IMAGE_VIEW_TYPE_2D, format,
+                                  ComponentMapping::default(), range).unwrap();
+
+        let (subpasses, dependencies) = subpasses();
+        let render_pass = RenderPass::new(&device, &[color_attachment()], &subpasses, &dependencies).unwrap();
+        assert_eq!(render_pass.subpass_count(), 2);
+        let framebuffer = Framebuffer::new(&render_pass, &[&view], 32, 64, 1).unwrap();
+        assert_eq!(framebuffer.area().extent.width, 32);
+
+        match Framebuffer::new(&render_pass, &[], 64, 64, 1) {
+            Err(Error::Validation(ValidationError::AttachmentCountMismatch{expected: 1, actual: 0})) => {},
+            _ => panic!("Expected an attachment count error"),
+        }
+        match Framebuffer::new(&render_pass, &[&view], 128, 64, 1) {
+            Err(Error::Validation(ValidationError::InvalidFramebufferExtent{width: 128, height: 64, layers: 1})) => {},
+            _ => panic!("Expected an extent error"),
+        }
+        match Framebuffer::new(&render_pass, &[&view], 64, 64, 2) {
+            Err(Error::Validation(ValidationError::InvalidFramebufferExtent{..})) => {},
+            _ => panic!("Expected an extent error"),
+        }
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn record_render_pass() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let format = VkFormat::VK_FORMAT_R8G8B8A8_UNORM;
+        let memory;
+        let mut image = ImageBuilder::new(VkImageType::VK_IMAGE_TYPE_2D, format, VkExtent3D{width: 64, height: 64, depth: 1})
+            .usage(VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT | VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT)
+            .build(&device).unwrap();
+        let requirements = image.memory_requirements();
+        memory = DeviceMemory::allocate(&device, requirements.size, requirements.memory_type_bits,
+                                        MemoryPropertyFlags::empty(), MemoryPropertyFlags::empty()).unwrap();
+        image.bind_memory(&memory, 0).unwrap();
+        let range = image.full_range(VK_IMAGE_ASPECT_COLOR_BIT);
+        let view = ImageView::new(&image, VkImageViewType::VK_IMAGE_VIEW_TYPE_2D, format,
+                                  ComponentMapping::default(), range).unwrap();
+        let (subpasses, dependencies) = subpasses();
+        let render_pass = RenderPass::new(&device, &[color_attachment()], &subpasses, &dependencies).unwrap();
+        let framebuffer = Framebuffer::new(&render_pass, &[&view], 64, 64, 1).unwrap();
+
+        let mut queue = device.get_queue(0, 0).unwrap();
+        let mut fence = Fence::new(&device, false).unwrap();
+        let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+        let inheritance = Inheritance {
+            render_pass: Some(&render_pass),
+            subpass: 1,
+            framebuffer: Some(&framebuffer),
+            ..Inheritance::default()
+        };
+        let secondary_buf = SecondaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+            .begin_in_render_pass(CommandBufferUsageFlags::empty(), &inheritance).unwrap()
+            .end().unwrap();
+        let secondary_bufs = SecondaryCommandBuffer::allocate(&cmd_pool, 2).unwrap();
+        let mut secondary_bufs = secondary_bufs.into_iter();
+        match secondary_bufs.next().unwrap()
+            .begin_in_render_pass(CommandBufferUsageFlags::empty(), &Inheritance{subpass: 2, ..inheritance}) {
+            Err(Error::Validation(ValidationError::InvalidSubpass(2))) => {},
+            _ => panic!("Expected an invalid subpass error"),
+        }
+        match secondary_bufs.next().unwrap()
+            .begin_in_render_pass(CommandBufferUsageFlags::empty(), &Inheritance::default()) {
+            Err(Error::Validation(ValidationError::NoInheritedRenderPass)) => {},
+            _ => panic!("Expected a missing render pass error"),
+        }
+
+        let clear_values = [clear_color([0.0, 0.0, 0.0, 1.0])];
+        let offset_area = VkRect2D{offset: VkOffset2D{x: 32, y: 0}, extent: framebuffer.extent()};
+        match PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+            .begin(CommandBufferUsageFlags::empty()).unwrap()
+            .begin_render_pass(&framebuffer, offset_area, &clear_values, VkSubpassContents::VK_SUBPASS_CONTENTS_INLINE) {
+            Err(Error::Validation(ValidationError::InvalidRenderArea{x: 32, y: 0, width: 64, height: 64})) => {},
+            _ => panic!("Expected an invalid render area error"),
+        }
+        match PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+            .begin(CommandBufferUsageFlags::empty()).unwrap()
+            .begin_render_pass(&framebuffer, framebuffer.area(), &clear_values,
+                               VkSubpassContents::VK_SUBPASS_CONTENTS_INLINE).unwrap()
+            .end_render_pass() {
+            Err(Error::Validation(ValidationError::RenderPassNotFinished{subpass: 0, subpass_count: 2})) => {},
+            _ => panic!("Expected an unfinished render pass error"),
+        }
+
+        let mut cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+            .begin(CommandBufferUsageFlags::empty()).unwrap()
+            .begin_render_pass(&framebuffer, framebuffer.area(), &clear_values,
+                               VkSubpassContents::VK_SUBPASS_CONTENTS_INLINE).unwrap();
+        cmd_buf.next_subpass(VkSubpassContents::VK_SUBPASS_CONTENTS_SECONDARY_COMMAND_BUFFERS).unwrap();
+        assert_eq!(cmd_buf.subpass(), 1);
+        assert_eq!(cmd_buf.next_subpass(VkSubpassContents::VK_SUBPASS_CONTENTS_INLINE),
+                   Err(Error::from(ValidationError::InvalidSubpass(2))));
+        cmd_buf.execute_commands(&[&secondary_buf]);
+        let cmd_buf = cmd_buf.end_render_pass().unwrap().end().unwrap();
+        queue.submit(&[&cmd_buf], &[], &[], Some(&mut fence)).unwrap();
+        assert!(fence.wait(1_000_000_000).unwrap());
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,221 @@
+use sys::sampler::*;
+use sys::common::{VkBool32, VkCompareOp, VkResult, VkStructureType, VK_NULL_HANDLE};
+use device::Device;
+use error::{Error, ValidationError};
+use std::ptr;
+
+pub struct SamplerBuilder {
+    mag_filter: VkFilter,
+    min_filter: VkFilter,
+    mipmap_mode: VkSamplerMipmapMode,
+    address_modes: [VkSamplerAddressMode; 3],
+    mip_lod_bias: f32,
+    min_lod: f32,
+    max_lod: f32,
+    max_anisotropy: Option<f32>,
+    compare_op: Option<VkCompareOp>,
+    border_color: VkBorderColor,
+    unnormalized_coordinates: bool,
+}
+
+impl SamplerBuilder {
+    // Nearest filtering, repeating, without a LOD clamp
+    pub fn new() -> Self {
+        SamplerBuilder {
+            mag_filter: VkFilter::VK_FILTER_NEAREST,
+            min_filter: VkFilter::VK_FILTER_NEAREST,
+            mipmap_mode: VkSamplerMipmapMode::VK_SAMPLER_MIPMAP_MODE_NEAREST,
+            address_modes: [VkSamplerAddressMode::VK_SAMPLER_ADDRESS_MODE_REPEAT; 3],
+            mip_lod_bias: 0.0,
+            min_lod: 0.0,
+            max_lod: VK_LOD_CLAMP_NONE,
+            max_anisotropy: None,
+            compare_op: None,
+            border_color: VkBorderColor::VK_BORDER_COLOR_FLOAT_TRANSPARENT_BLACK,
+            unnormalized_coordinates: false,
+        }
+    }
+
+    pub fn filters(mut self, mag_filter: VkFilter, min_filter: VkFilter) -> Self {
+        self.mag_filter = mag_filter;
+        self.min_filter = min_filter;
+        self
+    }
+
+    pub fn mipmap_mode(mut self, mipmap_mode: VkSamplerMipmapMode) -> Self {
+        self.mipmap_mode = mipmap_mode;
+        self
+    }
+
+    pub fn address_modes(mut self, u: VkSamplerAddressMode, v: VkSamplerAddressMode, w: VkSamplerAddressMode) -> Self {
+        self.address_modes = [u, v, w];
+        self
+    }
+
+    // Clamped to `max_sampler_lod_bias` on creation
+    pub fn mip_lod_bias(mut self, mip_lod_bias: f32) -> Self {
+        self.mip_lod_bias = mip_lod_bias;
+        self
+    }
+
+    pub fn lod_clamp(mut self, min_lod: f32, max_lod: f32) -> Self {
+        self.min_lod = min_lod;
+        self.max_lod = max_lod;
+        self
+    }
+
+    // Needs the sampler_anisotropy feature, and is clamped to
+    // `max_sampler_anisotropy` on creation
+    pub fn anisotropy(mut self, max_anisotropy: f32) -> Self {
+        self.max_anisotropy = Some(max_anisotropy);
+        self
+    }
+
+    pub fn compare_op(mut self, compare_op: VkCompareOp) -> Self {
+        self.compare_op = Some(compare_op);
+        self
+    }
+
+    pub fn border_color(mut self, border_color: VkBorderColor) -> Self {
+        self.border_color = border_color;
+        self
+    }
+
+    pub fn unnormalized_coordinates(mut self, unnormalized_coordinates: bool) -> Self {
+        self.unnormalized_coordinates = unnormalized_coordinates;
+        self
+    }
+
+    pub fn build<'a>(&self, device: &'a Device) -> Result<Sampler<'a>, Error> {
+        if self.min_lod > self.max_lod {
+            return Err(Error::from(ValidationError::InvalidLodRange));
+        }
+        let limits = device.limits();
+        let max_anisotropy = match self.max_anisotropy {
+            Some(_) if device.features().sampler_anisotropy != VkBool32::True =>
+                return Err(Error::from(ValidationError::FeatureNotEnabled("sampler_anisotropy"))),
+            Some(max_anisotropy) => Some(max_anisotropy.max(1.0).min(limits.max_sampler_anisotropy)),
+            None => None,
+        };
+        let mip_lod_bias = self.mip_lod_bias.max(-limits.max_sampler_lod_bias).min(limits.max_sampler_lod_bias);
+
+        let bool32 = |b| if b {VkBool32::True} else {VkBool32::False};
+        let create_info = VkSamplerCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_SAMPLER_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkSamplerCreateFlags::Reserved,
+            mag_filter: self.mag_filter,
+            min_filter: self.min_filter,
+            mipmap_mode: self.mipmap_mode,
+            address_mode_u: self.address_modes[0],
+            address_mode_v: self.address_modes[1],
+            address_mode_w: self.address_modes[2],
+            mip_lod_bias: mip_lod_bias,
+            anisotropy_enable: bool32(max_anisotropy.is_some()),
+            max_anisotropy: max_anisotropy.unwrap_or(1.0),
+            compare_enable: bool32(self.compare_op.is_some()),
+            compare_op: self.compare_op.unwrap_or(VkCompareOp::VK_COMPARE_OP_NEVER),
+            min_lod: self.min_lod,
+            max_lod: self.max_lod,
+            border_color: self.border_color,
+            unnormalized_coordinates: bool32(self.unnormalized_coordinates),
+        };
+        let mut sampler = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateSampler)(*device.handle(), &create_info, ptr::null(), &mut sampler)} {
+            VkResult::VK_SUCCESS => Ok(Sampler{handle: sampler, device: device, max_anisotropy: max_anisotropy}),
+            x => Err(Error::from(x)),
+        }
+    }
+}
+
+impl Default for SamplerBuilder {
+    fn default() -> Self {
+        SamplerBuilder::new()
+    }
+}
+
+pub struct Sampler<'a> {
+    handle: VkSampler,
+    device: &'a Device<'a>,
+    max_anisotropy: Option<f32>,
+}
+
+impl<'a> Sampler<'a> {
+    pub fn handle(&self) -> &VkSampler {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+    // The clamped anisotropy, if enabled
+    pub fn max_anisotropy(&self) -> Option<f32> {self.max_anisotropy}
+}
+
+impl<'a> Drop for Sampler<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroySampler)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority};
+    use error::{Error, ValidationError};
+    use sys::common::{VkBool32, VkCompareOp};
+    use sys::device::VkPhysicalDeviceFeatures;
+    use std::collections::HashMap;
+
+    use sampler::*;
+
+    #[test]
+    fn create_sampler() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        let clamp = VkSamplerAddressMode::VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_EDGE;
+        let sampler = SamplerBuilder::new()
+            .filters(VkFilter::VK_FILTER_LINEAR, VkFilter::VK_FILTER_LINEAR)
+            .mipmap_mode(VkSamplerMipmapMode::VK_SAMPLER_MIPMAP_MODE_LINEAR)
+            .address_modes(clamp, clamp, clamp)
+            .lod_clamp(0.0, 4.0)
+            .compare_op(VkCompareOp::VK_COMPARE_OP_LESS)
+            .build(&device).unwrap();
+        assert_eq!(sampler.max_anisotropy(), None);
+        assert_eq!(SamplerBuilder::new().lod_clamp(2.0, 1.0).build(&device).err(),
+                   Some(Error::from(ValidationError::InvalidLodRange)));
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn anisotropy() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let physical_device = &instance.devices().unwrap()[0];
+        {
+            let device = {
+                let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+                Device::new(physical_device, priorities).unwrap()
+            };
+            assert_eq!(SamplerBuilder::new().anisotropy(16.0).build(&device).err(),
+                       Some(Error::from(ValidationError::FeatureNotEnabled("sampler_anisotropy"))));
+        }
+
+        if physical_device.features().sampler_anisotropy == VkBool32::True {
+            let device = {
+                let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+                let features = VkPhysicalDeviceFeatures{sampler_anisotropy: VkBool32::True, ..Default::default()};
+                Device::with_features(physical_device, priorities, &features).unwrap()
+            };
+            let sampler = SamplerBuilder::new().anisotropy(1.0e6).build(&device).unwrap();
+            assert_eq!(sampler.max_anisotropy(), Some(device.limits().max_sampler_anisotropy));
+        }
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
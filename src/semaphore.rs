@@ -2,6 +2,7 @@ use sys::semaphore::*;
 use device::Device;
 use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
 use std::ptr;
+use error::Error;
 
 pub struct Semaphore<'a> {
     handle: VkSemaphore,
@@ -9,23 +10,25 @@ pub struct Semaphore<'a> {
 }
 
 impl<'a> Semaphore<'a> {
-    pub fn new(device: &'a Device) -> Result<Self, VkResult> {
+    pub fn new(device: &'a Device) -> Result<Self, Error> {
         let create_info = VkSemaphoreCreateInfo {
             s_type: VkStructureType::VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO,
             p_next: ptr::null(),
             flags: VkSemaphoreCreateFlags::Reserved,
         };
         let mut semaphore = VK_NULL_HANDLE;
-        match unsafe{vkCreateSemaphore(*device.handle(), &create_info, ptr::null(), &mut semaphore)} {
+        match unsafe{(device.fns().vkCreateSemaphore)(*device.handle(), &create_info, ptr::null(), &mut semaphore)} {
             VkResult::VK_SUCCESS => Ok(Semaphore{handle: semaphore, device: device}),
-            x => Err(x),
+            x => Err(Error::from(x)),
         }
     }
+
+    pub fn handle(&self) -> &VkSemaphore {&self.handle}
 }
 
 impl<'a> Drop for Semaphore<'a> {
     fn drop(&mut self) {
-        unsafe{vkDestroySemaphore(*self.device.handle(), self.handle, ptr::null())}
+        unsafe{(self.device.fns().vkDestroySemaphore)(*self.device.handle(), self.handle, ptr::null())}
     }
 }
 
@@ -0,0 +1,165 @@
+use sys::shader_module::*;
+use sys::common::{VkResult, VkStructureType, VK_NULL_HANDLE};
+use device::Device;
+use error::{Error, ValidationError};
+use std::ptr;
+
+pub const SPIRV_MAGIC: u32 = 0x07230203;
+// Magic number, version, generator, bound and schema
+const SPIRV_HEADER_WORDS: usize = 5;
+
+// Reassembles the words of a SPIR-V binary, which may have been written with
+// either endianness
+fn spirv_words(bytes: &[u8]) -> Result<Vec<u32>, Error> {
+    if bytes.len() % 4 != 0 || bytes.len() < SPIRV_HEADER_WORDS * 4 {
+        return Err(Error::from(ValidationError::InvalidSpirvLength(bytes.len())));
+    }
+    let words = bytes.chunks(4)
+        .map(|b| (b[0] as u32) | (b[1] as u32) << 8 | (b[2] as u32) << 16 | (b[3] as u32) << 24)
+        .collect::<Vec<_>>();
+    match words[0] {
+        SPIRV_MAGIC => Ok(words),
+        magic if magic.swap_bytes() == SPIRV_MAGIC => Ok(words.into_iter().map(u32::swap_bytes).collect()),
+        magic => Err(Error::from(ValidationError::InvalidSpirvMagic(magic))),
+    }
+}
+
+pub struct ShaderModule<'a> {
+    handle: VkShaderModule,
+    device: &'a Device<'a>,
+}
+
+impl<'a> ShaderModule<'a> {
+    pub fn new(device: &'a Device, code: &[u32]) -> Result<Self, Error> {
+        if code.len() < SPIRV_HEADER_WORDS {
+            return Err(Error::from(ValidationError::InvalidSpirvLength(code.len() * 4)));
+        }
+        if code[0] != SPIRV_MAGIC {
+            return Err(Error::from(ValidationError::InvalidSpirvMagic(code[0])));
+        }
+        let create_info = VkShaderModuleCreateInfo {
+            s_type: VkStructureType::VK_STRUCTURE_TYPE_SHADER_MODULE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: VkShaderModuleCreateFlags::Reserved,
+            code_size: code.len() * 4,
+            p_code: code.as_ptr(),
+        };
+        let mut module = VK_NULL_HANDLE;
+        match unsafe {(device.fns().vkCreateShaderModule)(*device.handle(), &create_info, ptr::null(), &mut module)} {
+            VkResult::VK_SUCCESS => Ok(ShaderModule{handle: module, device: device}),
+            x => Err(Error::from(x)),
+        }
+    }
+
+    // E.g. the contents of a .spv file, which needn't be aligned
+    pub fn from_bytes(device: &'a Device, bytes: &[u8]) -> Result<Self, Error> {
+        let words = spirv_words(bytes)?;
+        ShaderModule::new(device, &words)
+    }
+
+    pub fn handle(&self) -> &VkShaderModule {&self.handle}
+    pub fn device(&self) -> &'a Device<'a> {self.device}
+}
+
+impl<'a> Drop for ShaderModule<'a> {
+    fn drop(&mut self) {
+        unsafe {(self.device.fns().vkDestroyShaderModule)(*self.device.handle(), self.handle, ptr::null())}
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use instance::debug_instance;
+    use debug::debug_monitor;
+    use std::sync::atomic::Ordering;
+
+    use device::{Device, QueuePriority};
+    use error::{Error, ValidationError};
+    use std::collections::HashMap;
+
+    use shader_module::*;
+
+    // An empty compute shader with entry point "main"
+    pub const COMPUTE_SHADER: [u32; 35] = [
+        SPIRV_MAGIC, 0x00010000, 0, 5, 0,
+        0x00020011, 1,                          // OpCapability Shader
+        0x0003000e, 0, 1,                       // OpMemoryModel Logical GLSL450
+        0x0005000f, 5, 3, 0x6e69616d, 0,        // OpEntryPoint GLCompute %3 "main"
+        0x00060010, 3, 17, 1, 1, 1,             // OpExecutionMode %3 LocalSize 1 1 1
+        0x00020013, 1,                          // %1 = OpTypeVoid
+        0x00030021, 2, 1,                       // %2 = OpTypeFunction %1
+        0x00050036, 1, 3, 0, 2,                 // %3 = OpFunction %1 None %2
+        0x000200f8, 4,                          // %4 = OpLabel
+        0x000100fd,                             // OpReturn
+        0x00010038,                             // OpFunctionEnd
+    ];
+
+    // An empty vertex shader with entry point "main"
+    pub const VERTEX_SHADER: [u32; 29] = [
+        SPIRV_MAGIC, 0x00010000, 0, 5, 0,
+        0x00020011, 1,                          // OpCapability Shader
+        0x0003000e, 0, 1,                       // OpMemoryModel Logical GLSL450
+        0x0005000f, 0, 3, 0x6e69616d, 0,        // OpEntryPoint Vertex %3 "main"
+        0x00020013, 1,                          // %1 = OpTypeVoid
+        0x00030021, 2, 1,                       // %2 = OpTypeFunction %1
+        0x00050036, 1, 3, 0, 2,                 // %3 = OpFunction %1 None %2
+        0x000200f8, 4,                          // %4 = OpLabel
+        0x000100fd,                             // OpReturn
+        0x00010038,                             // OpFunctionEnd
+    ];
+
+    // An empty fragment shader with entry point "main"
+    pub const FRAGMENT_SHADER: [u32; 32] = [
+        SPIRV_MAGIC, 0x00010000, 0, 5, 0,
+        0x00020011, 1,                          // OpCapability Shader
+        0x0003000e, 0, 1,                       // OpMemoryModel Logical GLSL450
+        0x0005000f, 4, 3, 0x6e69616d, 0,        // OpEntryPoint Fragment %3 "main"
+        0x00030010, 3, 7,                       // OpExecutionMode %3 OriginUpperLeft
+        0x00020013, 1,                          // %1 = OpTypeVoid
+        0x00030021, 2, 1,                       // %2 = OpTypeFunction %1
+        0x00050036, 1, 3, 0, 2,                 // %3 = OpFunction %1 None %2
+        0x000200f8, 4,                          // %4 = OpLabel
+        0x000100fd,                             // OpReturn
+        0x00010038,                             // OpFunctionEnd
+    ];
+
+    fn bytes(words: &[u32], big_endian: bool) -> Vec<u8> {
+        words.iter()
+            .flat_map(|&w| if big_endian {w.to_be()} else {w.to_le()}.to_ne_bytes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn spirv_endianness() {
+        assert_eq!(spirv_words(&bytes(&COMPUTE_SHADER, false)).unwrap(), &COMPUTE_SHADER[..]);
+        assert_eq!(spirv_words(&bytes(&COMPUTE_SHADER, true)).unwrap(), &COMPUTE_SHADER[..]);
+    }
+
+    #[test]
+    fn invalid_spirv() {
+        let mut code = bytes(&COMPUTE_SHADER, false);
+        code.pop();
+        assert_eq!(spirv_words(&code), Err(Error::from(ValidationError::InvalidSpirvLength(139))));
+        assert_eq!(spirv_words(&[0; 8]), Err(Error::from(ValidationError::InvalidSpirvLength(8))));
+        assert_eq!(spirv_words(&[0; 20]), Err(Error::from(ValidationError::InvalidSpirvMagic(0))));
+    }
+
+    #[test]
+    fn create_shader_module() {
+        let instance = debug_instance();
+        let (errs, dbg) = debug_monitor(&instance, true);
+        let device = {
+            let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<_, _>>();
+            Device::new(&instance.devices().unwrap()[0], priorities).unwrap()
+        };
+        ShaderModule::new(&device, &COMPUTE_SHADER).unwrap();
+        ShaderModule::from_bytes(&device, &bytes(&COMPUTE_SHADER, true)).unwrap();
+        ShaderModule::new(&device, &VERTEX_SHADER).unwrap();
+        ShaderModule::new(&device, &FRAGMENT_SHADER).unwrap();
+        assert_eq!(ShaderModule::new(&device, &COMPUTE_SHADER[1..]).err(),
+                   Some(Error::from(ValidationError::InvalidSpirvMagic(0x00010000))));
+
+        drop(dbg);
+        assert!(!errs.load(Ordering::Relaxed));
+    }
+}
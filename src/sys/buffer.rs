@@ -0,0 +1,8 @@
+pub use sys::vk::{VkBuffer, VkBufferView, VkBufferCreateFlags, VK_BUFFER_CREATE_SPARSE_BINDING_BIT,
+                  VK_BUFFER_CREATE_SPARSE_RESIDENCY_BIT, VK_BUFFER_CREATE_SPARSE_ALIASED_BIT,
+                  VkBufferUsageFlags, VK_BUFFER_USAGE_TRANSFER_SRC_BIT, VK_BUFFER_USAGE_TRANSFER_DST_BIT,
+                  VK_BUFFER_USAGE_UNIFORM_TEXEL_BUFFER_BIT, VK_BUFFER_USAGE_STORAGE_TEXEL_BUFFER_BIT,
+                  VK_BUFFER_USAGE_UNIFORM_BUFFER_BIT, VK_BUFFER_USAGE_STORAGE_BUFFER_BIT,
+                  VK_BUFFER_USAGE_INDEX_BUFFER_BIT, VK_BUFFER_USAGE_VERTEX_BUFFER_BIT,
+                  VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT, VkBufferCreateInfo, PFNvkCreateBuffer,
+                  PFNvkDestroyBuffer, PFNvkGetBufferMemoryRequirements, PFNvkBindBufferMemory};
@@ -1,36 +1,16 @@
-extern crate libc;
-use self::libc::{uint32_t, c_void};
-use sys::common::{VkFlags, VkStructureType, VkResult};
-use sys::device::VkDevice;
-use sys::command_pool::VkCommandPool;
-
-pub type VkCommandBuffer = usize;
-
-#[repr(C)]
-pub enum VkCommandBufferLevel {
-    VK_COMMAND_BUFFER_LEVEL_PRIMARY = 0,
-    VK_COMMAND_BUFFER_LEVEL_SECONDARY = 1,
-}
-
-#[repr(C)]
-pub struct VkCommandBufferAllocateInfo {
-    pub s_type: VkStructureType,
-    pub p_next: *const c_void,
-    pub command_pool: VkCommandPool,
-    pub level: VkCommandBufferLevel,
-    pub command_buffer_count: uint32_t,
-}
-
-bitflags! {
-    #[repr(C)]
-    pub flags VkCommandBufferResetFlags: VkFlags {
-        const VK_COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT = 0x00000001,
-    }
-}
-
-#[link(name="vulkan")]
-extern {
-    pub fn vkAllocateCommandBuffers(device: VkDevice, p_allocate_info: *const VkCommandBufferAllocateInfo, p_command_buffers: *mut VkCommandBuffer) -> VkResult;
-    pub fn vkFreeCommandBuffers(device: VkDevice, command_pool: VkCommandPool, command_buffer_count: uint32_t, p_command_buffers: *const VkCommandBuffer);
-    pub fn vkResetCommandBuffer(command_buffer: VkCommandBuffer, flags: VkCommandBufferResetFlags) -> VkResult;
-}
+pub use sys::vk::{VkCommandBuffer, VkCommandBufferLevel, VkIndexType, VkCommandBufferAllocateInfo,
+                  VkCommandBufferResetFlags, VK_COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT,
+                  VkCommandBufferUsageFlags, VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT,
+                  VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT,
+                  VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT, VkCommandBufferInheritanceInfo,
+                  VkCommandBufferBeginInfo, VkMemoryBarrier, VkBufferMemoryBarrier, VkImageMemoryBarrier,
+                  PFNvkAllocateCommandBuffers, PFNvkFreeCommandBuffers, PFNvkResetCommandBuffer,
+                  PFNvkBeginCommandBuffer, PFNvkEndCommandBuffer, PFNvkCmdExecuteCommands,
+                  PFNvkCmdBindPipeline, PFNvkCmdBindDescriptorSets, PFNvkCmdDispatch,
+                  PFNvkCmdDispatchIndirect, PFNvkCmdSetViewport, PFNvkCmdSetScissor,
+                  PFNvkCmdBindVertexBuffers, PFNvkCmdBindIndexBuffer, PFNvkCmdDraw, PFNvkCmdDrawIndexed,
+                  PFNvkCmdDrawIndirect, PFNvkCmdPipelineBarrier, PFNvkCmdPushConstants,
+                  PFNvkCmdSetLineWidth, PFNvkCmdSetDepthBias, PFNvkCmdSetBlendConstants, PFNvkCmdSetDepthBounds,
+                  PFNvkCmdSetStencilCompareMask, PFNvkCmdSetStencilWriteMask, PFNvkCmdSetStencilReference,
+                  VkStencilFaceFlags, VK_STENCIL_FACE_FRONT_BIT, VK_STENCIL_FACE_BACK_BIT,
+                  VK_STENCIL_FRONT_AND_BACK};
@@ -0,0 +1,9 @@
+pub use sys::vk::{VkDescriptorSetLayout, VkDescriptorPool, VkDescriptorSet, VkDescriptorType,
+                  VkDescriptorSetLayoutBinding, VkDescriptorSetLayoutCreateFlags,
+                  VkDescriptorSetLayoutCreateInfo, VkDescriptorPoolCreateFlags,
+                  VK_DESCRIPTOR_POOL_CREATE_FREE_DESCRIPTOR_SET_BIT, VkDescriptorPoolResetFlags,
+                  VkDescriptorPoolSize, VkDescriptorPoolCreateInfo, VkDescriptorSetAllocateInfo,
+                  VkDescriptorImageInfo, VkDescriptorBufferInfo, VkWriteDescriptorSet, VkCopyDescriptorSet,
+                  PFNvkCreateDescriptorSetLayout, PFNvkDestroyDescriptorSetLayout, PFNvkCreateDescriptorPool,
+                  PFNvkDestroyDescriptorPool, PFNvkResetDescriptorPool, PFNvkAllocateDescriptorSets,
+                  PFNvkFreeDescriptorSets, PFNvkUpdateDescriptorSets};
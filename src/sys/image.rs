@@ -0,0 +1,33 @@
+pub use sys::vk::{VkImage, VkImageView, VkFormat, VkImageType, VK_REMAINING_MIP_LEVELS,
+                  VK_REMAINING_ARRAY_LAYERS, VkImageTiling, VkImageLayout, VkImageViewType,
+                  VkComponentSwizzle, VkImageCreateFlags, VK_IMAGE_CREATE_SPARSE_BINDING_BIT,
+                  VK_IMAGE_CREATE_SPARSE_RESIDENCY_BIT, VK_IMAGE_CREATE_SPARSE_ALIASED_BIT,
+                  VK_IMAGE_CREATE_MUTABLE_FORMAT_BIT, VK_IMAGE_CREATE_CUBE_COMPATIBLE_BIT, VkImageUsageFlags,
+                  VK_IMAGE_USAGE_TRANSFER_SRC_BIT, VK_IMAGE_USAGE_TRANSFER_DST_BIT,
+                  VK_IMAGE_USAGE_SAMPLED_BIT, VK_IMAGE_USAGE_STORAGE_BIT, VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT,
+                  VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT, VK_IMAGE_USAGE_TRANSIENT_ATTACHMENT_BIT,
+                  VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT, VkImageAspectFlags, VK_IMAGE_ASPECT_COLOR_BIT,
+                  VK_IMAGE_ASPECT_DEPTH_BIT, VK_IMAGE_ASPECT_STENCIL_BIT, VK_IMAGE_ASPECT_METADATA_BIT,
+                  VkFormatFeatureFlags, VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT,
+                  VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT, VK_FORMAT_FEATURE_STORAGE_IMAGE_ATOMIC_BIT,
+                  VK_FORMAT_FEATURE_UNIFORM_TEXEL_BUFFER_BIT, VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_BIT,
+                  VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_ATOMIC_BIT, VK_FORMAT_FEATURE_VERTEX_BUFFER_BIT,
+                  VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT, VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BLEND_BIT,
+                  VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT, VK_FORMAT_FEATURE_BLIT_SRC_BIT,
+                  VK_FORMAT_FEATURE_BLIT_DST_BIT, VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT,
+                  VkFormatProperties, VkImageFormatProperties, VkImageCreateInfo, VkComponentMapping,
+                  VkImageSubresourceRange, VkImageViewCreateFlags, VkImageViewCreateInfo,
+                  PFNvkGetPhysicalDeviceFormatProperties, PFNvkGetPhysicalDeviceImageFormatProperties,
+                  PFNvkCreateImage, PFNvkDestroyImage, PFNvkGetImageMemoryRequirements, PFNvkBindImageMemory,
+                  PFNvkCreateImageView, PFNvkDestroyImageView};
+
+impl Default for VkComponentMapping {
+    fn default() -> Self {
+        VkComponentMapping {
+            r: VkComponentSwizzle::VK_COMPONENT_SWIZZLE_IDENTITY,
+            g: VkComponentSwizzle::VK_COMPONENT_SWIZZLE_IDENTITY,
+            b: VkComponentSwizzle::VK_COMPONENT_SWIZZLE_IDENTITY,
+            a: VkComponentSwizzle::VK_COMPONENT_SWIZZLE_IDENTITY,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+// Function tables, loaded by name through vkGet*ProcAddr
+pub use sys::vk::{EntryFns, InstanceFns, DebugReportFns, DeviceFns};
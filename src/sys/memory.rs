@@ -0,0 +1,9 @@
+pub use sys::vk::{VkDeviceMemory, VK_MAX_MEMORY_TYPES, VK_MAX_MEMORY_HEAPS, VK_WHOLE_SIZE,
+                  VkMemoryPropertyFlags, VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT,
+                  VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT, VK_MEMORY_PROPERTY_HOST_COHERENT_BIT,
+                  VK_MEMORY_PROPERTY_HOST_CACHED_BIT, VK_MEMORY_PROPERTY_LAZILY_ALLOCATED_BIT,
+                  VkMemoryHeapFlags, VK_MEMORY_HEAP_DEVICE_LOCAL_BIT, VkMemoryType, VkMemoryHeap,
+                  VkPhysicalDeviceMemoryProperties, VkMemoryAllocateInfo, VkMappedMemoryRange,
+                  VkMemoryRequirements, VkMemoryMapFlags, PFNvkGetPhysicalDeviceMemoryProperties,
+                  PFNvkAllocateMemory, PFNvkFreeMemory, PFNvkMapMemory, PFNvkUnmapMemory,
+                  PFNvkFlushMappedMemoryRanges, PFNvkInvalidateMappedMemoryRanges};
@@ -1,5 +1,55 @@
-// Not auto-generated, pending KhronosGroup/VulkanDocs#210
+// The Vulkan declarations are generated from the registry into vk.rs, see
+// gen/. The other modules group them by the part of the API they belong to,
+// next to the few hand-written helpers.
 
+// C enums are integer newtypes rather than Rust enums, a value we don't know
+// about (e.g. from a newer driver) would otherwise be undefined behaviour.
+macro_rules! vk_enum {
+    ($name:ident: $repr:ty { $($variant:ident = $value:expr,)* }) => {
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name(pub $repr);
+
+        impl $name {
+            $(pub const $variant: $name = $name($value);)*
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match *self {
+                    $($name::$variant => f.write_str(stringify!($variant)),)*
+                    $name(x) => write!(f, "{}({})", stringify!($name), x),
+                }
+            }
+        }
+    }
+}
+
+// Declares a table of function pointers, named after the Vulkan functions.
+// `load` resolves each by name, and fails if any is missing.
+macro_rules! vk_functions {
+    ($name:ident { $($function:ident: $ty:ty,)* }) => {
+        #[derive(Clone, Copy)]
+        pub struct $name {
+            $(pub $function: $ty,)*
+        }
+
+        impl $name {
+            pub unsafe fn load<F>(mut get_proc_addr: F) -> Option<Self>
+                where F: FnMut(*const c_char) -> Option<PFNvkVoidFunction>
+            {
+                Some($name {
+                    $($function: match get_proc_addr(concat!(stringify!($function), "\0").as_ptr() as *const c_char) {
+                        Some(f) => ::std::mem::transmute::<PFNvkVoidFunction, $ty>(f),
+                        None => return None,
+                    },)*
+                })
+            }
+        }
+    }
+}
+
+pub mod vk;
 pub mod common;
 pub mod instance;
 pub mod device;
@@ -9,3 +59,14 @@ pub mod command_buffer;
 pub mod fence;
 pub mod semaphore;
 pub mod event;
+pub mod queue;
+pub mod render_pass;
+pub mod query;
+pub mod memory;
+pub mod buffer;
+pub mod image;
+pub mod sampler;
+pub mod shader_module;
+pub mod descriptor;
+pub mod pipeline;
+pub mod loader;
@@ -0,0 +1,47 @@
+use sys::common::VkBool32;
+
+pub use sys::vk::{VkPipeline, VkPipelineLayout, VkPipelineCache, VkShaderStageFlags,
+                  VK_SHADER_STAGE_VERTEX_BIT, VK_SHADER_STAGE_TESSELLATION_CONTROL_BIT,
+                  VK_SHADER_STAGE_TESSELLATION_EVALUATION_BIT, VK_SHADER_STAGE_GEOMETRY_BIT,
+                  VK_SHADER_STAGE_FRAGMENT_BIT, VK_SHADER_STAGE_COMPUTE_BIT, VK_SHADER_STAGE_ALL_GRAPHICS,
+                  VK_SHADER_STAGE_ALL, VkPipelineCreateFlags, VK_PIPELINE_CREATE_DISABLE_OPTIMIZATION_BIT,
+                  VK_PIPELINE_CREATE_ALLOW_DERIVATIVES_BIT, VK_PIPELINE_CREATE_DERIVATIVE_BIT,
+                  VkPipelineBindPoint, VkPushConstantRange, VkPipelineLayoutCreateFlags,
+                  VkPipelineLayoutCreateInfo, VkPipelineCacheHeaderVersion, VkPipelineCacheCreateFlags,
+                  VkPipelineCacheCreateInfo, VkSpecializationMapEntry, VkSpecializationInfo,
+                  VkPipelineShaderStageCreateFlags, VkPipelineShaderStageCreateInfo,
+                  VkComputePipelineCreateInfo, VkVertexInputRate, VkVertexInputBindingDescription,
+                  VkVertexInputAttributeDescription, VkPipelineVertexInputStateCreateFlags,
+                  VkPipelineVertexInputStateCreateInfo, VkPrimitiveTopology,
+                  VkPipelineInputAssemblyStateCreateFlags, VkPipelineInputAssemblyStateCreateInfo,
+                  VkPipelineTessellationStateCreateFlags, VkPipelineTessellationStateCreateInfo, VkViewport,
+                  VkPipelineViewportStateCreateFlags, VkPipelineViewportStateCreateInfo, VkPolygonMode,
+                  VkCullModeFlags, VK_CULL_MODE_NONE, VK_CULL_MODE_FRONT_BIT, VK_CULL_MODE_BACK_BIT,
+                  VK_CULL_MODE_FRONT_AND_BACK, VkFrontFace, VkPipelineRasterizationStateCreateFlags,
+                  VkPipelineRasterizationStateCreateInfo, VkSampleMask, VkPipelineMultisampleStateCreateFlags,
+                  VkPipelineMultisampleStateCreateInfo, VkStencilOp, VkStencilOpState,
+                  VkPipelineDepthStencilStateCreateFlags, VkPipelineDepthStencilStateCreateInfo,
+                  VkBlendFactor, VkBlendOp, VkColorComponentFlags, VK_COLOR_COMPONENT_R_BIT,
+                  VK_COLOR_COMPONENT_G_BIT, VK_COLOR_COMPONENT_B_BIT, VK_COLOR_COMPONENT_A_BIT,
+                  VkPipelineColorBlendAttachmentState, VkLogicOp, VkPipelineColorBlendStateCreateFlags,
+                  VkPipelineColorBlendStateCreateInfo, VkDynamicState, VkPipelineDynamicStateCreateFlags,
+                  VkPipelineDynamicStateCreateInfo, VkGraphicsPipelineCreateInfo, PFNvkCreatePipelineCache,
+                  PFNvkDestroyPipelineCache, PFNvkGetPipelineCacheData, PFNvkMergePipelineCaches,
+                  PFNvkCreatePipelineLayout, PFNvkDestroyPipelineLayout, PFNvkCreateComputePipelines,
+                  PFNvkCreateGraphicsPipelines, PFNvkDestroyPipeline};
+
+// Blending disabled, writing all components
+impl Default for VkPipelineColorBlendAttachmentState {
+    fn default() -> Self {
+        VkPipelineColorBlendAttachmentState {
+            blend_enable: VkBool32::False,
+            src_color_blend_factor: VkBlendFactor::VK_BLEND_FACTOR_ONE,
+            dst_color_blend_factor: VkBlendFactor::VK_BLEND_FACTOR_ZERO,
+            color_blend_op: VkBlendOp::VK_BLEND_OP_ADD,
+            src_alpha_blend_factor: VkBlendFactor::VK_BLEND_FACTOR_ONE,
+            dst_alpha_blend_factor: VkBlendFactor::VK_BLEND_FACTOR_ZERO,
+            alpha_blend_op: VkBlendOp::VK_BLEND_OP_ADD,
+            color_write_mask: VkColorComponentFlags::all(),
+        }
+    }
+}
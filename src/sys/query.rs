@@ -0,0 +1,16 @@
+pub use sys::vk::{VkQueryPool, VkQueryType, VkQueryControlFlags, VK_QUERY_CONTROL_PRECISE_BIT,
+                  VkQueryPipelineStatisticFlags, VK_QUERY_PIPELINE_STATISTIC_INPUT_ASSEMBLY_VERTICES_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_INPUT_ASSEMBLY_PRIMITIVES_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_VERTEX_SHADER_INVOCATIONS_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_GEOMETRY_SHADER_INVOCATIONS_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_GEOMETRY_SHADER_PRIMITIVES_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_CLIPPING_INVOCATIONS_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_CLIPPING_PRIMITIVES_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_FRAGMENT_SHADER_INVOCATIONS_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_TESSELLATION_CONTROL_SHADER_PATCHES_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_TESSELLATION_EVALUATION_SHADER_INVOCATIONS_BIT,
+                  VK_QUERY_PIPELINE_STATISTIC_COMPUTE_SHADER_INVOCATIONS_BIT, VkQueryResultFlags,
+                  VK_QUERY_RESULT_64_BIT, VK_QUERY_RESULT_WAIT_BIT, VK_QUERY_RESULT_WITH_AVAILABILITY_BIT,
+                  VK_QUERY_RESULT_PARTIAL_BIT, VkQueryPoolCreateFlags, VkQueryPoolCreateInfo,
+                  PFNvkCreateQueryPool, PFNvkDestroyQueryPool, PFNvkGetQueryPoolResults,
+                  PFNvkCmdResetQueryPool, PFNvkCmdBeginQuery, PFNvkCmdEndQuery, PFNvkCmdWriteTimestamp};
@@ -0,0 +1 @@
+pub use sys::vk::{VK_QUEUE_FAMILY_IGNORED, VkSubmitInfo, PFNvkQueueSubmit, PFNvkQueueWaitIdle};
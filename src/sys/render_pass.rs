@@ -0,0 +1,9 @@
+pub use sys::vk::{VkRenderPass, VkFramebuffer, VK_ATTACHMENT_UNUSED, VK_SUBPASS_EXTERNAL, VkAttachmentLoadOp,
+                  VkAttachmentStoreOp, VkSubpassContents, VkAttachmentDescriptionFlags,
+                  VK_ATTACHMENT_DESCRIPTION_MAY_ALIAS_BIT, VkAttachmentDescription, VkAttachmentReference,
+                  VkSubpassDescriptionFlags, VkSubpassDescription, VkSubpassDependency,
+                  VkRenderPassCreateFlags, VkRenderPassCreateInfo, VkFramebufferCreateFlags,
+                  VkFramebufferCreateInfo, VkClearColorValue, VkClearDepthStencilValue, VkClearValue,
+                  VkRenderPassBeginInfo, PFNvkCreateRenderPass, PFNvkDestroyRenderPass,
+                  PFNvkCreateFramebuffer, PFNvkDestroyFramebuffer, PFNvkCmdBeginRenderPass,
+                  PFNvkCmdNextSubpass, PFNvkCmdEndRenderPass};
@@ -0,0 +1,3 @@
+pub use sys::vk::{VkSampler, VK_LOD_CLAMP_NONE, VkFilter, VkSamplerMipmapMode, VkSamplerAddressMode,
+                  VkBorderColor, VkSamplerCreateFlags, VkSamplerCreateInfo, PFNvkCreateSampler,
+                  PFNvkDestroySampler};
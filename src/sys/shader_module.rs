@@ -0,0 +1,2 @@
+pub use sys::vk::{VkShaderModule, VkShaderModuleCreateFlags, VkShaderModuleCreateInfo,
+                  PFNvkCreateShaderModule, PFNvkDestroyShaderModule};
@@ -0,0 +1,3253 @@
+// Generated by vkrs-gen from vk.xml, do not edit.
+#![allow(dead_code, non_camel_case_types, non_snake_case)]
+
+extern crate libc;
+#[allow(unused_imports)]
+use self::libc::{c_void, c_char, c_float, c_double, c_int, c_ulong, size_t, int8_t, uint8_t, int16_t, uint16_t, int32_t, uint32_t, int64_t, uint64_t};
+
+pub const VK_NULL_HANDLE : usize = 0;
+pub const VK_ATTACHMENT_UNUSED: uint32_t = !0;
+pub const VK_EXT_DEBUG_REPORT_EXTENSION_NAME: &'static str = "VK_EXT_debug_report";
+pub const VK_EXT_DEBUG_REPORT_SPEC_VERSION: uint32_t = 3;
+pub const VK_FALSE: uint32_t = 0;
+pub const VK_KHR_ANDROID_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_android_surface";
+pub const VK_KHR_ANDROID_SURFACE_SPEC_VERSION: uint32_t = 6;
+pub const VK_KHR_DISPLAY_EXTENSION_NAME: &'static str = "VK_KHR_display";
+pub const VK_KHR_DISPLAY_SPEC_VERSION: uint32_t = 21;
+pub const VK_KHR_DISPLAY_SWAPCHAIN_EXTENSION_NAME: &'static str = "VK_KHR_display_swapchain";
+pub const VK_KHR_DISPLAY_SWAPCHAIN_SPEC_VERSION: uint32_t = 9;
+pub const VK_KHR_MAINTENANCE1_EXTENSION_NAME: &'static str = "VK_KHR_maintenance1";
+pub const VK_KHR_MAINTENANCE1_SPEC_VERSION: uint32_t = 1;
+pub const VK_KHR_MIR_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_mir_surface";
+pub const VK_KHR_MIR_SURFACE_SPEC_VERSION: uint32_t = 4;
+pub const VK_KHR_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_surface";
+pub const VK_KHR_SURFACE_SPEC_VERSION: uint32_t = 25;
+pub const VK_KHR_SWAPCHAIN_EXTENSION_NAME: &'static str = "VK_KHR_swapchain";
+pub const VK_KHR_SWAPCHAIN_SPEC_VERSION: uint32_t = 68;
+pub const VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_wayland_surface";
+pub const VK_KHR_WAYLAND_SURFACE_SPEC_VERSION: uint32_t = 5;
+pub const VK_KHR_WIN32_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_win32_surface";
+pub const VK_KHR_WIN32_SURFACE_SPEC_VERSION: uint32_t = 5;
+pub const VK_KHR_XCB_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_xcb_surface";
+pub const VK_KHR_XCB_SURFACE_SPEC_VERSION: uint32_t = 6;
+pub const VK_KHR_XLIB_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_xlib_surface";
+pub const VK_KHR_XLIB_SURFACE_SPEC_VERSION: uint32_t = 6;
+pub const VK_LOD_CLAMP_NONE: c_float = 1000.0;
+pub const VK_MAX_DESCRIPTION_SIZE: uint32_t = 256;
+pub const VK_MAX_EXTENSION_NAME_SIZE: uint32_t = 256;
+pub const VK_MAX_MEMORY_HEAPS: uint32_t = 16;
+pub const VK_MAX_MEMORY_TYPES: uint32_t = 32;
+pub const VK_MAX_PHYSICAL_DEVICE_NAME_SIZE: uint32_t = 256;
+pub const VK_NV_GLSL_SHADER_EXTENSION_NAME: &'static str = "VK_NV_glsl_shader";
+pub const VK_NV_GLSL_SHADER_SPEC_VERSION: uint32_t = 1;
+pub const VK_QUEUE_FAMILY_IGNORED: uint32_t = !0;
+pub const VK_REMAINING_ARRAY_LAYERS: uint32_t = !0;
+pub const VK_REMAINING_MIP_LEVELS: uint32_t = !0;
+pub const VK_SUBPASS_EXTERNAL: uint32_t = !0;
+pub const VK_TRUE: uint32_t = 1;
+pub const VK_UUID_SIZE: uint32_t = 16;
+pub const VK_WHOLE_SIZE: uint64_t = !0;
+
+pub enum Display {}
+
+pub type VisualID = c_ulong;
+
+pub type Window = c_ulong;
+
+pub enum xcb_connection_t {}
+
+pub type xcb_visualid_t = uint32_t;
+
+pub type xcb_window_t = uint32_t;
+
+pub enum wl_display {}
+
+pub enum wl_surface {}
+
+pub enum MirConnection {}
+
+pub enum MirSurface {}
+
+pub enum ANativeWindow {}
+
+pub type HINSTANCE = *mut c_void;
+
+pub type HWND = *mut c_void;
+
+pub type VkFlags = uint32_t;
+
+vk_enum!(VkResult: int32_t {
+    VK_SUCCESS = 0,
+    VK_NOT_READY = 1,
+    VK_TIMEOUT = 2,
+    VK_EVENT_SET = 3,
+    VK_EVENT_RESET = 4,
+    VK_INCOMPLETE = 5,
+    VK_ERROR_OUT_OF_HOST_MEMORY = -1,
+    VK_ERROR_OUT_OF_DEVICE_MEMORY = -2,
+    VK_ERROR_INITIALIZATION_FAILED = -3,
+    VK_ERROR_DEVICE_LOST = -4,
+    VK_ERROR_MEMORY_MAP_FAILED = -5,
+    VK_ERROR_LAYER_NOT_PRESENT = -6,
+    VK_ERROR_EXTENSION_NOT_PRESENT = -7,
+    VK_ERROR_FEATURE_NOT_PRESENT = -8,
+    VK_ERROR_INCOMPATIBLE_DRIVER = -9,
+    VK_ERROR_TOO_MANY_OBJECTS = -10,
+    VK_ERROR_FORMAT_NOT_SUPPORTED = -11,
+    VK_ERROR_FRAGMENTED_POOL = -12,
+    VK_ERROR_VALIDATION_FAILED_EXT = -1000011001,
+    VK_ERROR_SURFACE_LOST_KHR = -1000000000,
+    VK_ERROR_NATIVE_WINDOW_IN_USE_KHR = -1000000001,
+    VK_SUBOPTIMAL_KHR = 1000001003,
+    VK_ERROR_OUT_OF_DATE_KHR = -1000001004,
+    VK_ERROR_INCOMPATIBLE_DISPLAY_KHR = -1000003001,
+    VK_ERROR_INVALID_SHADER_NV = -1000012000,
+    VK_ERROR_OUT_OF_POOL_MEMORY_KHR = -1000069000,
+});
+
+vk_enum!(VkStructureType: int32_t {
+    VK_STRUCTURE_TYPE_APPLICATION_INFO = 0,
+    VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO = 1,
+    VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO = 2,
+    VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO = 3,
+    VK_STRUCTURE_TYPE_SUBMIT_INFO = 4,
+    VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO = 5,
+    VK_STRUCTURE_TYPE_MAPPED_MEMORY_RANGE = 6,
+    VK_STRUCTURE_TYPE_BIND_SPARSE_INFO = 7,
+    VK_STRUCTURE_TYPE_FENCE_CREATE_INFO = 8,
+    VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO = 9,
+    VK_STRUCTURE_TYPE_EVENT_CREATE_INFO = 10,
+    VK_STRUCTURE_TYPE_QUERY_POOL_CREATE_INFO = 11,
+    VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO = 12,
+    VK_STRUCTURE_TYPE_BUFFER_VIEW_CREATE_INFO = 13,
+    VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO = 14,
+    VK_STRUCTURE_TYPE_IMAGE_VIEW_CREATE_INFO = 15,
+    VK_STRUCTURE_TYPE_SHADER_MODULE_CREATE_INFO = 16,
+    VK_STRUCTURE_TYPE_PIPELINE_CACHE_CREATE_INFO = 17,
+    VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO = 18,
+    VK_STRUCTURE_TYPE_PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO = 19,
+    VK_STRUCTURE_TYPE_PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO = 20,
+    VK_STRUCTURE_TYPE_PIPELINE_TESSELLATION_STATE_CREATE_INFO = 21,
+    VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_STATE_CREATE_INFO = 22,
+    VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_CREATE_INFO = 23,
+    VK_STRUCTURE_TYPE_PIPELINE_MULTISAMPLE_STATE_CREATE_INFO = 24,
+    VK_STRUCTURE_TYPE_PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO = 25,
+    VK_STRUCTURE_TYPE_PIPELINE_COLOR_BLEND_STATE_CREATE_INFO = 26,
+    VK_STRUCTURE_TYPE_PIPELINE_DYNAMIC_STATE_CREATE_INFO = 27,
+    VK_STRUCTURE_TYPE_GRAPHICS_PIPELINE_CREATE_INFO = 28,
+    VK_STRUCTURE_TYPE_COMPUTE_PIPELINE_CREATE_INFO = 29,
+    VK_STRUCTURE_TYPE_PIPELINE_LAYOUT_CREATE_INFO = 30,
+    VK_STRUCTURE_TYPE_SAMPLER_CREATE_INFO = 31,
+    VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_CREATE_INFO = 32,
+    VK_STRUCTURE_TYPE_DESCRIPTOR_POOL_CREATE_INFO = 33,
+    VK_STRUCTURE_TYPE_DESCRIPTOR_SET_ALLOCATE_INFO = 34,
+    VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET = 35,
+    VK_STRUCTURE_TYPE_COPY_DESCRIPTOR_SET = 36,
+    VK_STRUCTURE_TYPE_FRAMEBUFFER_CREATE_INFO = 37,
+    VK_STRUCTURE_TYPE_RENDER_PASS_CREATE_INFO = 38,
+    VK_STRUCTURE_TYPE_COMMAND_POOL_CREATE_INFO = 39,
+    VK_STRUCTURE_TYPE_COMMAND_BUFFER_ALLOCATE_INFO = 40,
+    VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_INFO = 41,
+    VK_STRUCTURE_TYPE_COMMAND_BUFFER_BEGIN_INFO = 42,
+    VK_STRUCTURE_TYPE_RENDER_PASS_BEGIN_INFO = 43,
+    VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER = 44,
+    VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER = 45,
+    VK_STRUCTURE_TYPE_MEMORY_BARRIER = 46,
+    VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO = 47,
+    VK_STRUCTURE_TYPE_LOADER_DEVICE_CREATE_INFO = 48,
+    VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT = 1000011000,
+    VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR = 1000001000,
+    VK_STRUCTURE_TYPE_PRESENT_INFO_KHR = 1000001001,
+    VK_STRUCTURE_TYPE_DISPLAY_MODE_CREATE_INFO_KHR = 1000002000,
+    VK_STRUCTURE_TYPE_DISPLAY_SURFACE_CREATE_INFO_KHR = 1000002001,
+    VK_STRUCTURE_TYPE_DISPLAY_PRESENT_INFO_KHR = 1000003000,
+    VK_STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR = 1000004000,
+    VK_STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR = 1000005000,
+    VK_STRUCTURE_TYPE_WAYLAND_SURFACE_CREATE_INFO_KHR = 1000006000,
+    VK_STRUCTURE_TYPE_MIR_SURFACE_CREATE_INFO_KHR = 1000007000,
+    VK_STRUCTURE_TYPE_ANDROID_SURFACE_CREATE_INFO_KHR = 1000008000,
+    VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR = 1000009000,
+});
+
+vk_enum!(VkSystemAllocationScope: int32_t {
+    VK_SYSTEM_ALLOCATION_SCOPE_COMMAND = 0,
+    VK_SYSTEM_ALLOCATION_SCOPE_OBJECT = 1,
+    VK_SYSTEM_ALLOCATION_SCOPE_CACHE = 2,
+    VK_SYSTEM_ALLOCATION_SCOPE_DEVICE = 3,
+    VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE = 4,
+});
+
+vk_enum!(VkInternalAllocationType: int32_t {
+    VK_INTERNAL_ALLOCATION_TYPE_EXECUTABLE = 0,
+});
+
+pub type PFNvkAllocationFunction = extern fn(p_user_data: *mut c_void, size: size_t, alignment: size_t, allocation_scope: VkSystemAllocationScope) -> *mut c_void;
+
+pub type PFNvkReallocationFunction = extern fn(p_user_data: *mut c_void, p_original: *mut c_void, size: size_t, alignment: size_t, allocation_scope: VkSystemAllocationScope) -> *mut c_void;
+
+pub type PFNvkFreeFunction = extern fn(p_user_data: *mut c_void, p_memory: *mut c_void);
+
+pub type PFNvkInternalAllocationNotification = extern fn(p_user_data: *mut c_void, size: size_t, allocation_type: VkInternalAllocationType, allocation_scope: VkSystemAllocationScope);
+
+pub type PFNvkInternalFreeNotification = extern fn(p_user_data: *mut c_void, size: size_t, allocation_type: VkInternalAllocationType, allocation_scope: VkSystemAllocationScope);
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VkAllocationCallbacks {
+    pub p_user_data: *mut c_void,
+    pub pfn_allocation: PFNvkAllocationFunction,
+    pub pfn_reallocation: PFNvkReallocationFunction,
+    pub pfn_free: PFNvkFreeFunction,
+    pub pfn_internal_allocation: PFNvkInternalAllocationNotification,
+    pub pfn_internal_free: PFNvkInternalFreeNotification,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkBool32 {
+    False = 0,
+    True = 1,
+}
+
+impl Default for VkBool32 {
+    fn default() -> Self {
+        VkBool32::False
+    }
+}
+
+pub type VkDeviceSize = uint64_t;
+
+vk_enum!(VkCompareOp: int32_t {
+    VK_COMPARE_OP_NEVER = 0,
+    VK_COMPARE_OP_LESS = 1,
+    VK_COMPARE_OP_EQUAL = 2,
+    VK_COMPARE_OP_LESS_OR_EQUAL = 3,
+    VK_COMPARE_OP_GREATER = 4,
+    VK_COMPARE_OP_NOT_EQUAL = 5,
+    VK_COMPARE_OP_GREATER_OR_EQUAL = 6,
+    VK_COMPARE_OP_ALWAYS = 7,
+});
+
+vk_enum!(VkSharingMode: int32_t {
+    VK_SHARING_MODE_EXCLUSIVE = 0,
+    VK_SHARING_MODE_CONCURRENT = 1,
+});
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkPipelineStageFlags: VkFlags {
+        const VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT = 0x00000001,
+        const VK_PIPELINE_STAGE_DRAW_INDIRECT_BIT = 0x00000002,
+        const VK_PIPELINE_STAGE_VERTEX_INPUT_BIT = 0x00000004,
+        const VK_PIPELINE_STAGE_VERTEX_SHADER_BIT = 0x00000008,
+        const VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT = 0x00000010,
+        const VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT = 0x00000020,
+        const VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT = 0x00000040,
+        const VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT = 0x00000080,
+        const VK_PIPELINE_STAGE_EARLY_FRAGMENT_TESTS_BIT = 0x00000100,
+        const VK_PIPELINE_STAGE_LATE_FRAGMENT_TESTS_BIT = 0x00000200,
+        const VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT = 0x00000400,
+        const VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT = 0x00000800,
+        const VK_PIPELINE_STAGE_TRANSFER_BIT = 0x00001000,
+        const VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT = 0x00002000,
+        const VK_PIPELINE_STAGE_HOST_BIT = 0x00004000,
+        const VK_PIPELINE_STAGE_ALL_GRAPHICS_BIT = 0x00008000,
+        const VK_PIPELINE_STAGE_ALL_COMMANDS_BIT = 0x00010000,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkAccessFlags: VkFlags {
+        const VK_ACCESS_INDIRECT_COMMAND_READ_BIT = 0x00000001,
+        const VK_ACCESS_INDEX_READ_BIT = 0x00000002,
+        const VK_ACCESS_VERTEX_ATTRIBUTE_READ_BIT = 0x00000004,
+        const VK_ACCESS_UNIFORM_READ_BIT = 0x00000008,
+        const VK_ACCESS_INPUT_ATTACHMENT_READ_BIT = 0x00000010,
+        const VK_ACCESS_SHADER_READ_BIT = 0x00000020,
+        const VK_ACCESS_SHADER_WRITE_BIT = 0x00000040,
+        const VK_ACCESS_COLOR_ATTACHMENT_READ_BIT = 0x00000080,
+        const VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT = 0x00000100,
+        const VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_READ_BIT = 0x00000200,
+        const VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT = 0x00000400,
+        const VK_ACCESS_TRANSFER_READ_BIT = 0x00000800,
+        const VK_ACCESS_TRANSFER_WRITE_BIT = 0x00001000,
+        const VK_ACCESS_HOST_READ_BIT = 0x00002000,
+        const VK_ACCESS_HOST_WRITE_BIT = 0x00004000,
+        const VK_ACCESS_MEMORY_READ_BIT = 0x00008000,
+        const VK_ACCESS_MEMORY_WRITE_BIT = 0x00010000,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkDependencyFlags: VkFlags {
+        const VK_DEPENDENCY_BY_REGION_BIT = 0x00000001,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkSampleCountFlags: VkFlags {
+        const VK_SAMPLE_COUNT_1_BIT = 0x00000001,
+        const VK_SAMPLE_COUNT_2_BIT = 0x00000002,
+        const VK_SAMPLE_COUNT_4_BIT = 0x00000004,
+        const VK_SAMPLE_COUNT_8_BIT = 0x00000008,
+        const VK_SAMPLE_COUNT_16_BIT = 0x00000010,
+        const VK_SAMPLE_COUNT_32_BIT = 0x00000020,
+        const VK_SAMPLE_COUNT_64_BIT = 0x00000040,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkExtent3D {
+    pub width: uint32_t,
+    pub height: uint32_t,
+    pub depth: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkOffset2D {
+    pub x: int32_t,
+    pub y: int32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkExtent2D {
+    pub width: uint32_t,
+    pub height: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkRect2D {
+    pub offset: VkOffset2D,
+    pub extent: VkExtent2D,
+}
+
+pub type VkInstance = usize;
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkInstanceCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkApplicationInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub p_application_name: *const c_char,
+    pub application_version: uint32_t,
+    pub p_engine_name: *const c_char,
+    pub engine_version: uint32_t,
+    pub api_version: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkInstanceCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkInstanceCreateFlags,
+    pub p_application_info: *const VkApplicationInfo,
+    pub enabled_layer_count: uint32_t,
+    pub pp_enabled_layer_names: *const *const c_char,
+    pub enabled_extension_count: uint32_t,
+    pub pp_enabled_extension_names: *const *const c_char,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkQueueFamilyProperties {
+    pub queue_flags: VkQueueFlags,
+    pub queue_count: uint32_t,
+    pub timestamp_valid_bits: uint32_t,
+    pub min_image_transfer_granularity: VkExtent3D,
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkQueueFlags: VkFlags {
+        const VK_QUEUE_GRAPHICS_BIT = 0x00000001,
+        const VK_QUEUE_COMPUTE_BIT = 0x00000002,
+        const VK_QUEUE_TRANSFER_BIT = 0x00000004,
+        const VK_QUEUE_SPARSE_BINDING_BIT = 0x00000008,
+    }
+}
+
+pub type VkPhysicalDevice = usize;
+
+pub type PFNvkVoidFunction = extern fn();
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkLayerProperties {
+    pub layer_name: [c_char; VK_MAX_EXTENSION_NAME_SIZE as usize],
+    pub spec_version: uint32_t,
+    pub implementation_version: uint32_t,
+    pub description: [c_char; VK_MAX_DESCRIPTION_SIZE as usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkExtensionProperties {
+    pub extension_name: [c_char; VK_MAX_EXTENSION_NAME_SIZE as usize],
+    pub spec_version: uint32_t,
+}
+
+vk_enum!(VkPhysicalDeviceType: int32_t {
+    VK_PHYSICAL_DEVICE_TYPE_OTHER = 0,
+    VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU = 1,
+    VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU = 2,
+    VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU = 3,
+    VK_PHYSICAL_DEVICE_TYPE_CPU = 4,
+});
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkPhysicalDeviceLimits {
+    pub max_image_dimension1_d: uint32_t,
+    pub max_image_dimension2_d: uint32_t,
+    pub max_image_dimension3_d: uint32_t,
+    pub max_image_dimension_cube: uint32_t,
+    pub max_image_array_layers: uint32_t,
+    pub max_texel_buffer_elements: uint32_t,
+    pub max_uniform_buffer_range: uint32_t,
+    pub max_storage_buffer_range: uint32_t,
+    pub max_push_constants_size: uint32_t,
+    pub max_memory_allocation_count: uint32_t,
+    pub max_sampler_allocation_count: uint32_t,
+    pub buffer_image_granularity: VkDeviceSize,
+    pub sparse_address_space_size: VkDeviceSize,
+    pub max_bound_descriptor_sets: uint32_t,
+    pub max_per_stage_descriptor_samplers: uint32_t,
+    pub max_per_stage_descriptor_uniform_buffers: uint32_t,
+    pub max_per_stage_descriptor_storage_buffers: uint32_t,
+    pub max_per_stage_descriptor_sampled_images: uint32_t,
+    pub max_per_stage_descriptor_storage_images: uint32_t,
+    pub max_per_stage_descriptor_input_attachments: uint32_t,
+    pub max_per_stage_resources: uint32_t,
+    pub max_descriptor_set_samplers: uint32_t,
+    pub max_descriptor_set_uniform_buffers: uint32_t,
+    pub max_descriptor_set_uniform_buffers_dynamic: uint32_t,
+    pub max_descriptor_set_storage_buffers: uint32_t,
+    pub max_descriptor_set_storage_buffers_dynamic: uint32_t,
+    pub max_descriptor_set_sampled_images: uint32_t,
+    pub max_descriptor_set_storage_images: uint32_t,
+    pub max_descriptor_set_input_attachments: uint32_t,
+    pub max_vertex_input_attributes: uint32_t,
+    pub max_vertex_input_bindings: uint32_t,
+    pub max_vertex_input_attribute_offset: uint32_t,
+    pub max_vertex_input_binding_stride: uint32_t,
+    pub max_vertex_output_components: uint32_t,
+    pub max_tessellation_generation_level: uint32_t,
+    pub max_tessellation_patch_size: uint32_t,
+    pub max_tessellation_control_per_vertex_input_components: uint32_t,
+    pub max_tessellation_control_per_vertex_output_components: uint32_t,
+    pub max_tessellation_control_per_patch_output_components: uint32_t,
+    pub max_tessellation_control_total_output_components: uint32_t,
+    pub max_tessellation_evaluation_input_components: uint32_t,
+    pub max_tessellation_evaluation_output_components: uint32_t,
+    pub max_geometry_shader_invocations: uint32_t,
+    pub max_geometry_input_components: uint32_t,
+    pub max_geometry_output_components: uint32_t,
+    pub max_geometry_output_vertices: uint32_t,
+    pub max_geometry_total_output_components: uint32_t,
+    pub max_fragment_input_components: uint32_t,
+    pub max_fragment_output_attachments: uint32_t,
+    pub max_fragment_dual_src_attachments: uint32_t,
+    pub max_fragment_combined_output_resources: uint32_t,
+    pub max_compute_shared_memory_size: uint32_t,
+    pub max_compute_work_group_count: [uint32_t; 3],
+    pub max_compute_work_group_invocations: uint32_t,
+    pub max_compute_work_group_size: [uint32_t; 3],
+    pub sub_pixel_precision_bits: uint32_t,
+    pub sub_texel_precision_bits: uint32_t,
+    pub mipmap_precision_bits: uint32_t,
+    pub max_draw_indexed_index_value: uint32_t,
+    pub max_draw_indirect_count: uint32_t,
+    pub max_sampler_lod_bias: c_float,
+    pub max_sampler_anisotropy: c_float,
+    pub max_viewports: uint32_t,
+    pub max_viewport_dimensions: [uint32_t; 2],
+    pub viewport_bounds_range: [c_float; 2],
+    pub viewport_sub_pixel_bits: uint32_t,
+    pub min_memory_map_alignment: size_t,
+    pub min_texel_buffer_offset_alignment: VkDeviceSize,
+    pub min_uniform_buffer_offset_alignment: VkDeviceSize,
+    pub min_storage_buffer_offset_alignment: VkDeviceSize,
+    pub min_texel_offset: int32_t,
+    pub max_texel_offset: uint32_t,
+    pub min_texel_gather_offset: int32_t,
+    pub max_texel_gather_offset: uint32_t,
+    pub min_interpolation_offset: c_float,
+    pub max_interpolation_offset: c_float,
+    pub sub_pixel_interpolation_offset_bits: uint32_t,
+    pub max_framebuffer_width: uint32_t,
+    pub max_framebuffer_height: uint32_t,
+    pub max_framebuffer_layers: uint32_t,
+    pub framebuffer_color_sample_counts: VkSampleCountFlags,
+    pub framebuffer_depth_sample_counts: VkSampleCountFlags,
+    pub framebuffer_stencil_sample_counts: VkSampleCountFlags,
+    pub framebuffer_no_attachments_sample_counts: VkSampleCountFlags,
+    pub max_color_attachments: uint32_t,
+    pub sampled_image_color_sample_counts: VkSampleCountFlags,
+    pub sampled_image_integer_sample_counts: VkSampleCountFlags,
+    pub sampled_image_depth_sample_counts: VkSampleCountFlags,
+    pub sampled_image_stencil_sample_counts: VkSampleCountFlags,
+    pub storage_image_sample_counts: VkSampleCountFlags,
+    pub max_sample_mask_words: uint32_t,
+    pub timestamp_compute_and_graphics: VkBool32,
+    pub timestamp_period: c_float,
+    pub max_clip_distances: uint32_t,
+    pub max_cull_distances: uint32_t,
+    pub max_combined_clip_and_cull_distances: uint32_t,
+    pub discrete_queue_priorities: uint32_t,
+    pub point_size_range: [c_float; 2],
+    pub line_width_range: [c_float; 2],
+    pub point_size_granularity: c_float,
+    pub line_width_granularity: c_float,
+    pub strict_lines: VkBool32,
+    pub standard_sample_locations: VkBool32,
+    pub optimal_buffer_copy_offset_alignment: VkDeviceSize,
+    pub optimal_buffer_copy_row_pitch_alignment: VkDeviceSize,
+    pub non_coherent_atom_size: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkPhysicalDeviceSparseProperties {
+    pub residency_standard2_d_block_shape: VkBool32,
+    pub residency_standard2_d_multisample_block_shape: VkBool32,
+    pub residency_standard3_d_block_shape: VkBool32,
+    pub residency_aligned_mip_size: VkBool32,
+    pub residency_non_resident_strict: VkBool32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkPhysicalDeviceProperties {
+    pub api_version: uint32_t,
+    pub driver_version: uint32_t,
+    pub vendor_id: uint32_t,
+    pub device_id: uint32_t,
+    pub device_type: VkPhysicalDeviceType,
+    pub device_name: [c_char; VK_MAX_PHYSICAL_DEVICE_NAME_SIZE as usize],
+    pub pipeline_cache_uuid: [uint8_t; VK_UUID_SIZE as usize],
+    pub limits: VkPhysicalDeviceLimits,
+    pub sparse_properties: VkPhysicalDeviceSparseProperties,
+}
+
+pub type VkDevice = usize;
+
+pub type VkQueue = usize;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkPhysicalDeviceFeatures {
+    pub robust_buffer_access: VkBool32,
+    pub full_draw_index_uint32: VkBool32,
+    pub image_cube_array: VkBool32,
+    pub independent_blend: VkBool32,
+    pub geometry_shader: VkBool32,
+    pub tessellation_shader: VkBool32,
+    pub sample_rate_shading: VkBool32,
+    pub dual_src_blend: VkBool32,
+    pub logic_op: VkBool32,
+    pub multi_draw_indirect: VkBool32,
+    pub draw_indirect_first_instance: VkBool32,
+    pub depth_clamp: VkBool32,
+    pub depth_bias_clamp: VkBool32,
+    pub fill_mode_non_solid: VkBool32,
+    pub depth_bounds: VkBool32,
+    pub wide_lines: VkBool32,
+    pub large_points: VkBool32,
+    pub alpha_to_one: VkBool32,
+    pub multi_viewport: VkBool32,
+    pub sampler_anisotropy: VkBool32,
+    pub texture_compression_etc2: VkBool32,
+    pub texture_compression_astc_ldr: VkBool32,
+    pub texture_compression_bc: VkBool32,
+    pub occlusion_query_precise: VkBool32,
+    pub pipeline_statistics_query: VkBool32,
+    pub vertex_pipeline_stores_and_atomics: VkBool32,
+    pub fragment_stores_and_atomics: VkBool32,
+    pub shader_tessellation_and_geometry_point_size: VkBool32,
+    pub shader_image_gather_extended: VkBool32,
+    pub shader_storage_image_extended_formats: VkBool32,
+    pub shader_storage_image_multisample: VkBool32,
+    pub shader_storage_image_read_without_format: VkBool32,
+    pub shader_storage_image_write_without_format: VkBool32,
+    pub shader_uniform_buffer_array_dynamic_indexing: VkBool32,
+    pub shader_sampled_image_array_dynamic_indexing: VkBool32,
+    pub shader_storage_buffer_array_dynamic_indexing: VkBool32,
+    pub shader_storage_image_array_dynamic_indexing: VkBool32,
+    pub shader_clip_distance: VkBool32,
+    pub shader_cull_distance: VkBool32,
+    pub shader_float64: VkBool32,
+    pub shader_int64: VkBool32,
+    pub shader_int16: VkBool32,
+    pub shader_resource_residency: VkBool32,
+    pub shader_resource_min_lod: VkBool32,
+    pub sparse_binding: VkBool32,
+    pub sparse_residency_buffer: VkBool32,
+    pub sparse_residency_image2_d: VkBool32,
+    pub sparse_residency_image3_d: VkBool32,
+    pub sparse_residency2_samples: VkBool32,
+    pub sparse_residency4_samples: VkBool32,
+    pub sparse_residency8_samples: VkBool32,
+    pub sparse_residency16_samples: VkBool32,
+    pub sparse_residency_aliased: VkBool32,
+    pub variable_multisample_rate: VkBool32,
+    pub inherited_queries: VkBool32,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkDeviceQueueCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDeviceQueueCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkDeviceQueueCreateFlags,
+    pub queue_family_index: uint32_t,
+    pub queue_count: uint32_t,
+    pub p_queue_priorities: *const c_float,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkDeviceCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDeviceCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkDeviceCreateFlags,
+    pub queue_create_info_count: uint32_t,
+    pub p_queue_create_infos: *const VkDeviceQueueCreateInfo,
+    pub enabled_layer_count: uint32_t,
+    pub pp_enabled_layer_names: *const *const c_char,
+    pub enabled_extension_count: uint32_t,
+    pub pp_enabled_extension_names: *const *const c_char,
+    pub p_enabled_features: *const VkPhysicalDeviceFeatures,
+}
+
+pub type VkDebugReportCallbackEXT = usize;
+
+pub type PFNvkDebugReportCallbackEXT = extern fn(flags: VkDebugReportFlagsEXT, object_type: VkDebugReportObjectTypeEXT, object: uint64_t, location: size_t, message_code: int32_t, p_layer_prefix: *const c_char, p_message: *const c_char, p_user_data: *mut c_void) -> VkBool32;
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkDebugReportFlagsEXT: VkFlags {
+        const VK_DEBUG_REPORT_INFORMATION_BIT_EXT = 0x00000001,
+        const VK_DEBUG_REPORT_WARNING_BIT_EXT = 0x00000002,
+        const VK_DEBUG_REPORT_PERFORMANCE_WARNING_BIT_EXT = 0x00000004,
+        const VK_DEBUG_REPORT_ERROR_BIT_EXT = 0x00000008,
+        const VK_DEBUG_REPORT_DEBUG_BIT_EXT = 0x00000010,
+    }
+}
+
+vk_enum!(VkDebugReportObjectTypeEXT: int32_t {
+    VK_DEBUG_REPORT_OBJECT_TYPE_UNKNOWN_EXT = 0,
+    VK_DEBUG_REPORT_OBJECT_TYPE_INSTANCE_EXT = 1,
+    VK_DEBUG_REPORT_OBJECT_TYPE_PHYSICAL_DEVICE_EXT = 2,
+    VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT = 3,
+    VK_DEBUG_REPORT_OBJECT_TYPE_QUEUE_EXT = 4,
+    VK_DEBUG_REPORT_OBJECT_TYPE_SEMAPHORE_EXT = 5,
+    VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT = 6,
+    VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT = 7,
+    VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_MEMORY_EXT = 8,
+    VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT = 9,
+    VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT = 10,
+    VK_DEBUG_REPORT_OBJECT_TYPE_EVENT_EXT = 11,
+    VK_DEBUG_REPORT_OBJECT_TYPE_QUERY_POOL_EXT = 12,
+    VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_VIEW_EXT = 13,
+    VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_VIEW_EXT = 14,
+    VK_DEBUG_REPORT_OBJECT_TYPE_SHADER_MODULE_EXT = 15,
+    VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_CACHE_EXT = 16,
+    VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_LAYOUT_EXT = 17,
+    VK_DEBUG_REPORT_OBJECT_TYPE_RENDER_PASS_EXT = 18,
+    VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_EXT = 19,
+    VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_LAYOUT_EXT = 20,
+    VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_EXT = 21,
+    VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_POOL_EXT = 22,
+    VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_EXT = 23,
+    VK_DEBUG_REPORT_OBJECT_TYPE_FRAMEBUFFER_EXT = 24,
+    VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_POOL_EXT = 25,
+    VK_DEBUG_REPORT_OBJECT_TYPE_SURFACE_KHR_EXT = 26,
+    VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT = 27,
+    VK_DEBUG_REPORT_OBJECT_TYPE_DEBUG_REPORT_EXT = 28,
+});
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct VkDebugReportCallbackCreateInfoEXT {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkDebugReportFlagsEXT,
+    pub pfn_callback: PFNvkDebugReportCallbackEXT,
+    pub p_user_data: *mut c_void,
+}
+
+pub type VkCommandPool = usize;
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkCommandPoolCreateFlags: VkFlags {
+        const VK_COMMAND_POOL_CREATE_TRANSIENT_BIT = 0x00000001,
+        const VK_COMMAND_POOL_CREATE_RESET_COMMAND_BUFFER_BIT = 0x00000002,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkCommandPoolResetFlags: VkFlags {
+        const VK_COMMAND_POOL_RESET_RELEASE_RESOURCES_BIT = 0x00000001,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkCommandPoolCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkCommandPoolCreateFlags,
+    pub queue_family_index: uint32_t,
+}
+
+pub type VkCommandBuffer = usize;
+
+vk_enum!(VkCommandBufferLevel: int32_t {
+    VK_COMMAND_BUFFER_LEVEL_PRIMARY = 0,
+    VK_COMMAND_BUFFER_LEVEL_SECONDARY = 1,
+});
+
+vk_enum!(VkIndexType: int32_t {
+    VK_INDEX_TYPE_UINT16 = 0,
+    VK_INDEX_TYPE_UINT32 = 1,
+});
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkCommandBufferAllocateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub command_pool: VkCommandPool,
+    pub level: VkCommandBufferLevel,
+    pub command_buffer_count: uint32_t,
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkCommandBufferResetFlags: VkFlags {
+        const VK_COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT = 0x00000001,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkCommandBufferUsageFlags: VkFlags {
+        const VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT = 0x00000001,
+        const VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT = 0x00000002,
+        const VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT = 0x00000004,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkCommandBufferInheritanceInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub render_pass: VkRenderPass,
+    pub subpass: uint32_t,
+    pub framebuffer: VkFramebuffer,
+    pub occlusion_query_enable: VkBool32,
+    pub query_flags: VkQueryControlFlags,
+    pub pipeline_statistics: VkQueryPipelineStatisticFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkCommandBufferBeginInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkCommandBufferUsageFlags,
+    pub p_inheritance_info: *const VkCommandBufferInheritanceInfo,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkMemoryBarrier {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub src_access_mask: VkAccessFlags,
+    pub dst_access_mask: VkAccessFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkBufferMemoryBarrier {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub src_access_mask: VkAccessFlags,
+    pub dst_access_mask: VkAccessFlags,
+    pub src_queue_family_index: uint32_t,
+    pub dst_queue_family_index: uint32_t,
+    pub buffer: VkBuffer,
+    pub offset: VkDeviceSize,
+    pub size: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageMemoryBarrier {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub src_access_mask: VkAccessFlags,
+    pub dst_access_mask: VkAccessFlags,
+    pub old_layout: VkImageLayout,
+    pub new_layout: VkImageLayout,
+    pub src_queue_family_index: uint32_t,
+    pub dst_queue_family_index: uint32_t,
+    pub image: VkImage,
+    pub subresource_range: VkImageSubresourceRange,
+}
+
+pub type VkFence = usize;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkFenceCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkFenceCreateFlags,
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkFenceCreateFlags: VkFlags {
+        const VK_FENCE_CREATE_SIGNALED_BIT = 0x00000001,
+    }
+}
+
+pub type VkSemaphore = usize;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSemaphoreCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkSemaphoreCreateFlags,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkSemaphoreCreateFlags {
+    Reserved = 0,
+}
+
+pub type VkEvent = usize;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkEventCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkEventCreateFlags,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkEventCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSubmitInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub wait_semaphore_count: uint32_t,
+    pub p_wait_semaphores: *const VkSemaphore,
+    pub p_wait_dst_stage_mask: *const VkPipelineStageFlags,
+    pub command_buffer_count: uint32_t,
+    pub p_command_buffers: *const VkCommandBuffer,
+    pub signal_semaphore_count: uint32_t,
+    pub p_signal_semaphores: *const VkSemaphore,
+}
+
+pub type VkRenderPass = usize;
+
+pub type VkFramebuffer = usize;
+
+vk_enum!(VkAttachmentLoadOp: int32_t {
+    VK_ATTACHMENT_LOAD_OP_LOAD = 0,
+    VK_ATTACHMENT_LOAD_OP_CLEAR = 1,
+    VK_ATTACHMENT_LOAD_OP_DONT_CARE = 2,
+});
+
+vk_enum!(VkAttachmentStoreOp: int32_t {
+    VK_ATTACHMENT_STORE_OP_STORE = 0,
+    VK_ATTACHMENT_STORE_OP_DONT_CARE = 1,
+});
+
+vk_enum!(VkSubpassContents: int32_t {
+    VK_SUBPASS_CONTENTS_INLINE = 0,
+    VK_SUBPASS_CONTENTS_SECONDARY_COMMAND_BUFFERS = 1,
+});
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkAttachmentDescriptionFlags: VkFlags {
+        const VK_ATTACHMENT_DESCRIPTION_MAY_ALIAS_BIT = 0x00000001,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkAttachmentDescription {
+    pub flags: VkAttachmentDescriptionFlags,
+    pub format: VkFormat,
+    pub samples: VkSampleCountFlags,
+    pub load_op: VkAttachmentLoadOp,
+    pub store_op: VkAttachmentStoreOp,
+    pub stencil_load_op: VkAttachmentLoadOp,
+    pub stencil_store_op: VkAttachmentStoreOp,
+    pub initial_layout: VkImageLayout,
+    pub final_layout: VkImageLayout,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkAttachmentReference {
+    pub attachment: uint32_t,
+    pub layout: VkImageLayout,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkSubpassDescriptionFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSubpassDescription {
+    pub flags: VkSubpassDescriptionFlags,
+    pub pipeline_bind_point: VkPipelineBindPoint,
+    pub input_attachment_count: uint32_t,
+    pub p_input_attachments: *const VkAttachmentReference,
+    pub color_attachment_count: uint32_t,
+    pub p_color_attachments: *const VkAttachmentReference,
+    pub p_resolve_attachments: *const VkAttachmentReference,
+    pub p_depth_stencil_attachment: *const VkAttachmentReference,
+    pub preserve_attachment_count: uint32_t,
+    pub p_preserve_attachments: *const uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSubpassDependency {
+    pub src_subpass: uint32_t,
+    pub dst_subpass: uint32_t,
+    pub src_stage_mask: VkPipelineStageFlags,
+    pub dst_stage_mask: VkPipelineStageFlags,
+    pub src_access_mask: VkAccessFlags,
+    pub dst_access_mask: VkAccessFlags,
+    pub dependency_flags: VkDependencyFlags,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkRenderPassCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkRenderPassCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkRenderPassCreateFlags,
+    pub attachment_count: uint32_t,
+    pub p_attachments: *const VkAttachmentDescription,
+    pub subpass_count: uint32_t,
+    pub p_subpasses: *const VkSubpassDescription,
+    pub dependency_count: uint32_t,
+    pub p_dependencies: *const VkSubpassDependency,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkFramebufferCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkFramebufferCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkFramebufferCreateFlags,
+    pub render_pass: VkRenderPass,
+    pub attachment_count: uint32_t,
+    pub p_attachments: *const VkImageView,
+    pub width: uint32_t,
+    pub height: uint32_t,
+    pub layers: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union VkClearColorValue {
+    pub float32: [c_float; 4],
+    pub int32: [int32_t; 4],
+    pub uint32: [uint32_t; 4],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VkClearDepthStencilValue {
+    pub depth: c_float,
+    pub stencil: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union VkClearValue {
+    pub color: VkClearColorValue,
+    pub depth_stencil: VkClearDepthStencilValue,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkRenderPassBeginInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub render_pass: VkRenderPass,
+    pub framebuffer: VkFramebuffer,
+    pub render_area: VkRect2D,
+    pub clear_value_count: uint32_t,
+    pub p_clear_values: *const VkClearValue,
+}
+
+pub type VkQueryPool = usize;
+
+vk_enum!(VkQueryType: int32_t {
+    VK_QUERY_TYPE_OCCLUSION = 0,
+    VK_QUERY_TYPE_PIPELINE_STATISTICS = 1,
+    VK_QUERY_TYPE_TIMESTAMP = 2,
+});
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkQueryControlFlags: VkFlags {
+        const VK_QUERY_CONTROL_PRECISE_BIT = 0x00000001,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkQueryPipelineStatisticFlags: VkFlags {
+        const VK_QUERY_PIPELINE_STATISTIC_INPUT_ASSEMBLY_VERTICES_BIT = 0x00000001,
+        const VK_QUERY_PIPELINE_STATISTIC_INPUT_ASSEMBLY_PRIMITIVES_BIT = 0x00000002,
+        const VK_QUERY_PIPELINE_STATISTIC_VERTEX_SHADER_INVOCATIONS_BIT = 0x00000004,
+        const VK_QUERY_PIPELINE_STATISTIC_GEOMETRY_SHADER_INVOCATIONS_BIT = 0x00000008,
+        const VK_QUERY_PIPELINE_STATISTIC_GEOMETRY_SHADER_PRIMITIVES_BIT = 0x00000010,
+        const VK_QUERY_PIPELINE_STATISTIC_CLIPPING_INVOCATIONS_BIT = 0x00000020,
+        const VK_QUERY_PIPELINE_STATISTIC_CLIPPING_PRIMITIVES_BIT = 0x00000040,
+        const VK_QUERY_PIPELINE_STATISTIC_FRAGMENT_SHADER_INVOCATIONS_BIT = 0x00000080,
+        const VK_QUERY_PIPELINE_STATISTIC_TESSELLATION_CONTROL_SHADER_PATCHES_BIT = 0x00000100,
+        const VK_QUERY_PIPELINE_STATISTIC_TESSELLATION_EVALUATION_SHADER_INVOCATIONS_BIT = 0x00000200,
+        const VK_QUERY_PIPELINE_STATISTIC_COMPUTE_SHADER_INVOCATIONS_BIT = 0x00000400,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkQueryResultFlags: VkFlags {
+        const VK_QUERY_RESULT_64_BIT = 0x00000001,
+        const VK_QUERY_RESULT_WAIT_BIT = 0x00000002,
+        const VK_QUERY_RESULT_WITH_AVAILABILITY_BIT = 0x00000004,
+        const VK_QUERY_RESULT_PARTIAL_BIT = 0x00000008,
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkQueryPoolCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkQueryPoolCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkQueryPoolCreateFlags,
+    pub query_type: VkQueryType,
+    pub query_count: uint32_t,
+    pub pipeline_statistics: VkQueryPipelineStatisticFlags,
+}
+
+pub type VkDeviceMemory = usize;
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkMemoryPropertyFlags: VkFlags {
+        const VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT = 0x00000001,
+        const VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT = 0x00000002,
+        const VK_MEMORY_PROPERTY_HOST_COHERENT_BIT = 0x00000004,
+        const VK_MEMORY_PROPERTY_HOST_CACHED_BIT = 0x00000008,
+        const VK_MEMORY_PROPERTY_LAZILY_ALLOCATED_BIT = 0x00000010,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkMemoryHeapFlags: VkFlags {
+        const VK_MEMORY_HEAP_DEVICE_LOCAL_BIT = 0x00000001,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkMemoryType {
+    pub property_flags: VkMemoryPropertyFlags,
+    pub heap_index: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkMemoryHeap {
+    pub size: VkDeviceSize,
+    pub flags: VkMemoryHeapFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPhysicalDeviceMemoryProperties {
+    pub memory_type_count: uint32_t,
+    pub memory_types: [VkMemoryType; VK_MAX_MEMORY_TYPES as usize],
+    pub memory_heap_count: uint32_t,
+    pub memory_heaps: [VkMemoryHeap; VK_MAX_MEMORY_HEAPS as usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkMemoryAllocateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub allocation_size: VkDeviceSize,
+    pub memory_type_index: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkMappedMemoryRange {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub memory: VkDeviceMemory,
+    pub offset: VkDeviceSize,
+    pub size: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkMemoryRequirements {
+    pub size: VkDeviceSize,
+    pub alignment: VkDeviceSize,
+    pub memory_type_bits: uint32_t,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkMemoryMapFlags {
+    Reserved = 0,
+}
+
+pub type VkBuffer = usize;
+
+pub type VkBufferView = usize;
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkBufferCreateFlags: VkFlags {
+        const VK_BUFFER_CREATE_SPARSE_BINDING_BIT = 0x00000001,
+        const VK_BUFFER_CREATE_SPARSE_RESIDENCY_BIT = 0x00000002,
+        const VK_BUFFER_CREATE_SPARSE_ALIASED_BIT = 0x00000004,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkBufferUsageFlags: VkFlags {
+        const VK_BUFFER_USAGE_TRANSFER_SRC_BIT = 0x00000001,
+        const VK_BUFFER_USAGE_TRANSFER_DST_BIT = 0x00000002,
+        const VK_BUFFER_USAGE_UNIFORM_TEXEL_BUFFER_BIT = 0x00000004,
+        const VK_BUFFER_USAGE_STORAGE_TEXEL_BUFFER_BIT = 0x00000008,
+        const VK_BUFFER_USAGE_UNIFORM_BUFFER_BIT = 0x00000010,
+        const VK_BUFFER_USAGE_STORAGE_BUFFER_BIT = 0x00000020,
+        const VK_BUFFER_USAGE_INDEX_BUFFER_BIT = 0x00000040,
+        const VK_BUFFER_USAGE_VERTEX_BUFFER_BIT = 0x00000080,
+        const VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT = 0x00000100,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkBufferCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkBufferCreateFlags,
+    pub size: VkDeviceSize,
+    pub usage: VkBufferUsageFlags,
+    pub sharing_mode: VkSharingMode,
+    pub queue_family_index_count: uint32_t,
+    pub p_queue_family_indices: *const uint32_t,
+}
+
+pub type VkImage = usize;
+
+pub type VkImageView = usize;
+
+vk_enum!(VkFormat: int32_t {
+    VK_FORMAT_UNDEFINED = 0,
+    VK_FORMAT_R4G4_UNORM_PACK8 = 1,
+    VK_FORMAT_R4G4B4A4_UNORM_PACK16 = 2,
+    VK_FORMAT_B4G4R4A4_UNORM_PACK16 = 3,
+    VK_FORMAT_R5G6B5_UNORM_PACK16 = 4,
+    VK_FORMAT_B5G6R5_UNORM_PACK16 = 5,
+    VK_FORMAT_R5G5B5A1_UNORM_PACK16 = 6,
+    VK_FORMAT_B5G5R5A1_UNORM_PACK16 = 7,
+    VK_FORMAT_A1R5G5B5_UNORM_PACK16 = 8,
+    VK_FORMAT_R8_UNORM = 9,
+    VK_FORMAT_R8_SNORM = 10,
+    VK_FORMAT_R8_USCALED = 11,
+    VK_FORMAT_R8_SSCALED = 12,
+    VK_FORMAT_R8_UINT = 13,
+    VK_FORMAT_R8_SINT = 14,
+    VK_FORMAT_R8_SRGB = 15,
+    VK_FORMAT_R8G8_UNORM = 16,
+    VK_FORMAT_R8G8_SNORM = 17,
+    VK_FORMAT_R8G8_USCALED = 18,
+    VK_FORMAT_R8G8_SSCALED = 19,
+    VK_FORMAT_R8G8_UINT = 20,
+    VK_FORMAT_R8G8_SINT = 21,
+    VK_FORMAT_R8G8_SRGB = 22,
+    VK_FORMAT_R8G8B8_UNORM = 23,
+    VK_FORMAT_R8G8B8_SNORM = 24,
+    VK_FORMAT_R8G8B8_USCALED = 25,
+    VK_FORMAT_R8G8B8_SSCALED = 26,
+    VK_FORMAT_R8G8B8_UINT = 27,
+    VK_FORMAT_R8G8B8_SINT = 28,
+    VK_FORMAT_R8G8B8_SRGB = 29,
+    VK_FORMAT_B8G8R8_UNORM = 30,
+    VK_FORMAT_B8G8R8_SNORM = 31,
+    VK_FORMAT_B8G8R8_USCALED = 32,
+    VK_FORMAT_B8G8R8_SSCALED = 33,
+    VK_FORMAT_B8G8R8_UINT = 34,
+    VK_FORMAT_B8G8R8_SINT = 35,
+    VK_FORMAT_B8G8R8_SRGB = 36,
+    VK_FORMAT_R8G8B8A8_UNORM = 37,
+    VK_FORMAT_R8G8B8A8_SNORM = 38,
+    VK_FORMAT_R8G8B8A8_USCALED = 39,
+    VK_FORMAT_R8G8B8A8_SSCALED = 40,
+    VK_FORMAT_R8G8B8A8_UINT = 41,
+    VK_FORMAT_R8G8B8A8_SINT = 42,
+    VK_FORMAT_R8G8B8A8_SRGB = 43,
+    VK_FORMAT_B8G8R8A8_UNORM = 44,
+    VK_FORMAT_B8G8R8A8_SNORM = 45,
+    VK_FORMAT_B8G8R8A8_USCALED = 46,
+    VK_FORMAT_B8G8R8A8_SSCALED = 47,
+    VK_FORMAT_B8G8R8A8_UINT = 48,
+    VK_FORMAT_B8G8R8A8_SINT = 49,
+    VK_FORMAT_B8G8R8A8_SRGB = 50,
+    VK_FORMAT_A8B8G8R8_UNORM_PACK32 = 51,
+    VK_FORMAT_A8B8G8R8_SNORM_PACK32 = 52,
+    VK_FORMAT_A8B8G8R8_USCALED_PACK32 = 53,
+    VK_FORMAT_A8B8G8R8_SSCALED_PACK32 = 54,
+    VK_FORMAT_A8B8G8R8_UINT_PACK32 = 55,
+    VK_FORMAT_A8B8G8R8_SINT_PACK32 = 56,
+    VK_FORMAT_A8B8G8R8_SRGB_PACK32 = 57,
+    VK_FORMAT_A2R10G10B10_UNORM_PACK32 = 58,
+    VK_FORMAT_A2R10G10B10_SNORM_PACK32 = 59,
+    VK_FORMAT_A2R10G10B10_USCALED_PACK32 = 60,
+    VK_FORMAT_A2R10G10B10_SSCALED_PACK32 = 61,
+    VK_FORMAT_A2R10G10B10_UINT_PACK32 = 62,
+    VK_FORMAT_A2R10G10B10_SINT_PACK32 = 63,
+    VK_FORMAT_A2B10G10R10_UNORM_PACK32 = 64,
+    VK_FORMAT_A2B10G10R10_SNORM_PACK32 = 65,
+    VK_FORMAT_A2B10G10R10_USCALED_PACK32 = 66,
+    VK_FORMAT_A2B10G10R10_SSCALED_PACK32 = 67,
+    VK_FORMAT_A2B10G10R10_UINT_PACK32 = 68,
+    VK_FORMAT_A2B10G10R10_SINT_PACK32 = 69,
+    VK_FORMAT_R16_UNORM = 70,
+    VK_FORMAT_R16_SNORM = 71,
+    VK_FORMAT_R16_USCALED = 72,
+    VK_FORMAT_R16_SSCALED = 73,
+    VK_FORMAT_R16_UINT = 74,
+    VK_FORMAT_R16_SINT = 75,
+    VK_FORMAT_R16_SFLOAT = 76,
+    VK_FORMAT_R16G16_UNORM = 77,
+    VK_FORMAT_R16G16_SNORM = 78,
+    VK_FORMAT_R16G16_USCALED = 79,
+    VK_FORMAT_R16G16_SSCALED = 80,
+    VK_FORMAT_R16G16_UINT = 81,
+    VK_FORMAT_R16G16_SINT = 82,
+    VK_FORMAT_R16G16_SFLOAT = 83,
+    VK_FORMAT_R16G16B16_UNORM = 84,
+    VK_FORMAT_R16G16B16_SNORM = 85,
+    VK_FORMAT_R16G16B16_USCALED = 86,
+    VK_FORMAT_R16G16B16_SSCALED = 87,
+    VK_FORMAT_R16G16B16_UINT = 88,
+    VK_FORMAT_R16G16B16_SINT = 89,
+    VK_FORMAT_R16G16B16_SFLOAT = 90,
+    VK_FORMAT_R16G16B16A16_UNORM = 91,
+    VK_FORMAT_R16G16B16A16_SNORM = 92,
+    VK_FORMAT_R16G16B16A16_USCALED = 93,
+    VK_FORMAT_R16G16B16A16_SSCALED = 94,
+    VK_FORMAT_R16G16B16A16_UINT = 95,
+    VK_FORMAT_R16G16B16A16_SINT = 96,
+    VK_FORMAT_R16G16B16A16_SFLOAT = 97,
+    VK_FORMAT_R32_UINT = 98,
+    VK_FORMAT_R32_SINT = 99,
+    VK_FORMAT_R32_SFLOAT = 100,
+    VK_FORMAT_R32G32_UINT = 101,
+    VK_FORMAT_R32G32_SINT = 102,
+    VK_FORMAT_R32G32_SFLOAT = 103,
+    VK_FORMAT_R32G32B32_UINT = 104,
+    VK_FORMAT_R32G32B32_SINT = 105,
+    VK_FORMAT_R32G32B32_SFLOAT = 106,
+    VK_FORMAT_R32G32B32A32_UINT = 107,
+    VK_FORMAT_R32G32B32A32_SINT = 108,
+    VK_FORMAT_R32G32B32A32_SFLOAT = 109,
+    VK_FORMAT_R64_UINT = 110,
+    VK_FORMAT_R64_SINT = 111,
+    VK_FORMAT_R64_SFLOAT = 112,
+    VK_FORMAT_R64G64_UINT = 113,
+    VK_FORMAT_R64G64_SINT = 114,
+    VK_FORMAT_R64G64_SFLOAT = 115,
+    VK_FORMAT_R64G64B64_UINT = 116,
+    VK_FORMAT_R64G64B64_SINT = 117,
+    VK_FORMAT_R64G64B64_SFLOAT = 118,
+    VK_FORMAT_R64G64B64A64_UINT = 119,
+    VK_FORMAT_R64G64B64A64_SINT = 120,
+    VK_FORMAT_R64G64B64A64_SFLOAT = 121,
+    VK_FORMAT_B10G11R11_UFLOAT_PACK32 = 122,
+    VK_FORMAT_E5B9G9R9_UFLOAT_PACK32 = 123,
+    VK_FORMAT_D16_UNORM = 124,
+    VK_FORMAT_X8_D24_UNORM_PACK32 = 125,
+    VK_FORMAT_D32_SFLOAT = 126,
+    VK_FORMAT_S8_UINT = 127,
+    VK_FORMAT_D16_UNORM_S8_UINT = 128,
+    VK_FORMAT_D24_UNORM_S8_UINT = 129,
+    VK_FORMAT_D32_SFLOAT_S8_UINT = 130,
+    VK_FORMAT_BC1_RGB_UNORM_BLOCK = 131,
+    VK_FORMAT_BC1_RGB_SRGB_BLOCK = 132,
+    VK_FORMAT_BC1_RGBA_UNORM_BLOCK = 133,
+    VK_FORMAT_BC1_RGBA_SRGB_BLOCK = 134,
+    VK_FORMAT_BC2_UNORM_BLOCK = 135,
+    VK_FORMAT_BC2_SRGB_BLOCK = 136,
+    VK_FORMAT_BC3_UNORM_BLOCK = 137,
+    VK_FORMAT_BC3_SRGB_BLOCK = 138,
+    VK_FORMAT_BC4_UNORM_BLOCK = 139,
+    VK_FORMAT_BC4_SNORM_BLOCK = 140,
+    VK_FORMAT_BC5_UNORM_BLOCK = 141,
+    VK_FORMAT_BC5_SNORM_BLOCK = 142,
+    VK_FORMAT_BC6H_UFLOAT_BLOCK = 143,
+    VK_FORMAT_BC6H_SFLOAT_BLOCK = 144,
+    VK_FORMAT_BC7_UNORM_BLOCK = 145,
+    VK_FORMAT_BC7_SRGB_BLOCK = 146,
+    VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK = 147,
+    VK_FORMAT_ETC2_R8G8B8_SRGB_BLOCK = 148,
+    VK_FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK = 149,
+    VK_FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK = 150,
+    VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK = 151,
+    VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK = 152,
+    VK_FORMAT_EAC_R11_UNORM_BLOCK = 153,
+    VK_FORMAT_EAC_R11_SNORM_BLOCK = 154,
+    VK_FORMAT_EAC_R11G11_UNORM_BLOCK = 155,
+    VK_FORMAT_EAC_R11G11_SNORM_BLOCK = 156,
+    VK_FORMAT_ASTC_4x4_UNORM_BLOCK = 157,
+    VK_FORMAT_ASTC_4x4_SRGB_BLOCK = 158,
+    VK_FORMAT_ASTC_5x4_UNORM_BLOCK = 159,
+    VK_FORMAT_ASTC_5x4_SRGB_BLOCK = 160,
+    VK_FORMAT_ASTC_5x5_UNORM_BLOCK = 161,
+    VK_FORMAT_ASTC_5x5_SRGB_BLOCK = 162,
+    VK_FORMAT_ASTC_6x5_UNORM_BLOCK = 163,
+    VK_FORMAT_ASTC_6x5_SRGB_BLOCK = 164,
+    VK_FORMAT_ASTC_6x6_UNORM_BLOCK = 165,
+    VK_FORMAT_ASTC_6x6_SRGB_BLOCK = 166,
+    VK_FORMAT_ASTC_8x5_UNORM_BLOCK = 167,
+    VK_FORMAT_ASTC_8x5_SRGB_BLOCK = 168,
+    VK_FORMAT_ASTC_8x6_UNORM_BLOCK = 169,
+    VK_FORMAT_ASTC_8x6_SRGB_BLOCK = 170,
+    VK_FORMAT_ASTC_8x8_UNORM_BLOCK = 171,
+    VK_FORMAT_ASTC_8x8_SRGB_BLOCK = 172,
+    VK_FORMAT_ASTC_10x5_UNORM_BLOCK = 173,
+    VK_FORMAT_ASTC_10x5_SRGB_BLOCK = 174,
+    VK_FORMAT_ASTC_10x6_UNORM_BLOCK = 175,
+    VK_FORMAT_ASTC_10x6_SRGB_BLOCK = 176,
+    VK_FORMAT_ASTC_10x8_UNORM_BLOCK = 177,
+    VK_FORMAT_ASTC_10x8_SRGB_BLOCK = 178,
+    VK_FORMAT_ASTC_10x10_UNORM_BLOCK = 179,
+    VK_FORMAT_ASTC_10x10_SRGB_BLOCK = 180,
+    VK_FORMAT_ASTC_12x10_UNORM_BLOCK = 181,
+    VK_FORMAT_ASTC_12x10_SRGB_BLOCK = 182,
+    VK_FORMAT_ASTC_12x12_UNORM_BLOCK = 183,
+    VK_FORMAT_ASTC_12x12_SRGB_BLOCK = 184,
+});
+
+vk_enum!(VkImageType: int32_t {
+    VK_IMAGE_TYPE_1D = 0,
+    VK_IMAGE_TYPE_2D = 1,
+    VK_IMAGE_TYPE_3D = 2,
+});
+
+vk_enum!(VkImageTiling: int32_t {
+    VK_IMAGE_TILING_OPTIMAL = 0,
+    VK_IMAGE_TILING_LINEAR = 1,
+});
+
+vk_enum!(VkImageLayout: int32_t {
+    VK_IMAGE_LAYOUT_UNDEFINED = 0,
+    VK_IMAGE_LAYOUT_GENERAL = 1,
+    VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL = 2,
+    VK_IMAGE_LAYOUT_DEPTH_STENCIL_ATTACHMENT_OPTIMAL = 3,
+    VK_IMAGE_LAYOUT_DEPTH_STENCIL_READ_ONLY_OPTIMAL = 4,
+    VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL = 5,
+    VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL = 6,
+    VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL = 7,
+    VK_IMAGE_LAYOUT_PREINITIALIZED = 8,
+    VK_IMAGE_LAYOUT_PRESENT_SRC_KHR = 1000001002,
+});
+
+vk_enum!(VkImageViewType: int32_t {
+    VK_IMAGE_VIEW_TYPE_1D = 0,
+    VK_IMAGE_VIEW_TYPE_2D = 1,
+    VK_IMAGE_VIEW_TYPE_3D = 2,
+    VK_IMAGE_VIEW_TYPE_CUBE = 3,
+    VK_IMAGE_VIEW_TYPE_1D_ARRAY = 4,
+    VK_IMAGE_VIEW_TYPE_2D_ARRAY = 5,
+    VK_IMAGE_VIEW_TYPE_CUBE_ARRAY = 6,
+});
+
+vk_enum!(VkComponentSwizzle: int32_t {
+    VK_COMPONENT_SWIZZLE_IDENTITY = 0,
+    VK_COMPONENT_SWIZZLE_ZERO = 1,
+    VK_COMPONENT_SWIZZLE_ONE = 2,
+    VK_COMPONENT_SWIZZLE_R = 3,
+    VK_COMPONENT_SWIZZLE_G = 4,
+    VK_COMPONENT_SWIZZLE_B = 5,
+    VK_COMPONENT_SWIZZLE_A = 6,
+});
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkImageCreateFlags: VkFlags {
+        const VK_IMAGE_CREATE_SPARSE_BINDING_BIT = 0x00000001,
+        const VK_IMAGE_CREATE_SPARSE_RESIDENCY_BIT = 0x00000002,
+        const VK_IMAGE_CREATE_SPARSE_ALIASED_BIT = 0x00000004,
+        const VK_IMAGE_CREATE_MUTABLE_FORMAT_BIT = 0x00000008,
+        const VK_IMAGE_CREATE_CUBE_COMPATIBLE_BIT = 0x00000010,
+        const VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT_KHR = 0x00000020,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkImageUsageFlags: VkFlags {
+        const VK_IMAGE_USAGE_TRANSFER_SRC_BIT = 0x00000001,
+        const VK_IMAGE_USAGE_TRANSFER_DST_BIT = 0x00000002,
+        const VK_IMAGE_USAGE_SAMPLED_BIT = 0x00000004,
+        const VK_IMAGE_USAGE_STORAGE_BIT = 0x00000008,
+        const VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT = 0x00000010,
+        const VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT = 0x00000020,
+        const VK_IMAGE_USAGE_TRANSIENT_ATTACHMENT_BIT = 0x00000040,
+        const VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT = 0x00000080,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkImageAspectFlags: VkFlags {
+        const VK_IMAGE_ASPECT_COLOR_BIT = 0x00000001,
+        const VK_IMAGE_ASPECT_DEPTH_BIT = 0x00000002,
+        const VK_IMAGE_ASPECT_STENCIL_BIT = 0x00000004,
+        const VK_IMAGE_ASPECT_METADATA_BIT = 0x00000008,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkFormatFeatureFlags: VkFlags {
+        const VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT = 0x00000001,
+        const VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT = 0x00000002,
+        const VK_FORMAT_FEATURE_STORAGE_IMAGE_ATOMIC_BIT = 0x00000004,
+        const VK_FORMAT_FEATURE_UNIFORM_TEXEL_BUFFER_BIT = 0x00000008,
+        const VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_BIT = 0x00000010,
+        const VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_ATOMIC_BIT = 0x00000020,
+        const VK_FORMAT_FEATURE_VERTEX_BUFFER_BIT = 0x00000040,
+        const VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT = 0x00000080,
+        const VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BLEND_BIT = 0x00000100,
+        const VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT = 0x00000200,
+        const VK_FORMAT_FEATURE_BLIT_SRC_BIT = 0x00000400,
+        const VK_FORMAT_FEATURE_BLIT_DST_BIT = 0x00000800,
+        const VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT = 0x00001000,
+        const VK_FORMAT_FEATURE_TRANSFER_SRC_BIT_KHR = 0x00004000,
+        const VK_FORMAT_FEATURE_TRANSFER_DST_BIT_KHR = 0x00008000,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkFormatProperties {
+    pub linear_tiling_features: VkFormatFeatureFlags,
+    pub optimal_tiling_features: VkFormatFeatureFlags,
+    pub buffer_features: VkFormatFeatureFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageFormatProperties {
+    pub max_extent: VkExtent3D,
+    pub max_mip_levels: uint32_t,
+    pub max_array_layers: uint32_t,
+    pub sample_counts: VkSampleCountFlags,
+    pub max_resource_size: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkImageCreateFlags,
+    pub image_type: VkImageType,
+    pub format: VkFormat,
+    pub extent: VkExtent3D,
+    pub mip_levels: uint32_t,
+    pub array_layers: uint32_t,
+    pub samples: VkSampleCountFlags,
+    pub tiling: VkImageTiling,
+    pub usage: VkImageUsageFlags,
+    pub sharing_mode: VkSharingMode,
+    pub queue_family_index_count: uint32_t,
+    pub p_queue_family_indices: *const uint32_t,
+    pub initial_layout: VkImageLayout,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkComponentMapping {
+    pub r: VkComponentSwizzle,
+    pub g: VkComponentSwizzle,
+    pub b: VkComponentSwizzle,
+    pub a: VkComponentSwizzle,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageSubresourceRange {
+    pub aspect_mask: VkImageAspectFlags,
+    pub base_mip_level: uint32_t,
+    pub level_count: uint32_t,
+    pub base_array_layer: uint32_t,
+    pub layer_count: uint32_t,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkImageViewCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageViewCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkImageViewCreateFlags,
+    pub image: VkImage,
+    pub view_type: VkImageViewType,
+    pub format: VkFormat,
+    pub components: VkComponentMapping,
+    pub subresource_range: VkImageSubresourceRange,
+}
+
+pub type VkSampler = usize;
+
+vk_enum!(VkFilter: int32_t {
+    VK_FILTER_NEAREST = 0,
+    VK_FILTER_LINEAR = 1,
+});
+
+vk_enum!(VkSamplerMipmapMode: int32_t {
+    VK_SAMPLER_MIPMAP_MODE_NEAREST = 0,
+    VK_SAMPLER_MIPMAP_MODE_LINEAR = 1,
+});
+
+vk_enum!(VkSamplerAddressMode: int32_t {
+    VK_SAMPLER_ADDRESS_MODE_REPEAT = 0,
+    VK_SAMPLER_ADDRESS_MODE_MIRRORED_REPEAT = 1,
+    VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_EDGE = 2,
+    VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_BORDER = 3,
+    VK_SAMPLER_ADDRESS_MODE_MIRROR_CLAMP_TO_EDGE = 4,
+});
+
+vk_enum!(VkBorderColor: int32_t {
+    VK_BORDER_COLOR_FLOAT_TRANSPARENT_BLACK = 0,
+    VK_BORDER_COLOR_INT_TRANSPARENT_BLACK = 1,
+    VK_BORDER_COLOR_FLOAT_OPAQUE_BLACK = 2,
+    VK_BORDER_COLOR_INT_OPAQUE_BLACK = 3,
+    VK_BORDER_COLOR_FLOAT_OPAQUE_WHITE = 4,
+    VK_BORDER_COLOR_INT_OPAQUE_WHITE = 5,
+});
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkSamplerCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkSamplerCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkSamplerCreateFlags,
+    pub mag_filter: VkFilter,
+    pub min_filter: VkFilter,
+    pub mipmap_mode: VkSamplerMipmapMode,
+    pub address_mode_u: VkSamplerAddressMode,
+    pub address_mode_v: VkSamplerAddressMode,
+    pub address_mode_w: VkSamplerAddressMode,
+    pub mip_lod_bias: c_float,
+    pub anisotropy_enable: VkBool32,
+    pub max_anisotropy: c_float,
+    pub compare_enable: VkBool32,
+    pub compare_op: VkCompareOp,
+    pub min_lod: c_float,
+    pub max_lod: c_float,
+    pub border_color: VkBorderColor,
+    pub unnormalized_coordinates: VkBool32,
+}
+
+pub type VkShaderModule = usize;
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkShaderModuleCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkShaderModuleCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkShaderModuleCreateFlags,
+    pub code_size: size_t,
+    pub p_code: *const uint32_t,
+}
+
+pub type VkDescriptorSetLayout = usize;
+
+pub type VkDescriptorPool = usize;
+
+pub type VkDescriptorSet = usize;
+
+vk_enum!(VkDescriptorType: int32_t {
+    VK_DESCRIPTOR_TYPE_SAMPLER = 0,
+    VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER = 1,
+    VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE = 2,
+    VK_DESCRIPTOR_TYPE_STORAGE_IMAGE = 3,
+    VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER = 4,
+    VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER = 5,
+    VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER = 6,
+    VK_DESCRIPTOR_TYPE_STORAGE_BUFFER = 7,
+    VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC = 8,
+    VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC = 9,
+    VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT = 10,
+});
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDescriptorSetLayoutBinding {
+    pub binding: uint32_t,
+    pub descriptor_type: VkDescriptorType,
+    pub descriptor_count: uint32_t,
+    pub stage_flags: VkShaderStageFlags,
+    pub p_immutable_samplers: *const VkSampler,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkDescriptorSetLayoutCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDescriptorSetLayoutCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkDescriptorSetLayoutCreateFlags,
+    pub binding_count: uint32_t,
+    pub p_bindings: *const VkDescriptorSetLayoutBinding,
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkDescriptorPoolCreateFlags: VkFlags {
+        const VK_DESCRIPTOR_POOL_CREATE_FREE_DESCRIPTOR_SET_BIT = 0x00000001,
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkDescriptorPoolResetFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDescriptorPoolSize {
+    pub descriptor_type: VkDescriptorType,
+    pub descriptor_count: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDescriptorPoolCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkDescriptorPoolCreateFlags,
+    pub max_sets: uint32_t,
+    pub pool_size_count: uint32_t,
+    pub p_pool_sizes: *const VkDescriptorPoolSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDescriptorSetAllocateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub descriptor_pool: VkDescriptorPool,
+    pub descriptor_set_count: uint32_t,
+    pub p_set_layouts: *const VkDescriptorSetLayout,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDescriptorImageInfo {
+    pub sampler: VkSampler,
+    pub image_view: VkImageView,
+    pub image_layout: VkImageLayout,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkDescriptorBufferInfo {
+    pub buffer: VkBuffer,
+    pub offset: VkDeviceSize,
+    pub range: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkWriteDescriptorSet {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub dst_set: VkDescriptorSet,
+    pub dst_binding: uint32_t,
+    pub dst_array_element: uint32_t,
+    pub descriptor_count: uint32_t,
+    pub descriptor_type: VkDescriptorType,
+    pub p_image_info: *const VkDescriptorImageInfo,
+    pub p_buffer_info: *const VkDescriptorBufferInfo,
+    pub p_texel_buffer_view: *const VkBufferView,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkCopyDescriptorSet {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub src_set: VkDescriptorSet,
+    pub src_binding: uint32_t,
+    pub src_array_element: uint32_t,
+    pub dst_set: VkDescriptorSet,
+    pub dst_binding: uint32_t,
+    pub dst_array_element: uint32_t,
+    pub descriptor_count: uint32_t,
+}
+
+pub type VkPipeline = usize;
+
+pub type VkPipelineLayout = usize;
+
+pub type VkPipelineCache = usize;
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkShaderStageFlags: VkFlags {
+        const VK_SHADER_STAGE_VERTEX_BIT = 0x00000001,
+        const VK_SHADER_STAGE_TESSELLATION_CONTROL_BIT = 0x00000002,
+        const VK_SHADER_STAGE_TESSELLATION_EVALUATION_BIT = 0x00000004,
+        const VK_SHADER_STAGE_GEOMETRY_BIT = 0x00000008,
+        const VK_SHADER_STAGE_FRAGMENT_BIT = 0x00000010,
+        const VK_SHADER_STAGE_COMPUTE_BIT = 0x00000020,
+        const VK_SHADER_STAGE_ALL_GRAPHICS = 0x0000001f,
+        const VK_SHADER_STAGE_ALL = 0x7fffffff,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkPipelineCreateFlags: VkFlags {
+        const VK_PIPELINE_CREATE_DISABLE_OPTIMIZATION_BIT = 0x00000001,
+        const VK_PIPELINE_CREATE_ALLOW_DERIVATIVES_BIT = 0x00000002,
+        const VK_PIPELINE_CREATE_DERIVATIVE_BIT = 0x00000004,
+    }
+}
+
+vk_enum!(VkPipelineBindPoint: int32_t {
+    VK_PIPELINE_BIND_POINT_GRAPHICS = 0,
+    VK_PIPELINE_BIND_POINT_COMPUTE = 1,
+});
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPushConstantRange {
+    pub stage_flags: VkShaderStageFlags,
+    pub offset: uint32_t,
+    pub size: uint32_t,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineLayoutCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineLayoutCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineLayoutCreateFlags,
+    pub set_layout_count: uint32_t,
+    pub p_set_layouts: *const VkDescriptorSetLayout,
+    pub push_constant_range_count: uint32_t,
+    pub p_push_constant_ranges: *const VkPushConstantRange,
+}
+
+vk_enum!(VkPipelineCacheHeaderVersion: int32_t {
+    VK_PIPELINE_CACHE_HEADER_VERSION_ONE = 1,
+});
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineCacheCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineCacheCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineCacheCreateFlags,
+    pub initial_data_size: size_t,
+    pub p_initial_data: *const c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkSpecializationMapEntry {
+    pub constant_id: uint32_t,
+    pub offset: uint32_t,
+    pub size: size_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSpecializationInfo {
+    pub map_entry_count: uint32_t,
+    pub p_map_entries: *const VkSpecializationMapEntry,
+    pub data_size: size_t,
+    pub p_data: *const c_void,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineShaderStageCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineShaderStageCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineShaderStageCreateFlags,
+    pub stage: VkShaderStageFlags,
+    pub module: VkShaderModule,
+    pub p_name: *const c_char,
+    pub p_specialization_info: *const VkSpecializationInfo,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkComputePipelineCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineCreateFlags,
+    pub stage: VkPipelineShaderStageCreateInfo,
+    pub layout: VkPipelineLayout,
+    pub base_pipeline_handle: VkPipeline,
+    pub base_pipeline_index: int32_t,
+}
+
+vk_enum!(VkVertexInputRate: int32_t {
+    VK_VERTEX_INPUT_RATE_VERTEX = 0,
+    VK_VERTEX_INPUT_RATE_INSTANCE = 1,
+});
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkVertexInputBindingDescription {
+    pub binding: uint32_t,
+    pub stride: uint32_t,
+    pub input_rate: VkVertexInputRate,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkVertexInputAttributeDescription {
+    pub location: uint32_t,
+    pub binding: uint32_t,
+    pub format: VkFormat,
+    pub offset: uint32_t,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineVertexInputStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineVertexInputStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineVertexInputStateCreateFlags,
+    pub vertex_binding_description_count: uint32_t,
+    pub p_vertex_binding_descriptions: *const VkVertexInputBindingDescription,
+    pub vertex_attribute_description_count: uint32_t,
+    pub p_vertex_attribute_descriptions: *const VkVertexInputAttributeDescription,
+}
+
+vk_enum!(VkPrimitiveTopology: int32_t {
+    VK_PRIMITIVE_TOPOLOGY_POINT_LIST = 0,
+    VK_PRIMITIVE_TOPOLOGY_LINE_LIST = 1,
+    VK_PRIMITIVE_TOPOLOGY_LINE_STRIP = 2,
+    VK_PRIMITIVE_TOPOLOGY_TRIANGLE_LIST = 3,
+    VK_PRIMITIVE_TOPOLOGY_TRIANGLE_STRIP = 4,
+    VK_PRIMITIVE_TOPOLOGY_TRIANGLE_FAN = 5,
+    VK_PRIMITIVE_TOPOLOGY_LINE_LIST_WITH_ADJACENCY = 6,
+    VK_PRIMITIVE_TOPOLOGY_LINE_STRIP_WITH_ADJACENCY = 7,
+    VK_PRIMITIVE_TOPOLOGY_TRIANGLE_LIST_WITH_ADJACENCY = 8,
+    VK_PRIMITIVE_TOPOLOGY_TRIANGLE_STRIP_WITH_ADJACENCY = 9,
+    VK_PRIMITIVE_TOPOLOGY_PATCH_LIST = 10,
+});
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineInputAssemblyStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineInputAssemblyStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineInputAssemblyStateCreateFlags,
+    pub topology: VkPrimitiveTopology,
+    pub primitive_restart_enable: VkBool32,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineTessellationStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineTessellationStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineTessellationStateCreateFlags,
+    pub patch_control_points: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VkViewport {
+    pub x: c_float,
+    pub y: c_float,
+    pub width: c_float,
+    pub height: c_float,
+    pub min_depth: c_float,
+    pub max_depth: c_float,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineViewportStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineViewportStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineViewportStateCreateFlags,
+    pub viewport_count: uint32_t,
+    pub p_viewports: *const VkViewport,
+    pub scissor_count: uint32_t,
+    pub p_scissors: *const VkRect2D,
+}
+
+vk_enum!(VkPolygonMode: int32_t {
+    VK_POLYGON_MODE_FILL = 0,
+    VK_POLYGON_MODE_LINE = 1,
+    VK_POLYGON_MODE_POINT = 2,
+});
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkCullModeFlags: VkFlags {
+        const VK_CULL_MODE_NONE = 0x00000000,
+        const VK_CULL_MODE_FRONT_BIT = 0x00000001,
+        const VK_CULL_MODE_BACK_BIT = 0x00000002,
+        const VK_CULL_MODE_FRONT_AND_BACK = 0x00000003,
+    }
+}
+
+vk_enum!(VkFrontFace: int32_t {
+    VK_FRONT_FACE_COUNTER_CLOCKWISE = 0,
+    VK_FRONT_FACE_CLOCKWISE = 1,
+});
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineRasterizationStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkPipelineRasterizationStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineRasterizationStateCreateFlags,
+    pub depth_clamp_enable: VkBool32,
+    pub rasterizer_discard_enable: VkBool32,
+    pub polygon_mode: VkPolygonMode,
+    pub cull_mode: VkCullModeFlags,
+    pub front_face: VkFrontFace,
+    pub depth_bias_enable: VkBool32,
+    pub depth_bias_constant_factor: c_float,
+    pub depth_bias_clamp: c_float,
+    pub depth_bias_slope_factor: c_float,
+    pub line_width: c_float,
+}
+
+pub type VkSampleMask = uint32_t;
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineMultisampleStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkPipelineMultisampleStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineMultisampleStateCreateFlags,
+    pub rasterization_samples: VkSampleCountFlags,
+    pub sample_shading_enable: VkBool32,
+    pub min_sample_shading: c_float,
+    pub p_sample_mask: *const VkSampleMask,
+    pub alpha_to_coverage_enable: VkBool32,
+    pub alpha_to_one_enable: VkBool32,
+}
+
+vk_enum!(VkStencilOp: int32_t {
+    VK_STENCIL_OP_KEEP = 0,
+    VK_STENCIL_OP_ZERO = 1,
+    VK_STENCIL_OP_REPLACE = 2,
+    VK_STENCIL_OP_INCREMENT_AND_CLAMP = 3,
+    VK_STENCIL_OP_DECREMENT_AND_CLAMP = 4,
+    VK_STENCIL_OP_INVERT = 5,
+    VK_STENCIL_OP_INCREMENT_AND_WRAP = 6,
+    VK_STENCIL_OP_DECREMENT_AND_WRAP = 7,
+});
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkStencilOpState {
+    pub fail_op: VkStencilOp,
+    pub pass_op: VkStencilOp,
+    pub depth_fail_op: VkStencilOp,
+    pub compare_op: VkCompareOp,
+    pub compare_mask: uint32_t,
+    pub write_mask: uint32_t,
+    pub reference: uint32_t,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineDepthStencilStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkPipelineDepthStencilStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineDepthStencilStateCreateFlags,
+    pub depth_test_enable: VkBool32,
+    pub depth_write_enable: VkBool32,
+    pub depth_compare_op: VkCompareOp,
+    pub depth_bounds_test_enable: VkBool32,
+    pub stencil_test_enable: VkBool32,
+    pub front: VkStencilOpState,
+    pub back: VkStencilOpState,
+    pub min_depth_bounds: c_float,
+    pub max_depth_bounds: c_float,
+}
+
+vk_enum!(VkBlendFactor: int32_t {
+    VK_BLEND_FACTOR_ZERO = 0,
+    VK_BLEND_FACTOR_ONE = 1,
+    VK_BLEND_FACTOR_SRC_COLOR = 2,
+    VK_BLEND_FACTOR_ONE_MINUS_SRC_COLOR = 3,
+    VK_BLEND_FACTOR_DST_COLOR = 4,
+    VK_BLEND_FACTOR_ONE_MINUS_DST_COLOR = 5,
+    VK_BLEND_FACTOR_SRC_ALPHA = 6,
+    VK_BLEND_FACTOR_ONE_MINUS_SRC_ALPHA = 7,
+    VK_BLEND_FACTOR_DST_ALPHA = 8,
+    VK_BLEND_FACTOR_ONE_MINUS_DST_ALPHA = 9,
+    VK_BLEND_FACTOR_CONSTANT_COLOR = 10,
+    VK_BLEND_FACTOR_ONE_MINUS_CONSTANT_COLOR = 11,
+    VK_BLEND_FACTOR_CONSTANT_ALPHA = 12,
+    VK_BLEND_FACTOR_ONE_MINUS_CONSTANT_ALPHA = 13,
+    VK_BLEND_FACTOR_SRC_ALPHA_SATURATE = 14,
+    VK_BLEND_FACTOR_SRC1_COLOR = 15,
+    VK_BLEND_FACTOR_ONE_MINUS_SRC1_COLOR = 16,
+    VK_BLEND_FACTOR_SRC1_ALPHA = 17,
+    VK_BLEND_FACTOR_ONE_MINUS_SRC1_ALPHA = 18,
+});
+
+vk_enum!(VkBlendOp: int32_t {
+    VK_BLEND_OP_ADD = 0,
+    VK_BLEND_OP_SUBTRACT = 1,
+    VK_BLEND_OP_REVERSE_SUBTRACT = 2,
+    VK_BLEND_OP_MIN = 3,
+    VK_BLEND_OP_MAX = 4,
+});
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkColorComponentFlags: VkFlags {
+        const VK_COLOR_COMPONENT_R_BIT = 0x00000001,
+        const VK_COLOR_COMPONENT_G_BIT = 0x00000002,
+        const VK_COLOR_COMPONENT_B_BIT = 0x00000004,
+        const VK_COLOR_COMPONENT_A_BIT = 0x00000008,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineColorBlendAttachmentState {
+    pub blend_enable: VkBool32,
+    pub src_color_blend_factor: VkBlendFactor,
+    pub dst_color_blend_factor: VkBlendFactor,
+    pub color_blend_op: VkBlendOp,
+    pub src_alpha_blend_factor: VkBlendFactor,
+    pub dst_alpha_blend_factor: VkBlendFactor,
+    pub alpha_blend_op: VkBlendOp,
+    pub color_write_mask: VkColorComponentFlags,
+}
+
+vk_enum!(VkLogicOp: int32_t {
+    VK_LOGIC_OP_CLEAR = 0,
+    VK_LOGIC_OP_AND = 1,
+    VK_LOGIC_OP_AND_REVERSE = 2,
+    VK_LOGIC_OP_COPY = 3,
+    VK_LOGIC_OP_AND_INVERTED = 4,
+    VK_LOGIC_OP_NO_OP = 5,
+    VK_LOGIC_OP_XOR = 6,
+    VK_LOGIC_OP_OR = 7,
+    VK_LOGIC_OP_NOR = 8,
+    VK_LOGIC_OP_EQUIVALENT = 9,
+    VK_LOGIC_OP_INVERT = 10,
+    VK_LOGIC_OP_OR_REVERSE = 11,
+    VK_LOGIC_OP_COPY_INVERTED = 12,
+    VK_LOGIC_OP_OR_INVERTED = 13,
+    VK_LOGIC_OP_NAND = 14,
+    VK_LOGIC_OP_SET = 15,
+});
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineColorBlendStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkPipelineColorBlendStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineColorBlendStateCreateFlags,
+    pub logic_op_enable: VkBool32,
+    pub logic_op: VkLogicOp,
+    pub attachment_count: uint32_t,
+    pub p_attachments: *const VkPipelineColorBlendAttachmentState,
+    pub blend_constants: [c_float; 4],
+}
+
+vk_enum!(VkDynamicState: int32_t {
+    VK_DYNAMIC_STATE_VIEWPORT = 0,
+    VK_DYNAMIC_STATE_SCISSOR = 1,
+    VK_DYNAMIC_STATE_LINE_WIDTH = 2,
+    VK_DYNAMIC_STATE_DEPTH_BIAS = 3,
+    VK_DYNAMIC_STATE_BLEND_CONSTANTS = 4,
+    VK_DYNAMIC_STATE_DEPTH_BOUNDS = 5,
+    VK_DYNAMIC_STATE_STENCIL_COMPARE_MASK = 6,
+    VK_DYNAMIC_STATE_STENCIL_WRITE_MASK = 7,
+    VK_DYNAMIC_STATE_STENCIL_REFERENCE = 8,
+});
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkPipelineDynamicStateCreateFlags {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPipelineDynamicStateCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineDynamicStateCreateFlags,
+    pub dynamic_state_count: uint32_t,
+    pub p_dynamic_states: *const VkDynamicState,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkGraphicsPipelineCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkPipelineCreateFlags,
+    pub stage_count: uint32_t,
+    pub p_stages: *const VkPipelineShaderStageCreateInfo,
+    pub p_vertex_input_state: *const VkPipelineVertexInputStateCreateInfo,
+    pub p_input_assembly_state: *const VkPipelineInputAssemblyStateCreateInfo,
+    pub p_tessellation_state: *const VkPipelineTessellationStateCreateInfo,
+    pub p_viewport_state: *const VkPipelineViewportStateCreateInfo,
+    pub p_rasterization_state: *const VkPipelineRasterizationStateCreateInfo,
+    pub p_multisample_state: *const VkPipelineMultisampleStateCreateInfo,
+    pub p_depth_stencil_state: *const VkPipelineDepthStencilStateCreateInfo,
+    pub p_color_blend_state: *const VkPipelineColorBlendStateCreateInfo,
+    pub p_dynamic_state: *const VkPipelineDynamicStateCreateInfo,
+    pub layout: VkPipelineLayout,
+    pub render_pass: VkRenderPass,
+    pub subpass: uint32_t,
+    pub base_pipeline_handle: VkPipeline,
+    pub base_pipeline_index: int32_t,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkBufferViewCreateFlags {
+    Reserved = 0,
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkStencilFaceFlags: VkFlags {
+        const VK_STENCIL_FACE_FRONT_BIT = 0x00000001,
+        const VK_STENCIL_FACE_BACK_BIT = 0x00000002,
+        const VK_STENCIL_FRONT_AND_BACK = 0x00000003,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkSparseImageFormatFlags: VkFlags {
+        const VK_SPARSE_IMAGE_FORMAT_SINGLE_MIPTAIL_BIT = 0x00000001,
+        const VK_SPARSE_IMAGE_FORMAT_ALIGNED_MIP_SIZE_BIT = 0x00000002,
+        const VK_SPARSE_IMAGE_FORMAT_NONSTANDARD_BLOCK_SIZE_BIT = 0x00000004,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkSparseMemoryBindFlags: VkFlags {
+        const VK_SPARSE_MEMORY_BIND_METADATA_BIT = 0x00000001,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkOffset3D {
+    pub x: int32_t,
+    pub y: int32_t,
+    pub z: int32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageSubresource {
+    pub aspect_mask: VkImageAspectFlags,
+    pub mip_level: uint32_t,
+    pub array_layer: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkSubresourceLayout {
+    pub offset: VkDeviceSize,
+    pub size: VkDeviceSize,
+    pub row_pitch: VkDeviceSize,
+    pub array_pitch: VkDeviceSize,
+    pub depth_pitch: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSparseImageFormatProperties {
+    pub aspect_mask: VkImageAspectFlags,
+    pub image_granularity: VkExtent3D,
+    pub flags: VkSparseImageFormatFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSparseImageMemoryRequirements {
+    pub format_properties: VkSparseImageFormatProperties,
+    pub image_mip_tail_first_lod: uint32_t,
+    pub image_mip_tail_size: VkDeviceSize,
+    pub image_mip_tail_offset: VkDeviceSize,
+    pub image_mip_tail_stride: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSparseMemoryBind {
+    pub resource_offset: VkDeviceSize,
+    pub size: VkDeviceSize,
+    pub memory: VkDeviceMemory,
+    pub memory_offset: VkDeviceSize,
+    pub flags: VkSparseMemoryBindFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSparseBufferMemoryBindInfo {
+    pub buffer: VkBuffer,
+    pub bind_count: uint32_t,
+    pub p_binds: *const VkSparseMemoryBind,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSparseImageOpaqueMemoryBindInfo {
+    pub image: VkImage,
+    pub bind_count: uint32_t,
+    pub p_binds: *const VkSparseMemoryBind,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSparseImageMemoryBind {
+    pub subresource: VkImageSubresource,
+    pub offset: VkOffset3D,
+    pub extent: VkExtent3D,
+    pub memory: VkDeviceMemory,
+    pub memory_offset: VkDeviceSize,
+    pub flags: VkSparseMemoryBindFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSparseImageMemoryBindInfo {
+    pub image: VkImage,
+    pub bind_count: uint32_t,
+    pub p_binds: *const VkSparseImageMemoryBind,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkBindSparseInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub wait_semaphore_count: uint32_t,
+    pub p_wait_semaphores: *const VkSemaphore,
+    pub buffer_bind_count: uint32_t,
+    pub p_buffer_binds: *const VkSparseBufferMemoryBindInfo,
+    pub image_opaque_bind_count: uint32_t,
+    pub p_image_opaque_binds: *const VkSparseImageOpaqueMemoryBindInfo,
+    pub image_bind_count: uint32_t,
+    pub p_image_binds: *const VkSparseImageMemoryBindInfo,
+    pub signal_semaphore_count: uint32_t,
+    pub p_signal_semaphores: *const VkSemaphore,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkBufferViewCreateInfo {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkBufferViewCreateFlags,
+    pub buffer: VkBuffer,
+    pub format: VkFormat,
+    pub offset: VkDeviceSize,
+    pub range: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageSubresourceLayers {
+    pub aspect_mask: VkImageAspectFlags,
+    pub mip_level: uint32_t,
+    pub base_array_layer: uint32_t,
+    pub layer_count: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkBufferCopy {
+    pub src_offset: VkDeviceSize,
+    pub dst_offset: VkDeviceSize,
+    pub size: VkDeviceSize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageCopy {
+    pub src_subresource: VkImageSubresourceLayers,
+    pub src_offset: VkOffset3D,
+    pub dst_subresource: VkImageSubresourceLayers,
+    pub dst_offset: VkOffset3D,
+    pub extent: VkExtent3D,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageBlit {
+    pub src_subresource: VkImageSubresourceLayers,
+    pub src_offsets: [VkOffset3D; 2],
+    pub dst_subresource: VkImageSubresourceLayers,
+    pub dst_offsets: [VkOffset3D; 2],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkBufferImageCopy {
+    pub buffer_offset: VkDeviceSize,
+    pub buffer_row_length: uint32_t,
+    pub buffer_image_height: uint32_t,
+    pub image_subresource: VkImageSubresourceLayers,
+    pub image_offset: VkOffset3D,
+    pub image_extent: VkExtent3D,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VkClearAttachment {
+    pub aspect_mask: VkImageAspectFlags,
+    pub color_attachment: uint32_t,
+    pub clear_value: VkClearValue,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkClearRect {
+    pub rect: VkRect2D,
+    pub base_array_layer: uint32_t,
+    pub layer_count: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkImageResolve {
+    pub src_subresource: VkImageSubresourceLayers,
+    pub src_offset: VkOffset3D,
+    pub dst_subresource: VkImageSubresourceLayers,
+    pub dst_offset: VkOffset3D,
+    pub extent: VkExtent3D,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkDispatchIndirectCommand {
+    pub x: uint32_t,
+    pub y: uint32_t,
+    pub z: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkDrawIndexedIndirectCommand {
+    pub index_count: uint32_t,
+    pub instance_count: uint32_t,
+    pub first_index: uint32_t,
+    pub vertex_offset: int32_t,
+    pub first_instance: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkDrawIndirectCommand {
+    pub vertex_count: uint32_t,
+    pub instance_count: uint32_t,
+    pub first_vertex: uint32_t,
+    pub first_instance: uint32_t,
+}
+
+pub type VkSurfaceKHR = usize;
+
+pub type VkSwapchainKHR = usize;
+
+pub type VkDisplayKHR = usize;
+
+pub type VkDisplayModeKHR = usize;
+
+vk_enum!(VkColorSpaceKHR: int32_t {
+    VK_COLOR_SPACE_SRGB_NONLINEAR_KHR = 0,
+});
+
+vk_enum!(VkPresentModeKHR: int32_t {
+    VK_PRESENT_MODE_IMMEDIATE_KHR = 0,
+    VK_PRESENT_MODE_MAILBOX_KHR = 1,
+    VK_PRESENT_MODE_FIFO_KHR = 2,
+    VK_PRESENT_MODE_FIFO_RELAXED_KHR = 3,
+});
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkSurfaceTransformFlagsKHR: VkFlags {
+        const VK_SURFACE_TRANSFORM_IDENTITY_BIT_KHR = 0x00000001,
+        const VK_SURFACE_TRANSFORM_ROTATE_90_BIT_KHR = 0x00000002,
+        const VK_SURFACE_TRANSFORM_ROTATE_180_BIT_KHR = 0x00000004,
+        const VK_SURFACE_TRANSFORM_ROTATE_270_BIT_KHR = 0x00000008,
+        const VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_BIT_KHR = 0x00000010,
+        const VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_90_BIT_KHR = 0x00000020,
+        const VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_180_BIT_KHR = 0x00000040,
+        const VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_270_BIT_KHR = 0x00000080,
+        const VK_SURFACE_TRANSFORM_INHERIT_BIT_KHR = 0x00000100,
+    }
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkCompositeAlphaFlagsKHR: VkFlags {
+        const VK_COMPOSITE_ALPHA_OPAQUE_BIT_KHR = 0x00000001,
+        const VK_COMPOSITE_ALPHA_PRE_MULTIPLIED_BIT_KHR = 0x00000002,
+        const VK_COMPOSITE_ALPHA_POST_MULTIPLIED_BIT_KHR = 0x00000004,
+        const VK_COMPOSITE_ALPHA_INHERIT_BIT_KHR = 0x00000008,
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkSwapchainCreateFlagsKHR {
+    Reserved = 0,
+}
+
+bitflags! {
+    #[repr(C)]
+    pub flags VkDisplayPlaneAlphaFlagsKHR: VkFlags {
+        const VK_DISPLAY_PLANE_ALPHA_OPAQUE_BIT_KHR = 0x00000001,
+        const VK_DISPLAY_PLANE_ALPHA_GLOBAL_BIT_KHR = 0x00000002,
+        const VK_DISPLAY_PLANE_ALPHA_PER_PIXEL_BIT_KHR = 0x00000004,
+        const VK_DISPLAY_PLANE_ALPHA_PER_PIXEL_PREMULTIPLIED_BIT_KHR = 0x00000008,
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkDisplayModeCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkDisplaySurfaceCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkXlibSurfaceCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkXcbSurfaceCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkWaylandSurfaceCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkMirSurfaceCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkAndroidSurfaceCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkWin32SurfaceCreateFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VkCommandPoolTrimFlagsKHR {
+    Reserved = 0,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSurfaceCapabilitiesKHR {
+    pub min_image_count: uint32_t,
+    pub max_image_count: uint32_t,
+    pub current_extent: VkExtent2D,
+    pub min_image_extent: VkExtent2D,
+    pub max_image_extent: VkExtent2D,
+    pub max_image_array_layers: uint32_t,
+    pub supported_transforms: VkSurfaceTransformFlagsKHR,
+    pub current_transform: VkSurfaceTransformFlagsKHR,
+    pub supported_composite_alpha: VkCompositeAlphaFlagsKHR,
+    pub supported_usage_flags: VkImageUsageFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSurfaceFormatKHR {
+    pub format: VkFormat,
+    pub color_space: VkColorSpaceKHR,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkSwapchainCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkSwapchainCreateFlagsKHR,
+    pub surface: VkSurfaceKHR,
+    pub min_image_count: uint32_t,
+    pub image_format: VkFormat,
+    pub image_color_space: VkColorSpaceKHR,
+    pub image_extent: VkExtent2D,
+    pub image_array_layers: uint32_t,
+    pub image_usage: VkImageUsageFlags,
+    pub image_sharing_mode: VkSharingMode,
+    pub queue_family_index_count: uint32_t,
+    pub p_queue_family_indices: *const uint32_t,
+    pub pre_transform: VkSurfaceTransformFlagsKHR,
+    pub composite_alpha: VkCompositeAlphaFlagsKHR,
+    pub present_mode: VkPresentModeKHR,
+    pub clipped: VkBool32,
+    pub old_swapchain: VkSwapchainKHR,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkPresentInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub wait_semaphore_count: uint32_t,
+    pub p_wait_semaphores: *const VkSemaphore,
+    pub swapchain_count: uint32_t,
+    pub p_swapchains: *const VkSwapchainKHR,
+    pub p_image_indices: *const uint32_t,
+    pub p_results: *mut VkResult,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDisplayPropertiesKHR {
+    pub display: VkDisplayKHR,
+    pub display_name: *const c_char,
+    pub physical_dimensions: VkExtent2D,
+    pub physical_resolution: VkExtent2D,
+    pub supported_transforms: VkSurfaceTransformFlagsKHR,
+    pub plane_reorder_possible: VkBool32,
+    pub persistent_content: VkBool32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkDisplayModeParametersKHR {
+    pub visible_region: VkExtent2D,
+    pub refresh_rate: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkDisplayModePropertiesKHR {
+    pub display_mode: VkDisplayModeKHR,
+    pub parameters: VkDisplayModeParametersKHR,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDisplayModeCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkDisplayModeCreateFlagsKHR,
+    pub parameters: VkDisplayModeParametersKHR,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDisplayPlaneCapabilitiesKHR {
+    pub supported_alpha: VkDisplayPlaneAlphaFlagsKHR,
+    pub min_src_position: VkOffset2D,
+    pub max_src_position: VkOffset2D,
+    pub min_src_extent: VkExtent2D,
+    pub max_src_extent: VkExtent2D,
+    pub min_dst_position: VkOffset2D,
+    pub max_dst_position: VkOffset2D,
+    pub min_dst_extent: VkExtent2D,
+    pub max_dst_extent: VkExtent2D,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VkDisplayPlanePropertiesKHR {
+    pub current_display: VkDisplayKHR,
+    pub current_stack_index: uint32_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VkDisplaySurfaceCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkDisplaySurfaceCreateFlagsKHR,
+    pub display_mode: VkDisplayModeKHR,
+    pub plane_index: uint32_t,
+    pub plane_stack_index: uint32_t,
+    pub transform: VkSurfaceTransformFlagsKHR,
+    pub global_alpha: c_float,
+    pub alpha_mode: VkDisplayPlaneAlphaFlagsKHR,
+    pub image_extent: VkExtent2D,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkDisplayPresentInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub src_rect: VkRect2D,
+    pub dst_rect: VkRect2D,
+    pub persistent: VkBool32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkXlibSurfaceCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkXlibSurfaceCreateFlagsKHR,
+    pub dpy: *mut Display,
+    pub window: Window,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkXcbSurfaceCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkXcbSurfaceCreateFlagsKHR,
+    pub connection: *mut xcb_connection_t,
+    pub window: xcb_window_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkWaylandSurfaceCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkWaylandSurfaceCreateFlagsKHR,
+    pub display: *mut wl_display,
+    pub surface: *mut wl_surface,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkMirSurfaceCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkMirSurfaceCreateFlagsKHR,
+    pub connection: *mut MirConnection,
+    pub mir_surface: *mut MirSurface,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkAndroidSurfaceCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkAndroidSurfaceCreateFlagsKHR,
+    pub window: *mut ANativeWindow,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VkWin32SurfaceCreateInfoKHR {
+    pub s_type: VkStructureType,
+    pub p_next: *const c_void,
+    pub flags: VkWin32SurfaceCreateFlagsKHR,
+    pub hinstance: HINSTANCE,
+    pub hwnd: HWND,
+}
+
+pub type PFNvkAcquireNextImageKHR = unsafe extern fn(device: VkDevice, swapchain: VkSwapchainKHR, timeout: uint64_t, semaphore: VkSemaphore, fence: VkFence, p_image_index: *mut uint32_t) -> VkResult;
+pub type PFNvkAllocateCommandBuffers = unsafe extern fn(device: VkDevice, p_allocate_info: *const VkCommandBufferAllocateInfo, p_command_buffers: *mut VkCommandBuffer) -> VkResult;
+pub type PFNvkAllocateDescriptorSets = unsafe extern fn(device: VkDevice, p_allocate_info: *const VkDescriptorSetAllocateInfo, p_descriptor_sets: *mut VkDescriptorSet) -> VkResult;
+pub type PFNvkAllocateMemory = unsafe extern fn(device: VkDevice, p_allocate_info: *const VkMemoryAllocateInfo, p_allocator: *const VkAllocationCallbacks, p_memory: *mut VkDeviceMemory) -> VkResult;
+pub type PFNvkBeginCommandBuffer = unsafe extern fn(command_buffer: VkCommandBuffer, p_begin_info: *const VkCommandBufferBeginInfo) -> VkResult;
+pub type PFNvkBindBufferMemory = unsafe extern fn(device: VkDevice, buffer: VkBuffer, memory: VkDeviceMemory, memory_offset: VkDeviceSize) -> VkResult;
+pub type PFNvkBindImageMemory = unsafe extern fn(device: VkDevice, image: VkImage, memory: VkDeviceMemory, memory_offset: VkDeviceSize) -> VkResult;
+pub type PFNvkCmdBeginQuery = unsafe extern fn(command_buffer: VkCommandBuffer, query_pool: VkQueryPool, query: uint32_t, flags: VkQueryControlFlags);
+pub type PFNvkCmdBeginRenderPass = unsafe extern fn(command_buffer: VkCommandBuffer, p_render_pass_begin: *const VkRenderPassBeginInfo, contents: VkSubpassContents);
+pub type PFNvkCmdBindDescriptorSets = unsafe extern fn(command_buffer: VkCommandBuffer, pipeline_bind_point: VkPipelineBindPoint, layout: VkPipelineLayout, first_set: uint32_t, descriptor_set_count: uint32_t, p_descriptor_sets: *const VkDescriptorSet, dynamic_offset_count: uint32_t, p_dynamic_offsets: *const uint32_t);
+pub type PFNvkCmdBindIndexBuffer = unsafe extern fn(command_buffer: VkCommandBuffer, buffer: VkBuffer, offset: VkDeviceSize, index_type: VkIndexType);
+pub type PFNvkCmdBindPipeline = unsafe extern fn(command_buffer: VkCommandBuffer, pipeline_bind_point: VkPipelineBindPoint, pipeline: VkPipeline);
+pub type PFNvkCmdBindVertexBuffers = unsafe extern fn(command_buffer: VkCommandBuffer, first_binding: uint32_t, binding_count: uint32_t, p_buffers: *const VkBuffer, p_offsets: *const VkDeviceSize);
+pub type PFNvkCmdBlitImage = unsafe extern fn(command_buffer: VkCommandBuffer, src_image: VkImage, src_image_layout: VkImageLayout, dst_image: VkImage, dst_image_layout: VkImageLayout, region_count: uint32_t, p_regions: *const VkImageBlit, filter: VkFilter);
+pub type PFNvkCmdClearAttachments = unsafe extern fn(command_buffer: VkCommandBuffer, attachment_count: uint32_t, p_attachments: *const VkClearAttachment, rect_count: uint32_t, p_rects: *const VkClearRect);
+pub type PFNvkCmdClearColorImage = unsafe extern fn(command_buffer: VkCommandBuffer, image: VkImage, image_layout: VkImageLayout, p_color: *const VkClearColorValue, range_count: uint32_t, p_ranges: *const VkImageSubresourceRange);
+pub type PFNvkCmdClearDepthStencilImage = unsafe extern fn(command_buffer: VkCommandBuffer, image: VkImage, image_layout: VkImageLayout, p_depth_stencil: *const VkClearDepthStencilValue, range_count: uint32_t, p_ranges: *const VkImageSubresourceRange);
+pub type PFNvkCmdCopyBuffer = unsafe extern fn(command_buffer: VkCommandBuffer, src_buffer: VkBuffer, dst_buffer: VkBuffer, region_count: uint32_t, p_regions: *const VkBufferCopy);
+pub type PFNvkCmdCopyBufferToImage = unsafe extern fn(command_buffer: VkCommandBuffer, src_buffer: VkBuffer, dst_image: VkImage, dst_image_layout: VkImageLayout, region_count: uint32_t, p_regions: *const VkBufferImageCopy);
+pub type PFNvkCmdCopyImage = unsafe extern fn(command_buffer: VkCommandBuffer, src_image: VkImage, src_image_layout: VkImageLayout, dst_image: VkImage, dst_image_layout: VkImageLayout, region_count: uint32_t, p_regions: *const VkImageCopy);
+pub type PFNvkCmdCopyImageToBuffer = unsafe extern fn(command_buffer: VkCommandBuffer, src_image: VkImage, src_image_layout: VkImageLayout, dst_buffer: VkBuffer, region_count: uint32_t, p_regions: *const VkBufferImageCopy);
+pub type PFNvkCmdCopyQueryPoolResults = unsafe extern fn(command_buffer: VkCommandBuffer, query_pool: VkQueryPool, first_query: uint32_t, query_count: uint32_t, dst_buffer: VkBuffer, dst_offset: VkDeviceSize, stride: VkDeviceSize, flags: VkQueryResultFlags);
+pub type PFNvkCmdDispatch = unsafe extern fn(command_buffer: VkCommandBuffer, x: uint32_t, y: uint32_t, z: uint32_t);
+pub type PFNvkCmdDispatchIndirect = unsafe extern fn(command_buffer: VkCommandBuffer, buffer: VkBuffer, offset: VkDeviceSize);
+pub type PFNvkCmdDraw = unsafe extern fn(command_buffer: VkCommandBuffer, vertex_count: uint32_t, instance_count: uint32_t, first_vertex: uint32_t, first_instance: uint32_t);
+pub type PFNvkCmdDrawIndexed = unsafe extern fn(command_buffer: VkCommandBuffer, index_count: uint32_t, instance_count: uint32_t, first_index: uint32_t, vertex_offset: int32_t, first_instance: uint32_t);
+pub type PFNvkCmdDrawIndexedIndirect = unsafe extern fn(command_buffer: VkCommandBuffer, buffer: VkBuffer, offset: VkDeviceSize, draw_count: uint32_t, stride: uint32_t);
+pub type PFNvkCmdDrawIndirect = unsafe extern fn(command_buffer: VkCommandBuffer, buffer: VkBuffer, offset: VkDeviceSize, draw_count: uint32_t, stride: uint32_t);
+pub type PFNvkCmdEndQuery = unsafe extern fn(command_buffer: VkCommandBuffer, query_pool: VkQueryPool, query: uint32_t);
+pub type PFNvkCmdEndRenderPass = unsafe extern fn(command_buffer: VkCommandBuffer);
+pub type PFNvkCmdExecuteCommands = unsafe extern fn(command_buffer: VkCommandBuffer, command_buffer_count: uint32_t, p_command_buffers: *const VkCommandBuffer);
+pub type PFNvkCmdFillBuffer = unsafe extern fn(command_buffer: VkCommandBuffer, dst_buffer: VkBuffer, dst_offset: VkDeviceSize, size: VkDeviceSize, data: uint32_t);
+pub type PFNvkCmdNextSubpass = unsafe extern fn(command_buffer: VkCommandBuffer, contents: VkSubpassContents);
+pub type PFNvkCmdPipelineBarrier = unsafe extern fn(command_buffer: VkCommandBuffer, src_stage_mask: VkPipelineStageFlags, dst_stage_mask: VkPipelineStageFlags, dependency_flags: VkDependencyFlags, memory_barrier_count: uint32_t, p_memory_barriers: *const VkMemoryBarrier, buffer_memory_barrier_count: uint32_t, p_buffer_memory_barriers: *const VkBufferMemoryBarrier, image_memory_barrier_count: uint32_t, p_image_memory_barriers: *const VkImageMemoryBarrier);
+pub type PFNvkCmdPushConstants = unsafe extern fn(command_buffer: VkCommandBuffer, layout: VkPipelineLayout, stage_flags: VkShaderStageFlags, offset: uint32_t, size: uint32_t, p_values: *const c_void);
+pub type PFNvkCmdResetEvent = unsafe extern fn(command_buffer: VkCommandBuffer, event: VkEvent, stage_mask: VkPipelineStageFlags);
+pub type PFNvkCmdResetQueryPool = unsafe extern fn(command_buffer: VkCommandBuffer, query_pool: VkQueryPool, first_query: uint32_t, query_count: uint32_t);
+pub type PFNvkCmdResolveImage = unsafe extern fn(command_buffer: VkCommandBuffer, src_image: VkImage, src_image_layout: VkImageLayout, dst_image: VkImage, dst_image_layout: VkImageLayout, region_count: uint32_t, p_regions: *const VkImageResolve);
+pub type PFNvkCmdSetBlendConstants = unsafe extern fn(command_buffer: VkCommandBuffer, blend_constants: *const c_float);
+pub type PFNvkCmdSetDepthBias = unsafe extern fn(command_buffer: VkCommandBuffer, depth_bias_constant_factor: c_float, depth_bias_clamp: c_float, depth_bias_slope_factor: c_float);
+pub type PFNvkCmdSetDepthBounds = unsafe extern fn(command_buffer: VkCommandBuffer, min_depth_bounds: c_float, max_depth_bounds: c_float);
+pub type PFNvkCmdSetEvent = unsafe extern fn(command_buffer: VkCommandBuffer, event: VkEvent, stage_mask: VkPipelineStageFlags);
+pub type PFNvkCmdSetLineWidth = unsafe extern fn(command_buffer: VkCommandBuffer, line_width: c_float);
+pub type PFNvkCmdSetScissor = unsafe extern fn(command_buffer: VkCommandBuffer, first_scissor: uint32_t, scissor_count: uint32_t, p_scissors: *const VkRect2D);
+pub type PFNvkCmdSetStencilCompareMask = unsafe extern fn(command_buffer: VkCommandBuffer, face_mask: VkStencilFaceFlags, compare_mask: uint32_t);
+pub type PFNvkCmdSetStencilReference = unsafe extern fn(command_buffer: VkCommandBuffer, face_mask: VkStencilFaceFlags, reference: uint32_t);
+pub type PFNvkCmdSetStencilWriteMask = unsafe extern fn(command_buffer: VkCommandBuffer, face_mask: VkStencilFaceFlags, write_mask: uint32_t);
+pub type PFNvkCmdSetViewport = unsafe extern fn(command_buffer: VkCommandBuffer, first_viewport: uint32_t, viewport_count: uint32_t, p_viewports: *const VkViewport);
+pub type PFNvkCmdUpdateBuffer = unsafe extern fn(command_buffer: VkCommandBuffer, dst_buffer: VkBuffer, dst_offset: VkDeviceSize, data_size: VkDeviceSize, p_data: *const c_void);
+pub type PFNvkCmdWaitEvents = unsafe extern fn(command_buffer: VkCommandBuffer, event_count: uint32_t, p_events: *const VkEvent, src_stage_mask: VkPipelineStageFlags, dst_stage_mask: VkPipelineStageFlags, memory_barrier_count: uint32_t, p_memory_barriers: *const VkMemoryBarrier, buffer_memory_barrier_count: uint32_t, p_buffer_memory_barriers: *const VkBufferMemoryBarrier, image_memory_barrier_count: uint32_t, p_image_memory_barriers: *const VkImageMemoryBarrier);
+pub type PFNvkCmdWriteTimestamp = unsafe extern fn(command_buffer: VkCommandBuffer, pipeline_stage: VkPipelineStageFlags, query_pool: VkQueryPool, query: uint32_t);
+pub type PFNvkCreateAndroidSurfaceKHR = unsafe extern fn(instance: VkInstance, p_create_info: *const VkAndroidSurfaceCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_surface: *mut VkSurfaceKHR) -> VkResult;
+pub type PFNvkCreateBuffer = unsafe extern fn(device: VkDevice, p_create_info: *const VkBufferCreateInfo, p_allocator: *const VkAllocationCallbacks, p_buffer: *mut VkBuffer) -> VkResult;
+pub type PFNvkCreateBufferView = unsafe extern fn(device: VkDevice, p_create_info: *const VkBufferViewCreateInfo, p_allocator: *const VkAllocationCallbacks, p_view: *mut VkBufferView) -> VkResult;
+pub type PFNvkCreateCommandPool = unsafe extern fn(device: VkDevice, create_info: *const VkCommandPoolCreateInfo, p_allocator: *const VkAllocationCallbacks, p_command_pool: *mut VkCommandPool) -> VkResult;
+pub type PFNvkCreateComputePipelines = unsafe extern fn(device: VkDevice, pipeline_cache: VkPipelineCache, create_info_count: uint32_t, p_create_infos: *const VkComputePipelineCreateInfo, p_allocator: *const VkAllocationCallbacks, p_pipelines: *mut VkPipeline) -> VkResult;
+pub type PFNvkCreateDebugReportCallbackEXT = unsafe extern fn(instance: VkInstance, p_create_info: *const VkDebugReportCallbackCreateInfoEXT, p_allocator: *const VkAllocationCallbacks, p_callback: *mut VkDebugReportCallbackEXT) -> VkResult;
+pub type PFNvkCreateDescriptorPool = unsafe extern fn(device: VkDevice, p_create_info: *const VkDescriptorPoolCreateInfo, p_allocator: *const VkAllocationCallbacks, p_descriptor_pool: *mut VkDescriptorPool) -> VkResult;
+pub type PFNvkCreateDescriptorSetLayout = unsafe extern fn(device: VkDevice, p_create_info: *const VkDescriptorSetLayoutCreateInfo, p_allocator: *const VkAllocationCallbacks, p_set_layout: *mut VkDescriptorSetLayout) -> VkResult;
+pub type PFNvkCreateDevice = unsafe extern fn(physical_device: VkPhysicalDevice, create_info: *const VkDeviceCreateInfo, p_allocator: *const VkAllocationCallbacks, p_device: *mut VkDevice) -> VkResult;
+pub type PFNvkCreateDisplayModeKHR = unsafe extern fn(physical_device: VkPhysicalDevice, display: VkDisplayKHR, p_create_info: *const VkDisplayModeCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_mode: *mut VkDisplayModeKHR) -> VkResult;
+pub type PFNvkCreateDisplayPlaneSurfaceKHR = unsafe extern fn(instance: VkInstance, p_create_info: *const VkDisplaySurfaceCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_surface: *mut VkSurfaceKHR) -> VkResult;
+pub type PFNvkCreateEvent = unsafe extern fn(device: VkDevice, create_info: *const VkEventCreateInfo, p_allocator: *const VkAllocationCallbacks, p_event: *mut VkEvent) -> VkResult;
+pub type PFNvkCreateFence = unsafe extern fn(device: VkDevice, create_info: *const VkFenceCreateInfo, p_allocator: *const VkAllocationCallbacks, p_fence: *mut VkFence) -> VkResult;
+pub type PFNvkCreateFramebuffer = unsafe extern fn(device: VkDevice, p_create_info: *const VkFramebufferCreateInfo, p_allocator: *const VkAllocationCallbacks, p_framebuffer: *mut VkFramebuffer) -> VkResult;
+pub type PFNvkCreateGraphicsPipelines = unsafe extern fn(device: VkDevice, pipeline_cache: VkPipelineCache, create_info_count: uint32_t, p_create_infos: *const VkGraphicsPipelineCreateInfo, p_allocator: *const VkAllocationCallbacks, p_pipelines: *mut VkPipeline) -> VkResult;
+pub type PFNvkCreateImage = unsafe extern fn(device: VkDevice, p_create_info: *const VkImageCreateInfo, p_allocator: *const VkAllocationCallbacks, p_image: *mut VkImage) -> VkResult;
+pub type PFNvkCreateImageView = unsafe extern fn(device: VkDevice, p_create_info: *const VkImageViewCreateInfo, p_allocator: *const VkAllocationCallbacks, p_view: *mut VkImageView) -> VkResult;
+pub type PFNvkCreateInstance = unsafe extern fn(create_info: *const VkInstanceCreateInfo, p_allocator: *const VkAllocationCallbacks, p_instance: *mut VkInstance) -> VkResult;
+pub type PFNvkCreateMirSurfaceKHR = unsafe extern fn(instance: VkInstance, p_create_info: *const VkMirSurfaceCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_surface: *mut VkSurfaceKHR) -> VkResult;
+pub type PFNvkCreatePipelineCache = unsafe extern fn(device: VkDevice, p_create_info: *const VkPipelineCacheCreateInfo, p_allocator: *const VkAllocationCallbacks, p_pipeline_cache: *mut VkPipelineCache) -> VkResult;
+pub type PFNvkCreatePipelineLayout = unsafe extern fn(device: VkDevice, p_create_info: *const VkPipelineLayoutCreateInfo, p_allocator: *const VkAllocationCallbacks, p_pipeline_layout: *mut VkPipelineLayout) -> VkResult;
+pub type PFNvkCreateQueryPool = unsafe extern fn(device: VkDevice, p_create_info: *const VkQueryPoolCreateInfo, p_allocator: *const VkAllocationCallbacks, p_query_pool: *mut VkQueryPool) -> VkResult;
+pub type PFNvkCreateRenderPass = unsafe extern fn(device: VkDevice, p_create_info: *const VkRenderPassCreateInfo, p_allocator: *const VkAllocationCallbacks, p_render_pass: *mut VkRenderPass) -> VkResult;
+pub type PFNvkCreateSampler = unsafe extern fn(device: VkDevice, p_create_info: *const VkSamplerCreateInfo, p_allocator: *const VkAllocationCallbacks, p_sampler: *mut VkSampler) -> VkResult;
+pub type PFNvkCreateSemaphore = unsafe extern fn(device: VkDevice, create_info: *const VkSemaphoreCreateInfo, p_allocator: *const VkAllocationCallbacks, p_semaphore: *mut VkSemaphore) -> VkResult;
+pub type PFNvkCreateShaderModule = unsafe extern fn(device: VkDevice, p_create_info: *const VkShaderModuleCreateInfo, p_allocator: *const VkAllocationCallbacks, p_shader_module: *mut VkShaderModule) -> VkResult;
+pub type PFNvkCreateSharedSwapchainsKHR = unsafe extern fn(device: VkDevice, swapchain_count: uint32_t, p_create_infos: *const VkSwapchainCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_swapchains: *mut VkSwapchainKHR) -> VkResult;
+pub type PFNvkCreateSwapchainKHR = unsafe extern fn(device: VkDevice, p_create_info: *const VkSwapchainCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_swapchain: *mut VkSwapchainKHR) -> VkResult;
+pub type PFNvkCreateWaylandSurfaceKHR = unsafe extern fn(instance: VkInstance, p_create_info: *const VkWaylandSurfaceCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_surface: *mut VkSurfaceKHR) -> VkResult;
+pub type PFNvkCreateWin32SurfaceKHR = unsafe extern fn(instance: VkInstance, p_create_info: *const VkWin32SurfaceCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_surface: *mut VkSurfaceKHR) -> VkResult;
+pub type PFNvkCreateXcbSurfaceKHR = unsafe extern fn(instance: VkInstance, p_create_info: *const VkXcbSurfaceCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_surface: *mut VkSurfaceKHR) -> VkResult;
+pub type PFNvkCreateXlibSurfaceKHR = unsafe extern fn(instance: VkInstance, p_create_info: *const VkXlibSurfaceCreateInfoKHR, p_allocator: *const VkAllocationCallbacks, p_surface: *mut VkSurfaceKHR) -> VkResult;
+pub type PFNvkDebugReportMessageEXT = unsafe extern fn(instance: VkInstance, flags: VkDebugReportFlagsEXT, object_type: VkDebugReportObjectTypeEXT, object: uint64_t, location: size_t, message_code: int32_t, p_layer_prefix: *const c_char, p_message: *const c_char);
+pub type PFNvkDestroyBuffer = unsafe extern fn(device: VkDevice, buffer: VkBuffer, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyBufferView = unsafe extern fn(device: VkDevice, buffer_view: VkBufferView, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyCommandPool = unsafe extern fn(device: VkDevice, command_pool: VkCommandPool, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyDebugReportCallbackEXT = unsafe extern fn(instance: VkInstance, callback: VkDebugReportCallbackEXT, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyDescriptorPool = unsafe extern fn(device: VkDevice, descriptor_pool: VkDescriptorPool, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyDescriptorSetLayout = unsafe extern fn(device: VkDevice, descriptor_set_layout: VkDescriptorSetLayout, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyDevice = unsafe extern fn(device: VkDevice, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyEvent = unsafe extern fn(device: VkDevice, event: VkEvent, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyFence = unsafe extern fn(device: VkDevice, fence: VkFence, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyFramebuffer = unsafe extern fn(device: VkDevice, framebuffer: VkFramebuffer, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyImage = unsafe extern fn(device: VkDevice, image: VkImage, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyImageView = unsafe extern fn(device: VkDevice, image_view: VkImageView, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyInstance = unsafe extern fn(instance: VkInstance, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyPipeline = unsafe extern fn(device: VkDevice, pipeline: VkPipeline, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyPipelineCache = unsafe extern fn(device: VkDevice, pipeline_cache: VkPipelineCache, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyPipelineLayout = unsafe extern fn(device: VkDevice, pipeline_layout: VkPipelineLayout, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyQueryPool = unsafe extern fn(device: VkDevice, query_pool: VkQueryPool, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyRenderPass = unsafe extern fn(device: VkDevice, render_pass: VkRenderPass, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroySampler = unsafe extern fn(device: VkDevice, sampler: VkSampler, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroySemaphore = unsafe extern fn(device: VkDevice, semaphore: VkSemaphore, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroyShaderModule = unsafe extern fn(device: VkDevice, shader_module: VkShaderModule, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroySurfaceKHR = unsafe extern fn(instance: VkInstance, surface: VkSurfaceKHR, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDestroySwapchainKHR = unsafe extern fn(device: VkDevice, swapchain: VkSwapchainKHR, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkDeviceWaitIdle = unsafe extern fn(device: VkDevice) -> VkResult;
+pub type PFNvkEndCommandBuffer = unsafe extern fn(command_buffer: VkCommandBuffer) -> VkResult;
+pub type PFNvkEnumerateDeviceExtensionProperties = unsafe extern fn(physical_device: VkPhysicalDevice, p_layer_name: *const c_char, p_property_count: *mut uint32_t, p_properties: *mut VkExtensionProperties) -> VkResult;
+pub type PFNvkEnumerateDeviceLayerProperties = unsafe extern fn(physical_device: VkPhysicalDevice, p_property_count: *mut uint32_t, p_properties: *mut VkLayerProperties) -> VkResult;
+pub type PFNvkEnumerateInstanceExtensionProperties = unsafe extern fn(p_layer_name: *const c_char, p_property_count: *mut uint32_t, p_properties: *mut VkExtensionProperties) -> VkResult;
+pub type PFNvkEnumerateInstanceLayerProperties = unsafe extern fn(p_property_count: *mut uint32_t, p_properties: *mut VkLayerProperties) -> VkResult;
+pub type PFNvkEnumeratePhysicalDevices = unsafe extern fn(instance: VkInstance, p_physical_device_count: *mut uint32_t, p_physical_devices: *mut VkPhysicalDevice) -> VkResult;
+pub type PFNvkFlushMappedMemoryRanges = unsafe extern fn(device: VkDevice, memory_range_count: uint32_t, p_memory_ranges: *const VkMappedMemoryRange) -> VkResult;
+pub type PFNvkFreeCommandBuffers = unsafe extern fn(device: VkDevice, command_pool: VkCommandPool, command_buffer_count: uint32_t, p_command_buffers: *const VkCommandBuffer);
+pub type PFNvkFreeDescriptorSets = unsafe extern fn(device: VkDevice, descriptor_pool: VkDescriptorPool, descriptor_set_count: uint32_t, p_descriptor_sets: *const VkDescriptorSet) -> VkResult;
+pub type PFNvkFreeMemory = unsafe extern fn(device: VkDevice, memory: VkDeviceMemory, p_allocator: *const VkAllocationCallbacks);
+pub type PFNvkGetBufferMemoryRequirements = unsafe extern fn(device: VkDevice, buffer: VkBuffer, p_memory_requirements: *mut VkMemoryRequirements);
+pub type PFNvkGetDeviceMemoryCommitment = unsafe extern fn(device: VkDevice, memory: VkDeviceMemory, p_committed_memory_in_bytes: *mut VkDeviceSize);
+pub type PFNvkGetDeviceProcAddr = unsafe extern fn(device: VkDevice, p_name: *const c_char) -> Option<PFNvkVoidFunction>;
+pub type PFNvkGetDeviceQueue = unsafe extern fn(device: VkDevice, queue_family_index: uint32_t, queue_index: uint32_t, p_queue: *mut VkQueue);
+pub type PFNvkGetDisplayModePropertiesKHR = unsafe extern fn(physical_device: VkPhysicalDevice, display: VkDisplayKHR, p_property_count: *mut uint32_t, p_properties: *mut VkDisplayModePropertiesKHR) -> VkResult;
+pub type PFNvkGetDisplayPlaneCapabilitiesKHR = unsafe extern fn(physical_device: VkPhysicalDevice, mode: VkDisplayModeKHR, plane_index: uint32_t, p_capabilities: *mut VkDisplayPlaneCapabilitiesKHR) -> VkResult;
+pub type PFNvkGetDisplayPlaneSupportedDisplaysKHR = unsafe extern fn(physical_device: VkPhysicalDevice, plane_index: uint32_t, p_display_count: *mut uint32_t, p_displays: *mut VkDisplayKHR) -> VkResult;
+pub type PFNvkGetEventStatus = unsafe extern fn(device: VkDevice, event: VkEvent) -> VkResult;
+pub type PFNvkGetFenceStatus = unsafe extern fn(device: VkDevice, fence: VkFence) -> VkResult;
+pub type PFNvkGetImageMemoryRequirements = unsafe extern fn(device: VkDevice, image: VkImage, p_memory_requirements: *mut VkMemoryRequirements);
+pub type PFNvkGetImageSparseMemoryRequirements = unsafe extern fn(device: VkDevice, image: VkImage, p_sparse_memory_requirement_count: *mut uint32_t, p_sparse_memory_requirements: *mut VkSparseImageMemoryRequirements);
+pub type PFNvkGetImageSubresourceLayout = unsafe extern fn(device: VkDevice, image: VkImage, p_subresource: *const VkImageSubresource, p_layout: *mut VkSubresourceLayout);
+pub type PFNvkGetInstanceProcAddr = unsafe extern fn(instance: VkInstance, p_name: *const c_char) -> Option<PFNvkVoidFunction>;
+pub type PFNvkGetPhysicalDeviceDisplayPlanePropertiesKHR = unsafe extern fn(physical_device: VkPhysicalDevice, p_property_count: *mut uint32_t, p_properties: *mut VkDisplayPlanePropertiesKHR) -> VkResult;
+pub type PFNvkGetPhysicalDeviceDisplayPropertiesKHR = unsafe extern fn(physical_device: VkPhysicalDevice, p_property_count: *mut uint32_t, p_properties: *mut VkDisplayPropertiesKHR) -> VkResult;
+pub type PFNvkGetPhysicalDeviceFeatures = unsafe extern fn(physical_device: VkPhysicalDevice, p_features: *mut VkPhysicalDeviceFeatures);
+pub type PFNvkGetPhysicalDeviceFormatProperties = unsafe extern fn(physical_device: VkPhysicalDevice, format: VkFormat, p_format_properties: *mut VkFormatProperties);
+pub type PFNvkGetPhysicalDeviceImageFormatProperties = unsafe extern fn(physical_device: VkPhysicalDevice, format: VkFormat, image_type: VkImageType, tiling: VkImageTiling, usage: VkImageUsageFlags, flags: VkImageCreateFlags, p_image_format_properties: *mut VkImageFormatProperties) -> VkResult;
+pub type PFNvkGetPhysicalDeviceMemoryProperties = unsafe extern fn(physical_device: VkPhysicalDevice, p_memory_properties: *mut VkPhysicalDeviceMemoryProperties);
+pub type PFNvkGetPhysicalDeviceMirPresentationSupportKHR = unsafe extern fn(physical_device: VkPhysicalDevice, queue_family_index: uint32_t, connection: *mut MirConnection) -> VkBool32;
+pub type PFNvkGetPhysicalDeviceProperties = unsafe extern fn(physical_device: VkPhysicalDevice, p_properties: *mut VkPhysicalDeviceProperties);
+pub type PFNvkGetPhysicalDeviceQueueFamilyProperties = unsafe extern fn(physical_device: VkPhysicalDevice, p_queue_family_property_count: *mut uint32_t, p_queue_family_properties: *mut VkQueueFamilyProperties);
+pub type PFNvkGetPhysicalDeviceSparseImageFormatProperties = unsafe extern fn(physical_device: VkPhysicalDevice, format: VkFormat, ty: VkImageType, samples: VkSampleCountFlags, usage: VkImageUsageFlags, tiling: VkImageTiling, p_property_count: *mut uint32_t, p_properties: *mut VkSparseImageFormatProperties);
+pub type PFNvkGetPhysicalDeviceSurfaceCapabilitiesKHR = unsafe extern fn(physical_device: VkPhysicalDevice, surface: VkSurfaceKHR, p_surface_capabilities: *mut VkSurfaceCapabilitiesKHR) -> VkResult;
+pub type PFNvkGetPhysicalDeviceSurfaceFormatsKHR = unsafe extern fn(physical_device: VkPhysicalDevice, surface: VkSurfaceKHR, p_surface_format_count: *mut uint32_t, p_surface_formats: *mut VkSurfaceFormatKHR) -> VkResult;
+pub type PFNvkGetPhysicalDeviceSurfacePresentModesKHR = unsafe extern fn(physical_device: VkPhysicalDevice, surface: VkSurfaceKHR, p_present_mode_count: *mut uint32_t, p_present_modes: *mut VkPresentModeKHR) -> VkResult;
+pub type PFNvkGetPhysicalDeviceSurfaceSupportKHR = unsafe extern fn(physical_device: VkPhysicalDevice, queue_family_index: uint32_t, surface: VkSurfaceKHR, p_supported: *mut VkBool32) -> VkResult;
+pub type PFNvkGetPhysicalDeviceWaylandPresentationSupportKHR = unsafe extern fn(physical_device: VkPhysicalDevice, queue_family_index: uint32_t, display: *mut wl_display) -> VkBool32;
+pub type PFNvkGetPhysicalDeviceWin32PresentationSupportKHR = unsafe extern fn(physical_device: VkPhysicalDevice, queue_family_index: uint32_t) -> VkBool32;
+pub type PFNvkGetPhysicalDeviceXcbPresentationSupportKHR = unsafe extern fn(physical_device: VkPhysicalDevice, queue_family_index: uint32_t, connection: *mut xcb_connection_t, visual_id: xcb_visualid_t) -> VkBool32;
+pub type PFNvkGetPhysicalDeviceXlibPresentationSupportKHR = unsafe extern fn(physical_device: VkPhysicalDevice, queue_family_index: uint32_t, dpy: *mut Display, visual_id: VisualID) -> VkBool32;
+pub type PFNvkGetPipelineCacheData = unsafe extern fn(device: VkDevice, pipeline_cache: VkPipelineCache, p_data_size: *mut size_t, p_data: *mut c_void) -> VkResult;
+pub type PFNvkGetQueryPoolResults = unsafe extern fn(device: VkDevice, query_pool: VkQueryPool, first_query: uint32_t, query_count: uint32_t, data_size: size_t, p_data: *mut c_void, stride: VkDeviceSize, flags: VkQueryResultFlags) -> VkResult;
+pub type PFNvkGetRenderAreaGranularity = unsafe extern fn(device: VkDevice, render_pass: VkRenderPass, p_granularity: *mut VkExtent2D);
+pub type PFNvkGetSwapchainImagesKHR = unsafe extern fn(device: VkDevice, swapchain: VkSwapchainKHR, p_swapchain_image_count: *mut uint32_t, p_swapchain_images: *mut VkImage) -> VkResult;
+pub type PFNvkInvalidateMappedMemoryRanges = unsafe extern fn(device: VkDevice, memory_range_count: uint32_t, p_memory_ranges: *const VkMappedMemoryRange) -> VkResult;
+pub type PFNvkMapMemory = unsafe extern fn(device: VkDevice, memory: VkDeviceMemory, offset: VkDeviceSize, size: VkDeviceSize, flags: VkMemoryMapFlags, pp_data: *mut *mut c_void) -> VkResult;
+pub type PFNvkMergePipelineCaches = unsafe extern fn(device: VkDevice, dst_cache: VkPipelineCache, src_cache_count: uint32_t, p_src_caches: *const VkPipelineCache) -> VkResult;
+pub type PFNvkQueueBindSparse = unsafe extern fn(queue: VkQueue, bind_info_count: uint32_t, p_bind_info: *const VkBindSparseInfo, fence: VkFence) -> VkResult;
+pub type PFNvkQueuePresentKHR = unsafe extern fn(queue: VkQueue, p_present_info: *const VkPresentInfoKHR) -> VkResult;
+pub type PFNvkQueueSubmit = unsafe extern fn(queue: VkQueue, submit_count: uint32_t, p_submits: *const VkSubmitInfo, fence: VkFence) -> VkResult;
+pub type PFNvkQueueWaitIdle = unsafe extern fn(queue: VkQueue) -> VkResult;
+pub type PFNvkResetCommandBuffer = unsafe extern fn(command_buffer: VkCommandBuffer, flags: VkCommandBufferResetFlags) -> VkResult;
+pub type PFNvkResetCommandPool = unsafe extern fn(device: VkDevice, command_pool: VkCommandPool, flags: VkCommandPoolResetFlags) -> VkResult;
+pub type PFNvkResetDescriptorPool = unsafe extern fn(device: VkDevice, descriptor_pool: VkDescriptorPool, flags: VkDescriptorPoolResetFlags) -> VkResult;
+pub type PFNvkResetEvent = unsafe extern fn(device: VkDevice, event: VkEvent) -> VkResult;
+pub type PFNvkResetFences = unsafe extern fn(device: VkDevice, fence_count: uint32_t, p_fences: *const VkFence) -> VkResult;
+pub type PFNvkSetEvent = unsafe extern fn(device: VkDevice, event: VkEvent) -> VkResult;
+pub type PFNvkTrimCommandPoolKHR = unsafe extern fn(device: VkDevice, command_pool: VkCommandPool, flags: VkCommandPoolTrimFlagsKHR);
+pub type PFNvkUnmapMemory = unsafe extern fn(device: VkDevice, memory: VkDeviceMemory);
+pub type PFNvkUpdateDescriptorSets = unsafe extern fn(device: VkDevice, descriptor_write_count: uint32_t, p_descriptor_writes: *const VkWriteDescriptorSet, descriptor_copy_count: uint32_t, p_descriptor_copies: *const VkCopyDescriptorSet);
+pub type PFNvkWaitForFences = unsafe extern fn(device: VkDevice, fence_count: uint32_t, p_fences: *const VkFence, wait_all: VkBool32, timeout: uint64_t) -> VkResult;
+
+vk_functions!(EntryFns {
+    vkCreateInstance: PFNvkCreateInstance,
+    vkEnumerateInstanceExtensionProperties: PFNvkEnumerateInstanceExtensionProperties,
+    vkEnumerateInstanceLayerProperties: PFNvkEnumerateInstanceLayerProperties,
+});
+
+vk_functions!(InstanceFns {
+    vkCreateDevice: PFNvkCreateDevice,
+    vkDestroyInstance: PFNvkDestroyInstance,
+    vkEnumerateDeviceExtensionProperties: PFNvkEnumerateDeviceExtensionProperties,
+    vkEnumerateDeviceLayerProperties: PFNvkEnumerateDeviceLayerProperties,
+    vkEnumeratePhysicalDevices: PFNvkEnumeratePhysicalDevices,
+    vkGetDeviceProcAddr: PFNvkGetDeviceProcAddr,
+    vkGetPhysicalDeviceFeatures: PFNvkGetPhysicalDeviceFeatures,
+    vkGetPhysicalDeviceFormatProperties: PFNvkGetPhysicalDeviceFormatProperties,
+    vkGetPhysicalDeviceImageFormatProperties: PFNvkGetPhysicalDeviceImageFormatProperties,
+    vkGetPhysicalDeviceMemoryProperties: PFNvkGetPhysicalDeviceMemoryProperties,
+    vkGetPhysicalDeviceProperties: PFNvkGetPhysicalDeviceProperties,
+    vkGetPhysicalDeviceQueueFamilyProperties: PFNvkGetPhysicalDeviceQueueFamilyProperties,
+    vkGetPhysicalDeviceSparseImageFormatProperties: PFNvkGetPhysicalDeviceSparseImageFormatProperties,
+});
+
+vk_functions!(DeviceFns {
+    vkAllocateCommandBuffers: PFNvkAllocateCommandBuffers,
+    vkAllocateDescriptorSets: PFNvkAllocateDescriptorSets,
+    vkAllocateMemory: PFNvkAllocateMemory,
+    vkBeginCommandBuffer: PFNvkBeginCommandBuffer,
+    vkBindBufferMemory: PFNvkBindBufferMemory,
+    vkBindImageMemory: PFNvkBindImageMemory,
+    vkCmdBeginQuery: PFNvkCmdBeginQuery,
+    vkCmdBeginRenderPass: PFNvkCmdBeginRenderPass,
+    vkCmdBindDescriptorSets: PFNvkCmdBindDescriptorSets,
+    vkCmdBindIndexBuffer: PFNvkCmdBindIndexBuffer,
+    vkCmdBindPipeline: PFNvkCmdBindPipeline,
+    vkCmdBindVertexBuffers: PFNvkCmdBindVertexBuffers,
+    vkCmdBlitImage: PFNvkCmdBlitImage,
+    vkCmdClearAttachments: PFNvkCmdClearAttachments,
+    vkCmdClearColorImage: PFNvkCmdClearColorImage,
+    vkCmdClearDepthStencilImage: PFNvkCmdClearDepthStencilImage,
+    vkCmdCopyBuffer: PFNvkCmdCopyBuffer,
+    vkCmdCopyBufferToImage: PFNvkCmdCopyBufferToImage,
+    vkCmdCopyImage: PFNvkCmdCopyImage,
+    vkCmdCopyImageToBuffer: PFNvkCmdCopyImageToBuffer,
+    vkCmdCopyQueryPoolResults: PFNvkCmdCopyQueryPoolResults,
+    vkCmdDispatch: PFNvkCmdDispatch,
+    vkCmdDispatchIndirect: PFNvkCmdDispatchIndirect,
+    vkCmdDraw: PFNvkCmdDraw,
+    vkCmdDrawIndexed: PFNvkCmdDrawIndexed,
+    vkCmdDrawIndexedIndirect: PFNvkCmdDrawIndexedIndirect,
+    vkCmdDrawIndirect: PFNvkCmdDrawIndirect,
+    vkCmdEndQuery: PFNvkCmdEndQuery,
+    vkCmdEndRenderPass: PFNvkCmdEndRenderPass,
+    vkCmdExecuteCommands: PFNvkCmdExecuteCommands,
+    vkCmdFillBuffer: PFNvkCmdFillBuffer,
+    vkCmdNextSubpass: PFNvkCmdNextSubpass,
+    vkCmdPipelineBarrier: PFNvkCmdPipelineBarrier,
+    vkCmdPushConstants: PFNvkCmdPushConstants,
+    vkCmdResetEvent: PFNvkCmdResetEvent,
+    vkCmdResetQueryPool: PFNvkCmdResetQueryPool,
+    vkCmdResolveImage: PFNvkCmdResolveImage,
+    vkCmdSetBlendConstants: PFNvkCmdSetBlendConstants,
+    vkCmdSetDepthBias: PFNvkCmdSetDepthBias,
+    vkCmdSetDepthBounds: PFNvkCmdSetDepthBounds,
+    vkCmdSetEvent: PFNvkCmdSetEvent,
+    vkCmdSetLineWidth: PFNvkCmdSetLineWidth,
+    vkCmdSetScissor: PFNvkCmdSetScissor,
+    vkCmdSetStencilCompareMask: PFNvkCmdSetStencilCompareMask,
+    vkCmdSetStencilReference: PFNvkCmdSetStencilReference,
+    vkCmdSetStencilWriteMask: PFNvkCmdSetStencilWriteMask,
+    vkCmdSetViewport: PFNvkCmdSetViewport,
+    vkCmdUpdateBuffer: PFNvkCmdUpdateBuffer,
+    vkCmdWaitEvents: PFNvkCmdWaitEvents,
+    vkCmdWriteTimestamp: PFNvkCmdWriteTimestamp,
+    vkCreateBuffer: PFNvkCreateBuffer,
+    vkCreateBufferView: PFNvkCreateBufferView,
+    vkCreateCommandPool: PFNvkCreateCommandPool,
+    vkCreateComputePipelines: PFNvkCreateComputePipelines,
+    vkCreateDescriptorPool: PFNvkCreateDescriptorPool,
+    vkCreateDescriptorSetLayout: PFNvkCreateDescriptorSetLayout,
+    vkCreateEvent: PFNvkCreateEvent,
+    vkCreateFence: PFNvkCreateFence,
+    vkCreateFramebuffer: PFNvkCreateFramebuffer,
+    vkCreateGraphicsPipelines: PFNvkCreateGraphicsPipelines,
+    vkCreateImage: PFNvkCreateImage,
+    vkCreateImageView: PFNvkCreateImageView,
+    vkCreatePipelineCache: PFNvkCreatePipelineCache,
+    vkCreatePipelineLayout: PFNvkCreatePipelineLayout,
+    vkCreateQueryPool: PFNvkCreateQueryPool,
+    vkCreateRenderPass: PFNvkCreateRenderPass,
+    vkCreateSampler: PFNvkCreateSampler,
+    vkCreateSemaphore: PFNvkCreateSemaphore,
+    vkCreateShaderModule: PFNvkCreateShaderModule,
+    vkDestroyBuffer: PFNvkDestroyBuffer,
+    vkDestroyBufferView: PFNvkDestroyBufferView,
+    vkDestroyCommandPool: PFNvkDestroyCommandPool,
+    vkDestroyDescriptorPool: PFNvkDestroyDescriptorPool,
+    vkDestroyDescriptorSetLayout: PFNvkDestroyDescriptorSetLayout,
+    vkDestroyDevice: PFNvkDestroyDevice,
+    vkDestroyEvent: PFNvkDestroyEvent,
+    vkDestroyFence: PFNvkDestroyFence,
+    vkDestroyFramebuffer: PFNvkDestroyFramebuffer,
+    vkDestroyImage: PFNvkDestroyImage,
+    vkDestroyImageView: PFNvkDestroyImageView,
+    vkDestroyPipeline: PFNvkDestroyPipeline,
+    vkDestroyPipelineCache: PFNvkDestroyPipelineCache,
+    vkDestroyPipelineLayout: PFNvkDestroyPipelineLayout,
+    vkDestroyQueryPool: PFNvkDestroyQueryPool,
+    vkDestroyRenderPass: PFNvkDestroyRenderPass,
+    vkDestroySampler: PFNvkDestroySampler,
+    vkDestroySemaphore: PFNvkDestroySemaphore,
+    vkDestroyShaderModule: PFNvkDestroyShaderModule,
+    vkDeviceWaitIdle: PFNvkDeviceWaitIdle,
+    vkEndCommandBuffer: PFNvkEndCommandBuffer,
+    vkFlushMappedMemoryRanges: PFNvkFlushMappedMemoryRanges,
+    vkFreeCommandBuffers: PFNvkFreeCommandBuffers,
+    vkFreeDescriptorSets: PFNvkFreeDescriptorSets,
+    vkFreeMemory: PFNvkFreeMemory,
+    vkGetBufferMemoryRequirements: PFNvkGetBufferMemoryRequirements,
+    vkGetDeviceMemoryCommitment: PFNvkGetDeviceMemoryCommitment,
+    vkGetDeviceQueue: PFNvkGetDeviceQueue,
+    vkGetEventStatus: PFNvkGetEventStatus,
+    vkGetFenceStatus: PFNvkGetFenceStatus,
+    vkGetImageMemoryRequirements: PFNvkGetImageMemoryRequirements,
+    vkGetImageSparseMemoryRequirements: PFNvkGetImageSparseMemoryRequirements,
+    vkGetImageSubresourceLayout: PFNvkGetImageSubresourceLayout,
+    vkGetPipelineCacheData: PFNvkGetPipelineCacheData,
+    vkGetQueryPoolResults: PFNvkGetQueryPoolResults,
+    vkGetRenderAreaGranularity: PFNvkGetRenderAreaGranularity,
+    vkInvalidateMappedMemoryRanges: PFNvkInvalidateMappedMemoryRanges,
+    vkMapMemory: PFNvkMapMemory,
+    vkMergePipelineCaches: PFNvkMergePipelineCaches,
+    vkQueueBindSparse: PFNvkQueueBindSparse,
+    vkQueueSubmit: PFNvkQueueSubmit,
+    vkQueueWaitIdle: PFNvkQueueWaitIdle,
+    vkResetCommandBuffer: PFNvkResetCommandBuffer,
+    vkResetCommandPool: PFNvkResetCommandPool,
+    vkResetDescriptorPool: PFNvkResetDescriptorPool,
+    vkResetEvent: PFNvkResetEvent,
+    vkResetFences: PFNvkResetFences,
+    vkSetEvent: PFNvkSetEvent,
+    vkUnmapMemory: PFNvkUnmapMemory,
+    vkUpdateDescriptorSets: PFNvkUpdateDescriptorSets,
+    vkWaitForFences: PFNvkWaitForFences,
+});
+
+// VK_EXT_debug_report
+vk_functions!(DebugReportFns {
+    vkCreateDebugReportCallbackEXT: PFNvkCreateDebugReportCallbackEXT,
+    vkDebugReportMessageEXT: PFNvkDebugReportMessageEXT,
+    vkDestroyDebugReportCallbackEXT: PFNvkDestroyDebugReportCallbackEXT,
+});
+
+// VK_KHR_surface
+vk_functions!(SurfaceFns {
+    vkDestroySurfaceKHR: PFNvkDestroySurfaceKHR,
+    vkGetPhysicalDeviceSurfaceCapabilitiesKHR: PFNvkGetPhysicalDeviceSurfaceCapabilitiesKHR,
+    vkGetPhysicalDeviceSurfaceFormatsKHR: PFNvkGetPhysicalDeviceSurfaceFormatsKHR,
+    vkGetPhysicalDeviceSurfacePresentModesKHR: PFNvkGetPhysicalDeviceSurfacePresentModesKHR,
+    vkGetPhysicalDeviceSurfaceSupportKHR: PFNvkGetPhysicalDeviceSurfaceSupportKHR,
+});
+
+// VK_KHR_swapchain
+vk_functions!(SwapchainFns {
+    vkAcquireNextImageKHR: PFNvkAcquireNextImageKHR,
+    vkCreateSwapchainKHR: PFNvkCreateSwapchainKHR,
+    vkDestroySwapchainKHR: PFNvkDestroySwapchainKHR,
+    vkGetSwapchainImagesKHR: PFNvkGetSwapchainImagesKHR,
+    vkQueuePresentKHR: PFNvkQueuePresentKHR,
+});
+
+// VK_KHR_display
+vk_functions!(DisplayFns {
+    vkCreateDisplayModeKHR: PFNvkCreateDisplayModeKHR,
+    vkCreateDisplayPlaneSurfaceKHR: PFNvkCreateDisplayPlaneSurfaceKHR,
+    vkGetDisplayModePropertiesKHR: PFNvkGetDisplayModePropertiesKHR,
+    vkGetDisplayPlaneCapabilitiesKHR: PFNvkGetDisplayPlaneCapabilitiesKHR,
+    vkGetDisplayPlaneSupportedDisplaysKHR: PFNvkGetDisplayPlaneSupportedDisplaysKHR,
+    vkGetPhysicalDeviceDisplayPlanePropertiesKHR: PFNvkGetPhysicalDeviceDisplayPlanePropertiesKHR,
+    vkGetPhysicalDeviceDisplayPropertiesKHR: PFNvkGetPhysicalDeviceDisplayPropertiesKHR,
+});
+
+// VK_KHR_display_swapchain
+vk_functions!(DisplaySwapchainFns {
+    vkCreateSharedSwapchainsKHR: PFNvkCreateSharedSwapchainsKHR,
+});
+
+// VK_KHR_xlib_surface
+vk_functions!(XlibSurfaceFns {
+    vkCreateXlibSurfaceKHR: PFNvkCreateXlibSurfaceKHR,
+    vkGetPhysicalDeviceXlibPresentationSupportKHR: PFNvkGetPhysicalDeviceXlibPresentationSupportKHR,
+});
+
+// VK_KHR_xcb_surface
+vk_functions!(XcbSurfaceFns {
+    vkCreateXcbSurfaceKHR: PFNvkCreateXcbSurfaceKHR,
+    vkGetPhysicalDeviceXcbPresentationSupportKHR: PFNvkGetPhysicalDeviceXcbPresentationSupportKHR,
+});
+
+// VK_KHR_wayland_surface
+vk_functions!(WaylandSurfaceFns {
+    vkCreateWaylandSurfaceKHR: PFNvkCreateWaylandSurfaceKHR,
+    vkGetPhysicalDeviceWaylandPresentationSupportKHR: PFNvkGetPhysicalDeviceWaylandPresentationSupportKHR,
+});
+
+// VK_KHR_mir_surface
+vk_functions!(MirSurfaceFns {
+    vkCreateMirSurfaceKHR: PFNvkCreateMirSurfaceKHR,
+    vkGetPhysicalDeviceMirPresentationSupportKHR: PFNvkGetPhysicalDeviceMirPresentationSupportKHR,
+});
+
+// VK_KHR_android_surface
+vk_functions!(AndroidSurfaceFns {
+    vkCreateAndroidSurfaceKHR: PFNvkCreateAndroidSurfaceKHR,
+});
+
+// VK_KHR_win32_surface
+vk_functions!(Win32SurfaceFns {
+    vkCreateWin32SurfaceKHR: PFNvkCreateWin32SurfaceKHR,
+    vkGetPhysicalDeviceWin32PresentationSupportKHR: PFNvkGetPhysicalDeviceWin32PresentationSupportKHR,
+});
+
+// VK_KHR_maintenance1
+vk_functions!(Maintenance1Fns {
+    vkTrimCommandPoolKHR: PFNvkTrimCommandPoolKHR,
+});
@@ -0,0 +1,24 @@
+extern crate vkrs;
+
+use vkrs::instance::Instance;
+use vkrs::device::{QueuePriority, Device, SharingMode};
+use vkrs::buffer::Buffer;
+use vkrs::memory::{DeviceMemory, MemoryPropertyFlags};
+use vkrs::sys::buffer::VK_BUFFER_USAGE_STORAGE_BUFFER_BIT;
+use std::collections::HashMap;
+
+fn memory_outlives_buffer() {
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let mut buffer = Buffer::new(&device, 1024, VK_BUFFER_USAGE_STORAGE_BUFFER_BIT, &SharingMode::Exclusive).unwrap();
+    let memory = DeviceMemory::allocate(&device, 1024, !0, MemoryPropertyFlags::empty(),
+                                        MemoryPropertyFlags::empty()).unwrap();
+    buffer.bind_memory(&memory, 0).unwrap();
+    //~^ Error `memory` does not live long enough
+}
+
+fn main() {}
@@ -0,0 +1,130 @@
+extern crate vkrs;
+
+use vkrs::instance::Instance;
+use vkrs::device::{QueuePriority, Device};
+use std::collections::HashMap;
+
+fn submit_recording() {
+    use vkrs::command_pool::{SplitCommandPool, CommandPool};
+    use vkrs::command_buffer::{PrimaryCommandBuffer, CommandBuffer, CommandBufferUsageFlags};
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let mut queue = device.get_queue(0, 0).unwrap();
+    let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+    let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+    let cmd_buf = cmd_buf.begin(CommandBufferUsageFlags::empty()).unwrap();
+    queue.submit(&[&cmd_buf], &[], &[], None).unwrap();
+    //~^ Error mismatched types
+}
+
+fn record_executable() {
+    use vkrs::command_pool::{SplitCommandPool, CommandPool};
+    use vkrs::command_buffer::{PrimaryCommandBuffer, CommandBuffer, CommandBufferUsageFlags};
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+    let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+    let cmd_buf = cmd_buf.begin(CommandBufferUsageFlags::empty()).unwrap().end().unwrap();
+    cmd_buf.begin(CommandBufferUsageFlags::empty());
+    //~^ Error no method named `begin` found
+}
+
+fn draw_outside_render_pass() {
+    use vkrs::command_pool::{SplitCommandPool, CommandPool};
+    use vkrs::command_buffer::{PrimaryCommandBuffer, CommandBuffer, CommandBufferUsageFlags, InsideRenderPass};
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+    let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+    let mut cmd_buf = cmd_buf.begin(CommandBufferUsageFlags::empty()).unwrap();
+    cmd_buf.draw(3, 1, 0, 0);
+    //~^ Error no method named `draw` found
+}
+
+fn end_in_render_pass() {
+    use vkrs::command_pool::{SplitCommandPool, CommandPool};
+    use vkrs::command_buffer::{PrimaryCommandBuffer, CommandBuffer, CommandBufferUsageFlags};
+    use vkrs::render_pass::{RenderPass, Subpass, Framebuffer};
+    use vkrs::sys::render_pass::VkSubpassContents;
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let render_pass = RenderPass::new(&device, &[], &[Subpass::default()], &[]).unwrap();
+    let framebuffer = Framebuffer::new(&render_pass, &[], 64, 64, 1).unwrap();
+    let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+    let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+    let cmd_buf = cmd_buf.begin(CommandBufferUsageFlags::empty()).unwrap()
+        .begin_render_pass(&framebuffer, framebuffer.area(), &[], VkSubpassContents::VK_SUBPASS_CONTENTS_INLINE)
+        .unwrap();
+    cmd_buf.end();
+    //~^ Error no method named `end` found
+}
+
+fn execute_outside_render_pass() {
+    use vkrs::command_pool::{SplitCommandPool, CommandPool};
+    use vkrs::command_buffer::{PrimaryCommandBuffer, SecondaryCommandBuffer, CommandBuffer, CommandBufferUsageFlags,
+                               Inheritance};
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+    let secondary_buf = SecondaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+        .begin_in_render_pass(CommandBufferUsageFlags::empty(), &Inheritance::default()).unwrap()
+        .end().unwrap();
+    let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+    let mut cmd_buf = cmd_buf.begin(CommandBufferUsageFlags::empty()).unwrap();
+    cmd_buf.execute_commands(&[&secondary_buf]);
+    //~^ Error the trait bound
+}
+
+fn execute_in_render_pass() {
+    use vkrs::command_pool::{SplitCommandPool, CommandPool};
+    use vkrs::command_buffer::{PrimaryCommandBuffer, SecondaryCommandBuffer, CommandBuffer, CommandBufferUsageFlags,
+                               Inheritance};
+    use vkrs::render_pass::{RenderPass, Subpass, Framebuffer};
+    use vkrs::sys::render_pass::VkSubpassContents;
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let render_pass = RenderPass::new(&device, &[], &[Subpass::default()], &[]).unwrap();
+    let framebuffer = Framebuffer::new(&render_pass, &[], 64, 64, 1).unwrap();
+    let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+    let secondary_buf = SecondaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap()
+        .begin(CommandBufferUsageFlags::empty(), &Inheritance::default()).unwrap()
+        .end().unwrap();
+    let cmd_buf = PrimaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+    let mut cmd_buf = cmd_buf.begin(CommandBufferUsageFlags::empty()).unwrap()
+        .begin_render_pass(&framebuffer, framebuffer.area(), &[],
+                           VkSubpassContents::VK_SUBPASS_CONTENTS_SECONDARY_COMMAND_BUFFERS).unwrap();
+    cmd_buf.execute_commands(&[&secondary_buf]);
+    //~^ Error the trait bound
+}
+
+fn main() {}
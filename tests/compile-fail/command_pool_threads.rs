@@ -0,0 +1,42 @@
+extern crate vkrs;
+
+use vkrs::instance::Instance;
+use vkrs::device::{QueuePriority, Device};
+use std::collections::HashMap;
+
+fn assert_send<T: Send>(_: T) {}
+fn assert_sync<T: Sync>(_: &T) {}
+
+fn command_buffer_send() {
+    use vkrs::command_pool::{SplitCommandPool, CommandPool};
+    use vkrs::command_buffer::{SecondaryCommandBuffer, CommandBuffer};
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let cmd_pool = SplitCommandPool::new(&device, 0, false).unwrap();
+    let cmd_buf = SecondaryCommandBuffer::allocate(&cmd_pool, 1).unwrap().pop().unwrap();
+    assert_send(cmd_buf);
+    //~^ Error `Cell<()>` cannot be shared between threads safely
+}
+
+fn command_pool_sync() {
+    use vkrs::command_pool::{UnifiedCommandPool, CommandPool};
+
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let cmd_pool = UnifiedCommandPool::new(&device, 0, false).unwrap();
+    assert_send(&cmd_pool);
+    //~^ Error `Cell<()>` cannot be shared between threads safely
+    assert_sync(&cmd_pool);
+    //~^ Error `Cell<()>` cannot be shared between threads safely
+}
+
+fn main() {}
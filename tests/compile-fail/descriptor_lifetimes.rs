@@ -0,0 +1,35 @@
+extern crate vkrs;
+
+use vkrs::instance::Instance;
+use vkrs::device::{QueuePriority, Device};
+use vkrs::descriptor::{DescriptorSetLayout, DescriptorSetLayoutBinding, DescriptorPool, SplitDescriptorPool,
+                       DescriptorPoolSize, DescriptorSet};
+use vkrs::sampler::SamplerBuilder;
+use vkrs::sys::descriptor::VkDescriptorType;
+use vkrs::sys::pipeline::VK_SHADER_STAGE_COMPUTE_BIT;
+use std::collections::HashMap;
+
+fn sampler_outlives_set() {
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let layout = DescriptorSetLayout::new(&device, &[DescriptorSetLayoutBinding {
+        binding: 0,
+        descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER,
+        descriptor_count: 1,
+        stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+    }]).unwrap();
+    let pool = SplitDescriptorPool::new(&device, 1, &[DescriptorPoolSize {
+        descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER,
+        descriptor_count: 1,
+    }]).unwrap();
+    let mut set = DescriptorSet::allocate(&pool, &[&layout]).unwrap().pop().unwrap();
+    let sampler = SamplerBuilder::new().build(&device).unwrap();
+    set.write_sampler(0, 0, &sampler).unwrap();
+    //~^ Error `sampler` does not live long enough
+}
+
+fn main() {}
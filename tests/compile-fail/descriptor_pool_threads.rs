@@ -0,0 +1,53 @@
+extern crate vkrs;
+
+use vkrs::instance::Instance;
+use vkrs::device::{QueuePriority, Device};
+use vkrs::descriptor::{DescriptorSetLayout, DescriptorSetLayoutBinding, DescriptorPool, SplitDescriptorPool,
+                       UnifiedDescriptorPool, DescriptorPoolSize, DescriptorSet};
+use vkrs::sys::descriptor::VkDescriptorType;
+use vkrs::sys::pipeline::VK_SHADER_STAGE_COMPUTE_BIT;
+use std::collections::HashMap;
+
+fn assert_send<T: Send>(_: T) {}
+fn assert_sync<T: Sync>(_: &T) {}
+
+fn descriptor_set_send() {
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let layout = DescriptorSetLayout::new(&device, &[DescriptorSetLayoutBinding {
+        binding: 0,
+        descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER,
+        descriptor_count: 1,
+        stage_flags: VK_SHADER_STAGE_COMPUTE_BIT,
+    }]).unwrap();
+    let pool = SplitDescriptorPool::new(&device, 1, &[DescriptorPoolSize {
+        descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER,
+        descriptor_count: 1,
+    }]).unwrap();
+    let set = DescriptorSet::allocate(&pool, &[&layout]).unwrap().pop().unwrap();
+    assert_send(set);
+    //~^ Error `Cell<()>` cannot be shared between threads safely
+}
+
+fn descriptor_pool_sync() {
+    let instance = Instance::new(None, None).unwrap();
+    let device = {
+        let physical_devices = instance.devices().unwrap();
+        let priorities = vec!((0, vec!(QueuePriority::from_float_clamped(1.0)))).into_iter().collect::<HashMap<u32, Vec<QueuePriority>>>();
+        Device::new(&physical_devices[0], priorities).unwrap()
+    };
+    let pool = UnifiedDescriptorPool::new(&device, 1, &[DescriptorPoolSize {
+        descriptor_type: VkDescriptorType::VK_DESCRIPTOR_TYPE_SAMPLER,
+        descriptor_count: 1,
+    }]).unwrap();
+    assert_send(&pool);
+    //~^ Error `Cell<()>` cannot be shared between threads safely
+    assert_sync(&pool);
+    //~^ Error `Cell<()>` cannot be shared between threads safely
+}
+
+fn main() {}